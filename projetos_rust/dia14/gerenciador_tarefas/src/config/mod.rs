@@ -0,0 +1,585 @@
+// src/config/mod.rs
+use crate::i18n::{Locale, t, tf};
+use crate::models::{Category, Priority};
+use crate::storage::StorageFormat;
+use crate::ui::json::OutputFormat;
+use crate::ui::theme::Theme;
+use chrono::NaiveDate;
+use std::env;
+use std::fmt::Write as _;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+/// Interface escolhida na inicialização
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UiMode {
+    Menu,
+    Tui,
+}
+
+impl UiMode {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            UiMode::Menu => "menu",
+            UiMode::Tui => "tui",
+        }
+    }
+}
+
+/// Configuração efetiva do gerenciador
+///
+/// Montada em camadas: valores padrão, arquivo TOML no diretório de
+/// configuração do usuário, variáveis de ambiente e flags de linha de comando.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Config {
+    /// Arquivo JSON com as tarefas (ou a pasta, no formato Markdown)
+    pub storage_path: String,
+    /// Formato das tarefas no disco ("json" ou "markdown")
+    pub storage_format: StorageFormat,
+    /// Criptografa as tarefas com uma senha pedida ao abrir
+    pub encrypted: bool,
+    /// Arquivo do log de eventos (vazio desativa)
+    pub event_log: String,
+    /// Idioma ("auto" detecta pelo ambiente)
+    pub locale: String,
+    /// Formato de data (vazio usa o do idioma)
+    pub date_format: String,
+    /// Limpa a tela entre as telas do menu
+    pub clear_screen: bool,
+    /// Usa cores na interface
+    pub colors: bool,
+    /// Tema de cores do menu ("default", "light" ou "mono")
+    pub theme: Theme,
+    pub default_category: Category,
+    pub default_priority: Priority,
+    pub ui: UiMode,
+    /// Limite de tarefas pendentes no quadro Kanban, aplicado ao criar,
+    /// restaurar ou desarquivar (0 = sem limite)
+    pub wip_limit_pending: usize,
+    /// Limite de tarefas em andamento, aplicado ao iniciar (0 = sem limite)
+    pub wip_limit_in_progress: usize,
+    /// Dias na lixeira antes de a tarefa ser apagada de vez
+    pub trash_retention_days: u32,
+    /// Duração de cada pomodoro, em minutos
+    pub pomodoro_work_minutes: u32,
+    /// Pausa entre pomodoros, em minutos (0 = sem pausa)
+    pub pomodoro_break_minutes: u32,
+    /// Usuário atual (vazio usa o login do sistema)
+    pub user: String,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            storage_path: "data/tasks.json".to_string(),
+            storage_format: StorageFormat::Json,
+            encrypted: false,
+            event_log: "data/events.jsonl".to_string(),
+            locale: "auto".to_string(),
+            date_format: String::new(),
+            clear_screen: true,
+            colors: true,
+            theme: Theme::Default,
+            default_category: Category::Other,
+            default_priority: Priority::Medium,
+            ui: UiMode::Menu,
+            wip_limit_pending: 0,
+            wip_limit_in_progress: 0,
+            trash_retention_days: 30,
+            pomodoro_work_minutes: 25,
+            pomodoro_break_minutes: 5,
+            user: String::new(),
+        }
+    }
+}
+
+/// Chaves aceitas no arquivo, nas variáveis de ambiente e em `--set`
+pub const KEYS: &[&str] = &[
+    "storage_path",
+    "storage_format",
+    "encrypted",
+    "event_log",
+    "locale",
+    "date_format",
+    "clear_screen",
+    "colors",
+    "theme",
+    "default_category",
+    "default_priority",
+    "ui",
+    "wip_limit_pending",
+    "wip_limit_in_progress",
+    "trash_retention_days",
+    "pomodoro_work_minutes",
+    "pomodoro_break_minutes",
+    "user",
+];
+
+/// Prefixo das variáveis de ambiente (ex.: GERENCIADOR_STORAGE_PATH)
+const ENV_PREFIX: &str = "GERENCIADOR_";
+
+impl Config {
+    /// Caminho padrão do arquivo de configuração
+    /// (ex.: ~/.config/gerenciador_tarefas/config.toml)
+    pub fn default_path() -> Option<PathBuf> {
+        dirs::config_dir().map(|dir| dir.join("gerenciador_tarefas").join("config.toml"))
+    }
+
+    /// Carrega a configuração aplicando todas as camadas
+    pub fn load(cli: &CliArgs) -> io::Result<Config> {
+        let path = cli
+            .config_path
+            .clone()
+            .or_else(|| env::var(format!("{}CONFIG", ENV_PREFIX)).ok().map(PathBuf::from))
+            .or_else(Config::default_path);
+
+        let file = match &path {
+            Some(path) if path.exists() => Some(fs::read_to_string(path)?),
+            Some(path) if cli.config_path.is_some() => {
+                return Err(io::Error::new(
+                    io::ErrorKind::NotFound,
+                    tf("config.file_not_found", &[&path.display()]),
+                ));
+            }
+            _ => None,
+        };
+
+        Config::from_layers(file.as_deref(), |name| env::var(name).ok(), &cli.overrides)
+    }
+
+    /// Monta a configuração a partir das camadas já lidas
+    pub fn from_layers<F>(
+        file: Option<&str>,
+        env_var: F,
+        overrides: &[(String, String)],
+    ) -> io::Result<Config>
+    where
+        F: Fn(&str) -> Option<String>,
+    {
+        let mut config = Config::default();
+
+        if let Some(content) = file {
+            config.apply_toml(content)?;
+        }
+
+        for key in KEYS {
+            if let Some(value) = env_var(&env_name(key)) {
+                config.set(key, &value)?;
+            }
+        }
+
+        for (key, value) in overrides {
+            config.set(key, value)?;
+        }
+
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// Aplica o conteúdo de um arquivo TOML
+    pub fn apply_toml(&mut self, content: &str) -> io::Result<()> {
+        let table: toml::Table = content
+            .parse()
+            .map_err(|e| invalid(tf("config.invalid_file", &[&e])))?;
+
+        for (key, value) in table {
+            let value = match value {
+                toml::Value::String(s) => s,
+                toml::Value::Boolean(b) => b.to_string(),
+                toml::Value::Integer(i) => i.to_string(),
+                other => {
+                    return Err(invalid(tf("config.invalid_value", &[&key, &other])));
+                }
+            };
+            self.set(&key, &value)?;
+        }
+
+        Ok(())
+    }
+
+    /// Altera uma chave a partir de texto
+    pub fn set(&mut self, key: &str, value: &str) -> io::Result<()> {
+        let bad_value = || invalid(tf("config.invalid_value", &[&key, &value]));
+
+        match key {
+            "storage_path" => self.storage_path = value.to_string(),
+            "storage_format" => {
+                self.storage_format = StorageFormat::parse(value).ok_or_else(bad_value)?
+            }
+            "encrypted" => self.encrypted = parse_bool(value).ok_or_else(bad_value)?,
+            "event_log" => self.event_log = value.to_string(),
+            "locale" => self.locale = value.to_string(),
+            "date_format" => self.date_format = value.to_string(),
+            "clear_screen" => self.clear_screen = parse_bool(value).ok_or_else(bad_value)?,
+            "colors" => self.colors = parse_bool(value).ok_or_else(bad_value)?,
+            "theme" => self.theme = Theme::parse(value).ok_or_else(bad_value)?,
+            "default_category" => {
+                self.default_category = Category::parse(value).ok_or_else(bad_value)?
+            }
+            "default_priority" => {
+                self.default_priority = Priority::parse(value).ok_or_else(bad_value)?
+            }
+            "ui" => {
+                self.ui = match value.to_lowercase().as_str() {
+                    "menu" => UiMode::Menu,
+                    "tui" => UiMode::Tui,
+                    _ => return Err(bad_value()),
+                }
+            }
+            "wip_limit_pending" => {
+                self.wip_limit_pending = value.trim().parse().map_err(|_| bad_value())?
+            }
+            "wip_limit_in_progress" => {
+                self.wip_limit_in_progress = value.trim().parse().map_err(|_| bad_value())?
+            }
+            "trash_retention_days" => {
+                self.trash_retention_days = value.trim().parse().map_err(|_| bad_value())?
+            }
+            "pomodoro_work_minutes" => {
+                self.pomodoro_work_minutes = value.trim().parse().map_err(|_| bad_value())?
+            }
+            "pomodoro_break_minutes" => {
+                self.pomodoro_break_minutes = value.trim().parse().map_err(|_| bad_value())?
+            }
+            "user" => self.user = value.trim().to_string(),
+            _ => return Err(invalid(tf("config.unknown_key", &[&key]))),
+        }
+
+        Ok(())
+    }
+
+    /// Valida a combinação de valores
+    pub fn validate(&self) -> io::Result<()> {
+        if self.storage_path.trim().is_empty() {
+            return Err(invalid(t("config.empty_storage").to_string()));
+        }
+
+        if self.encrypted && self.storage_format != StorageFormat::Json {
+            return Err(invalid(t("error.encryption_needs_json").to_string()));
+        }
+
+        if self.pomodoro_work_minutes == 0 {
+            return Err(invalid(t("config.zero_pomodoro").to_string()));
+        }
+
+        if self.locale != "auto" && Locale::parse(&self.locale).is_none() {
+            return Err(invalid(tf("config.unsupported_locale", &[&self.locale])));
+        }
+
+        if !self.date_format.is_empty() && !is_valid_date_format(&self.date_format) {
+            return Err(invalid(tf(
+                "config.invalid_date_format",
+                &[&self.date_format],
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Idioma efetivo
+    pub fn locale(&self) -> Locale {
+        Locale::parse(&self.locale).unwrap_or_else(Locale::from_env)
+    }
+
+    /// Formato de data configurado, se houver
+    pub fn date_format(&self) -> Option<String> {
+        if self.date_format.is_empty() {
+            None
+        } else {
+            Some(self.date_format.clone())
+        }
+    }
+
+    /// Usuário atual: o configurado ou, se vazio, o login do sistema
+    pub fn user(&self) -> Option<String> {
+        if !self.user.is_empty() {
+            return Some(self.user.clone());
+        }
+        env::var("USER")
+            .or_else(|_| env::var("USERNAME"))
+            .ok()
+            .filter(|user| !user.trim().is_empty())
+    }
+
+    /// Configuração efetiva em TOML
+    pub fn to_toml(&self) -> String {
+        let mut out = String::new();
+        let _ = writeln!(out, "storage_path = {:?}", self.storage_path);
+        let _ = writeln!(out, "storage_format = {:?}", self.storage_format.as_str());
+        let _ = writeln!(out, "encrypted = {}", self.encrypted);
+        let _ = writeln!(out, "event_log = {:?}", self.event_log);
+        let _ = writeln!(out, "locale = {:?}", self.locale);
+        let _ = writeln!(out, "date_format = {:?}", self.date_format);
+        let _ = writeln!(out, "clear_screen = {}", self.clear_screen);
+        let _ = writeln!(out, "colors = {}", self.colors);
+        let _ = writeln!(out, "theme = {:?}", self.theme.as_str());
+        let _ = writeln!(out, "default_category = {:?}", format!("{:?}", self.default_category));
+        let _ = writeln!(out, "default_priority = {:?}", format!("{:?}", self.default_priority));
+        let _ = writeln!(out, "ui = {:?}", self.ui.as_str());
+        let _ = writeln!(out, "wip_limit_pending = {}", self.wip_limit_pending);
+        let _ = writeln!(out, "wip_limit_in_progress = {}", self.wip_limit_in_progress);
+        let _ = writeln!(out, "trash_retention_days = {}", self.trash_retention_days);
+        let _ = writeln!(out, "pomodoro_work_minutes = {}", self.pomodoro_work_minutes);
+        let _ = writeln!(out, "pomodoro_break_minutes = {}", self.pomodoro_break_minutes);
+        let _ = writeln!(out, "user = {:?}", self.user);
+        out
+    }
+}
+
+/// Argumentos de linha de comando
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CliArgs {
+    /// `--config <arquivo>`
+    pub config_path: Option<PathBuf>,
+    /// `--show-config`
+    pub show_config: bool,
+    /// Valores definidos pelas demais flags, na ordem
+    pub overrides: Vec<(String, String)>,
+    /// `--output <text|json|jsonl>` (ou `--json`, `--jsonl`)
+    pub output: OutputFormat,
+    /// Comando de leitura e seus argumentos (ex.: `list --overdue`)
+    pub command: Vec<String>,
+}
+
+impl CliArgs {
+    /// Interpreta os argumentos (sem o nome do programa)
+    ///
+    /// Flags: `--config`, `--show-config`, `--storage`, `--event-log`,
+    /// `--lang`, `--date-format`, `--no-clear`, `--no-color`, `--theme`,
+    /// `--default-category`, `--default-priority`, `--wip-limit`, `--user`,
+    /// `--tui`, `--menu`, `--output`, `--json`, `--jsonl` e `--set chave=valor`.
+    ///
+    /// O primeiro argumento que não é flag inicia um comando (ex.: `list`);
+    /// dali em diante, o que não for flag conhecida vai para o comando.
+    pub fn parse<I>(args: I) -> io::Result<CliArgs>
+    where
+        I: IntoIterator<Item = String>,
+    {
+        let mut cli = CliArgs::default();
+        let mut args = args.into_iter();
+
+        while let Some(arg) = args.next() {
+            let mut value_for = |flag: &str| {
+                args.next()
+                    .ok_or_else(|| invalid(tf("config.missing_value", &[&flag])))
+            };
+
+            match arg.as_str() {
+                "--config" => cli.config_path = Some(PathBuf::from(value_for(&arg)?)),
+                "--show-config" => cli.show_config = true,
+                "--storage" => cli.push("storage_path", value_for(&arg)?),
+                "--storage-format" => cli.push("storage_format", value_for(&arg)?),
+                "--encrypted" => cli.push("encrypted", "true".to_string()),
+                "--event-log" => cli.push("event_log", value_for(&arg)?),
+                "--lang" => cli.push("locale", value_for(&arg)?),
+                "--date-format" => cli.push("date_format", value_for(&arg)?),
+                "--default-category" => cli.push("default_category", value_for(&arg)?),
+                "--default-priority" => cli.push("default_priority", value_for(&arg)?),
+                "--wip-limit" => cli.push("wip_limit_in_progress", value_for(&arg)?),
+                "--user" => cli.push("user", value_for(&arg)?),
+                "--theme" => cli.push("theme", value_for(&arg)?),
+                "--no-clear" => cli.push("clear_screen", "false".to_string()),
+                "--no-color" => cli.push("colors", "false".to_string()),
+                "--tui" => cli.push("ui", "tui".to_string()),
+                "--menu" => cli.push("ui", "menu".to_string()),
+                "--output" => {
+                    let value = value_for(&arg)?;
+                    cli.output = OutputFormat::parse(&value).ok_or_else(|| {
+                        invalid(tf("config.invalid_value", &[&"--output", &value]))
+                    })?;
+                }
+                "--json" => cli.output = OutputFormat::Json,
+                "--jsonl" => cli.output = OutputFormat::JsonLines,
+                "--set" => {
+                    let pair = value_for(&arg)?;
+                    let (key, value) = pair
+                        .split_once('=')
+                        .ok_or_else(|| invalid(tf("config.set_usage", &[&pair])))?;
+                    cli.push(key, value.to_string());
+                }
+                _ if !cli.command.is_empty() || !arg.starts_with('-') => cli.command.push(arg),
+                _ => return Err(invalid(tf("config.unknown_argument", &[&arg]))),
+            }
+        }
+
+        Ok(cli)
+    }
+
+    fn push(&mut self, key: &str, value: String) {
+        self.overrides.push((key.to_string(), value));
+    }
+}
+
+/// Nome da variável de ambiente de uma chave
+///
+/// O idioma usa `GERENCIADOR_LANG`, a mesma variável lida pelo i18n.
+fn env_name(key: &str) -> String {
+    match key {
+        "locale" => format!("{}LANG", ENV_PREFIX),
+        _ => format!("{}{}", ENV_PREFIX, key.to_uppercase()),
+    }
+}
+
+fn parse_bool(value: &str) -> Option<bool> {
+    match value.trim().to_lowercase().as_str() {
+        "true" | "1" | "sim" | "s" | "yes" | "y" | "on" => Some(true),
+        "false" | "0" | "nao" | "não" | "n" | "no" | "off" => Some(false),
+        _ => None,
+    }
+}
+
+/// Um formato é válido se formata e lê de volta a mesma data
+fn is_valid_date_format(format: &str) -> bool {
+    let sample = NaiveDate::from_ymd_opt(2024, 12, 31).unwrap();
+    let mut text = String::new();
+    if write!(text, "{}", sample.format(format)).is_err() {
+        return false;
+    }
+    NaiveDate::parse_from_str(&text, format) == Ok(sample)
+}
+
+fn invalid(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn no_env(_: &str) -> Option<String> {
+        None
+    }
+
+    fn pairs(items: &[(&str, &str)]) -> Vec<(String, String)> {
+        items
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_defaults() {
+        let config = Config::from_layers(None, no_env, &[]).unwrap();
+
+        assert_eq!(config, Config::default());
+        assert_eq!(config.storage_path, "data/tasks.json");
+        assert_eq!(config.date_format(), None);
+    }
+
+    #[test]
+    fn test_layers_override_in_order() {
+        let file = r#"
+            storage_path = "arquivo.json"
+            colors = false
+            default_priority = "High"
+        "#;
+        let env = |name: &str| match name {
+            "GERENCIADOR_STORAGE_PATH" => Some("ambiente.json".to_string()),
+            "GERENCIADOR_LANG" => Some("en-US".to_string()),
+            _ => None,
+        };
+        let cli = pairs(&[("storage_path", "cli.json"), ("ui", "tui")]);
+
+        let config = Config::from_layers(Some(file), env, &cli).unwrap();
+
+        assert_eq!(config.storage_path, "cli.json");
+        assert!(!config.colors);
+        assert_eq!(config.default_priority, Priority::High);
+        assert_eq!(config.locale(), Locale::EnUs);
+        assert_eq!(config.ui, UiMode::Tui);
+    }
+
+    #[test]
+    fn test_validation_errors() {
+        assert!(Config::from_layers(Some("cor = true"), no_env, &[]).is_err());
+        assert!(Config::from_layers(Some("colors = 3"), no_env, &[]).is_err());
+        assert!(Config::from_layers(Some("colors = \"talvez\""), no_env, &[]).is_err());
+        assert!(Config::from_layers(Some("theme = \"neon\""), no_env, &[]).is_err());
+        assert!(Config::from_layers(Some("storage_format = \"xml\""), no_env, &[]).is_err());
+        assert!(
+            Config::from_layers(Some("encrypted = true\nstorage_format = \"md\""), no_env, &[])
+                .is_err()
+        );
+        assert!(Config::from_layers(None, no_env, &pairs(&[("locale", "fr")])).is_err());
+        assert!(Config::from_layers(None, no_env, &pairs(&[("storage_path", " ")])).is_err());
+        assert!(Config::from_layers(None, no_env, &pairs(&[("date_format", "%d/%m")])).is_err());
+        assert!(Config::from_layers(None, no_env, &pairs(&[("date_format", "%Q")])).is_err());
+        assert!(
+            Config::from_layers(None, no_env, &pairs(&[("default_category", "xyz")])).is_err()
+        );
+        assert!(
+            Config::from_layers(None, no_env, &pairs(&[("wip_limit_pending", "-1")])).is_err()
+        );
+        assert!(
+            Config::from_layers(None, no_env, &pairs(&[("pomodoro_work_minutes", "0")])).is_err()
+        );
+    }
+
+    #[test]
+    fn test_to_toml_round_trip() {
+        let mut config = Config::default();
+        config.set("date_format", "%Y-%m-%d").unwrap();
+        config.set("default_category", "saúde").unwrap();
+        config.set("pomodoro_break_minutes", "10").unwrap();
+        config.set("clear_screen", "não").unwrap();
+        config.set("wip_limit_in_progress", "3").unwrap();
+        config.set("user", " ana ").unwrap();
+        config.set("theme", "Light").unwrap();
+        config.set("storage_format", "md").unwrap();
+
+        let loaded = Config::from_layers(Some(&config.to_toml()), no_env, &[]).unwrap();
+
+        assert_eq!(loaded, config);
+        assert_eq!(loaded.default_category, Category::Health);
+        assert_eq!(loaded.date_format(), Some("%Y-%m-%d".to_string()));
+        assert_eq!(loaded.wip_limit_in_progress, 3);
+        assert_eq!(loaded.user(), Some("ana".to_string()));
+        assert_eq!(loaded.theme, Theme::Light);
+    }
+
+    #[test]
+    fn test_cli_args() {
+        let args = [
+            "--config",
+            "meu.toml",
+            "--tui",
+            "--no-clear",
+            "--lang",
+            "en",
+            "--set",
+            "colors=false",
+            "--show-config",
+        ];
+        let cli = CliArgs::parse(args.iter().map(|a| a.to_string())).unwrap();
+
+        assert_eq!(cli.config_path, Some(PathBuf::from("meu.toml")));
+        assert!(cli.show_config);
+        assert_eq!(
+            cli.overrides,
+            pairs(&[
+                ("ui", "tui"),
+                ("clear_screen", "false"),
+                ("locale", "en"),
+                ("colors", "false"),
+            ])
+        );
+
+        assert_eq!(cli.output, OutputFormat::Text);
+        assert!(cli.command.is_empty());
+
+        assert!(CliArgs::parse(["--storage".to_string()]).is_err());
+        assert!(CliArgs::parse(["--desconhecida".to_string()]).is_err());
+        assert!(CliArgs::parse(["--output".to_string(), "xml".to_string()]).is_err());
+    }
+
+    #[test]
+    fn test_cli_command() {
+        let args = ["--storage", "t.json", "list", "--overdue", "--jsonl", "--status", "pendente"];
+        let cli = CliArgs::parse(args.iter().map(|a| a.to_string())).unwrap();
+
+        assert_eq!(cli.overrides, pairs(&[("storage_path", "t.json")]));
+        assert_eq!(cli.output, OutputFormat::JsonLines);
+        assert_eq!(cli.command, ["list", "--overdue", "--status", "pendente"]);
+    }
+}
@@ -0,0 +1,378 @@
+// src/i18n/en_us.rs
+
+/// Catálogo de mensagens (chave, texto)
+pub const MESSAGES: &[(&str, &str)] = &[
+    // Categorias
+    ("category.work", "Work"),
+    ("category.personal", "Personal"),
+    ("category.study", "Study"),
+    ("category.health", "Health"),
+    ("category.other", "Other"),
+    // Prioridades
+    ("priority.high", "High"),
+    ("priority.medium", "Medium"),
+    ("priority.low", "Low"),
+    // Status
+    ("status.pending", "Pending"),
+    ("status.in_progress", "In Progress"),
+    ("status.completed", "Completed"),
+    // Project colors
+    ("color.red", "Red"),
+    ("color.green", "Green"),
+    ("color.yellow", "Yellow"),
+    ("color.blue", "Blue"),
+    ("color.magenta", "Magenta"),
+    ("color.cyan", "Cyan"),
+    ("color.white", "White"),
+    // Erros
+    ("error.task_not_found", "Task not found"),
+    ("error.file_not_found", "File not found"),
+    ("error.end_of_input", "End of input"),
+    ("error.tui", "Interface error: {}"),
+    ("error.menu", "Menu error: {}"),
+    ("error.event_log", "Event log disabled: {}"),
+    ("error.timer_running", "A timer is already running on task {} ({})"),
+    ("error.timer_not_running", "This task's timer is not running"),
+    ("error.invalid_minutes", "Enter a number of minutes greater than zero"),
+    ("error.wip_limit", "WIP limit reached for column \"{}\" ({} tasks)"),
+    ("error.archive_not_completed", "Only completed tasks can be archived"),
+    ("error.invalid_dependency", "A task cannot depend on itself"),
+    ("error.empty_project_name", "Project name cannot be empty"),
+    ("error.project_exists", "A project named '{}' already exists"),
+    ("error.project_not_found", "Project not found: {}"),
+    ("error.empty_template_name", "Template name cannot be empty"),
+    ("error.template_exists", "A template named '{}' already exists"),
+    ("error.template_not_found", "Template not found: {}"),
+    ("error.empty_template", "A template needs at least one task"),
+    ("error.template_dependency", "Template task {} depends on a task that is not in the template"),
+    ("error.template_missing_variable", "Missing value for variable '{}'"),
+    ("error.template_due_out_of_range", "Due date out of range in '{}': {} day(s)"),
+    ("error.empty_note", "The note cannot be empty"),
+    ("error.invalid_attachment", "Invalid file to attach: {}"),
+    ("error.attachment_exists", "The task already has the attachment '{}'"),
+    ("error.attachment_not_found", "Attachment not found"),
+    ("error.attachment_broken", "Attachment missing or corrupted: {}"),
+    ("error.attachment_hash", "Invalid attachment hash: {}"),
+    ("error.invalid_task_file", "Invalid task file: the header must be between {} lines"),
+    ("error.front_matter_line", "A line with only {} in a text field could not be escaped in task #{}"),
+    ("error.not_a_task_file", "Not a task file: {}"),
+    ("error.wrong_passphrase", "Wrong passphrase (or tampered file): could not open {}"),
+    ("error.passphrase_required", "{} is encrypted; enter the passphrase"),
+    ("error.storage_encrypted", "{} is encrypted; open it with the passphrase to save"),
+    ("error.unsupported_encryption", "{}: unsupported encryption format"),
+    ("error.encryption_failed", "Failed to encrypt the data"),
+    ("error.empty_passphrase", "The passphrase cannot be empty"),
+    ("error.passphrase_mismatch", "Passphrases do not match"),
+    ("error.encryption_needs_json", "Encryption only works with storage_format = \"json\""),
+    // Entrada
+    ("input.press_enter", "\nPress Enter to continue..."),
+    ("input.yes_no", "(y/n)"),
+    // Campos da tarefa
+    ("field.id", "ID"),
+    ("field.uuid", "UUID"),
+    ("field.title", "Title"),
+    ("field.description", "Description"),
+    ("field.category", "Category"),
+    ("field.priority", "Priority"),
+    ("field.status", "Status"),
+    ("field.due_date", "Due date"),
+    ("field.created_at", "Created at"),
+    ("field.completed_at", "Completed at"),
+    ("field.time_spent", "Time spent"),
+    ("field.estimate", "Estimate"),
+    ("field.blocked_by", "Blocked by"),
+    ("field.assignee", "Assignee"),
+    ("field.created_by", "Created by"),
+    ("field.project", "Project"),
+    ("field.pomodoros", "Pomodoros"),
+    // Saída
+    ("output.error", "Error: {}"),
+    ("output.no_tasks", "No tasks found."),
+    ("output.overdue_tag", " (OVERDUE!)"),
+    ("output.total_tasks", "Total: {} task(s)"),
+    ("output.timer_running_tag", " (⏱ running)"),
+    ("output.over_estimate_tag", " (over estimate!)"),
+    ("output.notes", "Notes:"),
+    ("output.attachments", "Attachments:"),
+    ("output.broken_attachment", "Attachment missing or corrupted: task #{}, {}"),
+    ("output.skipped_task_file", "Unreadable task file, skipped until fixed: {}"),
+    // Estatísticas
+    ("stats.title", "STATISTICS"),
+    ("stats.summary", "Overview:"),
+    ("stats.total", "Total tasks: {}"),
+    ("stats.completed", "Completed: {}"),
+    ("stats.in_progress", "In progress: {}"),
+    ("stats.pending", "Pending: {}"),
+    ("stats.overdue", "Overdue: {}"),
+    ("stats.by_category", "By Category:"),
+    ("stats.by_priority", "By Priority:"),
+    ("stats.time", "Time:"),
+    ("stats.tracked", "Tracked: {}"),
+    ("stats.estimate_vs_actual", "Estimated vs actual: {} / {}"),
+    ("stats.over_estimate", "Over estimate: {}"),
+    ("stats.focus", "Focus:"),
+    ("stats.pomodoros", "{} pomodoro(s), {} of focus"),
+    ("stats.by_user", "By Assignee:"),
+    ("stats.group_line", "{}: {} task(s), {} completed, {} overdue"),
+    ("stats.unassigned", "(unassigned)"),
+    ("stats.by_project", "By Project:"),
+    ("stats.no_project", "(no project)"),
+    // Productivity analytics
+    ("analytics.title", "PRODUCTIVITY ANALYTICS"),
+    ("analytics.period", "Period: {} to {}"),
+    ("analytics.per_day", "Completed per day:"),
+    ("analytics.per_week", "Completed per week:"),
+    ("analytics.lead_time", "Average time to completion, by category:"),
+    ("analytics.overdue_rate", "Overdue rate by due week:"),
+    ("analytics.burndown", "Burndown (open tasks):"),
+    ("analytics.no_data", "No data in this period."),
+    // Kanban board
+    ("board.title", "KANBAN BOARD"),
+    ("board.legend", "↑ high  → medium  ↓ low  ! overdue"),
+    // Scoring
+    ("score.priority", "{} priority"),
+    ("score.due_today", "due today"),
+    ("score.due_in", "due in {} day(s)"),
+    ("score.overdue", "overdue by {} day(s)"),
+    ("score.age", "created {} day(s) ago"),
+    ("score.blocked", "blocked by another task"),
+    ("score.points", "{} pts"),
+    // Menu
+    ("menu.title", "TASK MANAGER"),
+    ("menu.create", "Create new task"),
+    ("menu.list", "List all tasks"),
+    ("menu.view", "View task details"),
+    ("menu.update", "Update task"),
+    ("menu.delete", "Delete task"),
+    ("menu.filter", "Filter tasks"),
+    ("menu.statistics", "Statistics"),
+    ("menu.analytics", "Productivity analytics"),
+    ("menu.board", "Kanban board"),
+    ("menu.trash_archive", "Trash and archive"),
+    ("menu.bulk", "Bulk operations"),
+    ("menu.quick_add", "Quick add"),
+    ("menu.next_up", "What should I do next?"),
+    ("menu.projects", "Projects"),
+    ("menu.encryption", "Encryption"),
+    ("menu.templates", "Templates"),
+    ("menu.prompt_from", "Start date ({}) or Enter for {}: "),
+    ("menu.prompt_to", "End date ({}) or Enter for {}: "),
+    ("menu.invalid_range", "The start date must come before the end date!"),
+    ("menu.exit", "Exit"),
+    ("menu.choose_option", "Choose an option: "),
+    ("menu.goodbye", "Goodbye! 👋"),
+    ("menu.invalid_option", "Invalid option!"),
+    ("menu.create_title", "CREATE NEW TASK"),
+    ("menu.prompt_title", "Title: "),
+    ("menu.empty_title", "Title cannot be empty!"),
+    ("menu.prompt_description", "Description: "),
+    ("menu.categories", "\nCategories:"),
+    ("menu.choose_category", "Choose the category: "),
+    ("menu.invalid_category", "Invalid category!"),
+    ("menu.priorities", "\nPriorities:"),
+    ("menu.choose_priority", "Choose the priority: "),
+    ("menu.invalid_priority", "Invalid priority!"),
+    ("menu.prompt_due_date", "Due date ({}) or Enter to skip: "),
+    ("menu.invalid_date", "Invalid date! Use the format {}"),
+    ("menu.task_created", "Task created with ID: {}"),
+    ("menu.create_error", "Failed to create task: {}"),
+    ("menu.list_title", "ALL TASKS"),
+    ("menu.view_title", "TASK DETAILS"),
+    ("menu.prompt_id", "Task ID: "),
+    ("menu.invalid_id", "Invalid ID!"),
+    ("menu.actions", "Actions:"),
+    ("menu.action_start", "Start task"),
+    ("menu.action_complete", "Mark as completed"),
+    ("menu.action_start_timer", "Start timer"),
+    ("menu.action_stop_timer", "Stop timer"),
+    ("menu.action_log_time", "Log time manually"),
+    ("menu.action_set_estimate", "Set estimate"),
+    ("menu.back", "Back"),
+    ("menu.choose_action", "\nChoose an action: "),
+    ("menu.task_started", "Task started!"),
+    ("menu.task_completed", "Task completed!"),
+    ("menu.timer_started", "Timer started!"),
+    ("menu.timer_stopped", "Timer stopped: {}"),
+    ("menu.prompt_minutes", "Minutes worked: "),
+    ("menu.time_logged", "Time logged!"),
+    ("menu.prompt_estimate", "Estimate in minutes (Enter to clear): "),
+    ("menu.estimate_set", "Estimate updated!"),
+    ("menu.invalid_minutes", "Invalid minutes!"),
+    ("menu.task_not_found", "Task not found!"),
+    ("menu.update_title", "UPDATE TASK"),
+    ("menu.keep_blank", "Leave blank to keep the current value."),
+    ("menu.prompt_new_title", "New title: "),
+    ("menu.prompt_new_description", "New description: "),
+    ("menu.task_updated", "Task updated successfully!"),
+    ("menu.update_error", "Failed to update: {}"),
+    ("menu.delete_title", "DELETE TASK"),
+    (
+        "menu.confirm_delete",
+        "\nAre you sure you want to delete this task?",
+    ),
+    ("menu.task_deleted", "Task moved to the trash!"),
+    ("menu.delete_error", "Failed to delete: {}"),
+    ("menu.cancelled", "Operation cancelled."),
+    ("menu.trash_title", "TRASH AND ARCHIVE"),
+    ("menu.trash_list", "Show trash"),
+    ("menu.trash_restore", "Restore from trash"),
+    ("menu.trash_purge", "Permanently delete from trash"),
+    ("menu.trash_empty", "Empty trash"),
+    ("menu.archive_completed", "Archive completed tasks"),
+    ("menu.archive_list", "Show archived"),
+    ("menu.archive_restore", "Unarchive task"),
+    ("menu.statistics_all", "Statistics including archived"),
+    ("menu.trash_header", "IN THE TRASH"),
+    ("menu.archive_header", "ARCHIVED"),
+    ("menu.task_restored", "Task restored!"),
+    ("menu.task_purged", "Task permanently deleted!"),
+    ("menu.confirm_purge", "Delete permanently? This cannot be undone "),
+    ("menu.trash_emptied", "{} task(s) permanently deleted."),
+    ("menu.tasks_archived", "{} task(s) archived."),
+    ("menu.bulk_title", "BULK OPERATIONS"),
+    ("menu.prompt_ids", "IDs (e.g. 3-7,10): "),
+    ("menu.invalid_ids", "Invalid ID list!"),
+    ("menu.bulk_set_priority", "Change priority"),
+    ("menu.bulk_set_category", "Change category"),
+    ("menu.bulk_set_due_date", "Change due date"),
+    ("menu.prompt_bulk_due_date", "New due date ({}) or Enter to clear: "),
+    ("menu.confirm_bulk_delete", "Move {} task(s) to the trash?"),
+    ("menu.bulk_summary", "{} task(s) changed, {} failed."),
+    ("menu.quick_add_title", "QUICK ADD"),
+    ("menu.prompt_quick_add", "Task (e.g. Review PR tomorrow 3pm #work !high): "),
+    ("menu.next_up_title", "WHAT TO DO NEXT"),
+    ("menu.action_dependencies", "Set dependencies"),
+    ("menu.prompt_dependencies", "IDs blocking this task (e.g. 3,5) or Enter for none: "),
+    ("menu.dependencies_set", "Dependencies updated!"),
+    ("menu.action_assign", "Assign"),
+    ("menu.prompt_assignee", "Assignee (Enter removes): "),
+    ("menu.task_assigned", "Assignee updated!"),
+    ("menu.no_current_user", "No current user set (use --user or the user key)"),
+    ("menu.active_project", "📁 Active project: {}"),
+    ("menu.projects_title", "PROJECTS"),
+    ("menu.project_list", "List projects"),
+    ("menu.project_create", "Create project"),
+    ("menu.project_switch", "Switch active project"),
+    ("menu.project_move", "Move task to another project"),
+    ("menu.project_delete", "Delete project"),
+    ("menu.project_statistics", "Statistics by project"),
+    ("menu.prompt_project_name", "Project name: "),
+    ("menu.prompt_project_description", "Description: "),
+    ("menu.prompt_project_switch", "Project (Enter = all tasks): "),
+    ("menu.prompt_project_move", "Target project (Enter = no project): "),
+    ("menu.choose_color", "Choose the color (Enter = {}): "),
+    ("menu.project_created", "Project '{}' created!"),
+    ("menu.project_switched", "Active project: {}"),
+    ("menu.project_cleared", "Showing all tasks"),
+    ("menu.task_moved", "Task moved!"),
+    ("menu.confirm_delete_project", "Delete the project? Its tasks will have no project"),
+    ("menu.project_deleted", "Project deleted!"),
+    ("menu.no_projects", "No projects yet."),
+    ("menu.project_line", "{} ({}) — {} task(s)"),
+    ("menu.encryption_title", "ENCRYPTION"),
+    ("menu.encryption_on", "🔐 Tasks encrypted on disk"),
+    ("menu.encryption_off", "🔓 Tasks in plain text on disk"),
+    ("menu.passphrase_change", "Change passphrase"),
+    ("menu.passphrase_set", "Encrypt with a passphrase"),
+    ("menu.decrypt_export", "Export tasks without encryption"),
+    ("menu.prompt_passphrase", "Passphrase: "),
+    ("menu.prompt_current_passphrase", "Current passphrase: "),
+    ("menu.prompt_new_passphrase", "New passphrase: "),
+    ("menu.prompt_confirm_passphrase", "Confirm the new passphrase: "),
+    ("menu.passphrase_changed", "Passphrase set; files encrypted again!"),
+    ("menu.prompt_export_path", "Target file: "),
+    ("menu.export_warning", "The exported file is plain text, unprotected"),
+    ("menu.exported", "{} task(s) exported to {}"),
+    ("menu.action_add_note", "Add note"),
+    ("menu.action_attach", "Attach file"),
+    ("menu.action_attachments", "Attachments"),
+    ("menu.action_focus", "Focus session (Pomodoro)"),
+    ("menu.no_attachments", "No attachments."),
+    ("menu.prompt_note", "Note: "),
+    ("menu.note_added", "Note added!"),
+    ("menu.prompt_attachment_path", "File path: "),
+    ("menu.file_attached", "File '{}' attached!"),
+    ("menu.prompt_remove_attachment", "Attachment number to remove (Enter goes back): "),
+    ("menu.attachment_encrypted", "Encrypted; save a copy to open it"),
+    ("menu.prompt_save_attachment", "Attachment number to save a copy of (Enter skips): "),
+    ("menu.prompt_save_attachment_path", "Save the copy to: "),
+    ("menu.attachment_saved", "Copy of '{}' saved to {}"),
+    ("menu.attachment_removed", "Attachment '{}' removed!"),
+    ("menu.filter_title", "FILTER TASKS"),
+    ("menu.filter_status", "By Status"),
+    ("menu.filter_category", "By Category"),
+    ("menu.filter_priority", "By Priority"),
+    ("menu.filter_overdue", "Overdue Tasks"),
+    ("menu.filter_mine", "My Tasks"),
+    ("menu.filter_assigned_by_me", "Assigned by Me"),
+    ("menu.choose_filter", "\nChoose the filter: "),
+    ("menu.status_pending", "Pending"),
+    ("menu.status_in_progress", "In Progress"),
+    ("menu.status_completed", "Completed"),
+    ("menu.choose_status", "Choose the status: "),
+    ("menu.choose_with_default", "Choose (Enter = {}): "),
+    ("menu.templates_title", "TEMPLATES"),
+    ("menu.template_list", "List templates"),
+    ("menu.template_use", "Create tasks from a template"),
+    ("menu.template_save", "Save tasks as a template"),
+    ("menu.template_delete", "Delete template"),
+    ("menu.no_templates", "No templates yet."),
+    ("menu.template_line", "{} — {} task(s)"),
+    ("menu.choose_template", "Choose the template: "),
+    ("menu.prompt_variable", "Value for '{}': "),
+    ("menu.prompt_template_start", "Count due dates from ({}) or Enter for {}: "),
+    ("menu.template_used", "{} task(s) created: {}"),
+    ("menu.prompt_template_name", "Template name: "),
+    ("menu.template_saved", "Template '{}' saved!"),
+    ("menu.confirm_delete_template", "Delete the template? Tasks already created are kept"),
+    ("menu.template_deleted", "Template deleted!"),
+    // Interface em tela cheia
+    ("tui.tasks", "Tasks"),
+    ("tui.tasks_filtered", "Tasks (filter: {})"),
+    ("tui.details", "Details"),
+    (
+        "tui.help",
+        "↑↓ move  / filter  n new  e title  E description  s start  c complete  t timer  p priority  g category  d delete  q quit",
+    ),
+    (
+        "tui.filter_prompt",
+        "Filter: {}▏  (Enter applies, Esc clears)",
+    ),
+    ("tui.prompt_new_task", "New task (e.g. Review PR tomorrow #work !high): "),
+    ("tui.priority_changed", "Priority changed!"),
+    ("tui.category_changed", "Category changed!"),
+    ("tui.timer_stopped", "Timer stopped!"),
+    // Configuração
+    ("config.file_not_found", "Configuration file not found: {}"),
+    ("config.invalid_file", "Invalid configuration file: {}"),
+    ("config.invalid_value", "Invalid value for '{}': {}"),
+    ("config.unknown_key", "Unknown configuration key: {}"),
+    ("config.empty_storage", "storage_path cannot be empty"),
+    ("config.zero_pomodoro", "pomodoro_work_minutes must be greater than zero"),
+    ("config.unsupported_locale", "Unsupported language: {}"),
+    ("config.invalid_date_format", "Invalid date format: {} (must contain day, month and year, e.g. %m/%d/%Y)"),
+    ("config.missing_value", "Missing value for {}"),
+    ("config.set_usage", "Use --set key=value: {}"),
+    ("config.unknown_argument", "Unknown argument: {}"),
+    // Commands
+    ("command.unknown", "Unknown command: {} (use list, show, stats, next, projects, analytics, merge-file, sync or decrypt-export)"),
+    ("command.invalid_argument", "Invalid argument for {}: {}"),
+    ("command.text_only", "merge-file, sync and decrypt-export write files and only have text output (no --json or --jsonl)"),
+    // Merge
+    ("merge.conflict_field", "Conflict in task #{}: '{}' changed on both sides"),
+    ("merge.conflict_deleted", "Conflict in task #{}: deleted on one side and changed on the other (kept the changed one)"),
+    ("merge.renumbered", "Task #{} created on both sides; the other one is now #{}"),
+    ("merge.kept_local", "(kept the local version)"),
+    // Sync
+    ("sync.summary", "Sync finished: {} received, {} sent, {} updated, {} deleted"),
+    ("sync.latest_wins", "(kept the most recent change)"),
+    // Focus session
+    ("focus.hint", "p pause/resume · q quit"),
+    ("focus.work", "🍅 Focus"),
+    ("focus.break", "☕ Break"),
+    ("focus.paused", "⏸️  Paused"),
+    ("focus.completed", "🍅 Pomodoro done! ({} this session)"),
+    ("focus.break_over", "☕ Break over; p starts the next pomodoro"),
+    ("focus.summary", "{} pomodoro(s) completed on #{}"),
+    ("focus.needs_terminal", "The focus session needs a terminal"),
+];
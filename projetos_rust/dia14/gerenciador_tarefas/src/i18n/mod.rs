@@ -0,0 +1,254 @@
+// src/i18n/mod.rs
+mod en_us;
+mod pt_br;
+
+use std::cell::{Cell, RefCell};
+use std::env;
+use std::fmt::Display;
+
+/// Idiomas suportados
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Locale {
+    PtBr,
+    EnUs,
+}
+
+impl Locale {
+    pub fn all() -> Vec<Locale> {
+        vec![Locale::PtBr, Locale::EnUs]
+    }
+
+    /// Código do idioma (ex.: "pt-BR")
+    pub fn code(&self) -> &'static str {
+        match self {
+            Locale::PtBr => "pt-BR",
+            Locale::EnUs => "en-US",
+        }
+    }
+
+    /// Interpreta códigos como "pt-BR", "en_US.UTF-8" ou "en"
+    pub fn parse(value: &str) -> Option<Locale> {
+        let lang = value
+            .split(['.', '@'])
+            .next()
+            .unwrap_or("")
+            .replace('_', "-")
+            .to_lowercase();
+
+        if lang == "pt" || lang.starts_with("pt-") {
+            Some(Locale::PtBr)
+        } else if lang == "en" || lang.starts_with("en-") {
+            Some(Locale::EnUs)
+        } else {
+            None
+        }
+    }
+
+    /// Detecta o idioma pelas variáveis de ambiente
+    ///
+    /// Ordem: `GERENCIADOR_LANG`, `LC_ALL`, `LC_MESSAGES`, `LANG`.
+    /// Sem nenhuma reconhecida, usa pt-BR.
+    pub fn from_env() -> Locale {
+        ["GERENCIADOR_LANG", "LC_ALL", "LC_MESSAGES", "LANG"]
+            .iter()
+            .filter_map(|var| env::var(var).ok())
+            .find_map(|value| Locale::parse(&value))
+            .unwrap_or(Locale::PtBr)
+    }
+
+    /// Formato de data (chrono)
+    pub fn date_format(&self) -> &'static str {
+        match self {
+            Locale::PtBr => "%d/%m/%Y",
+            Locale::EnUs => "%m/%d/%Y",
+        }
+    }
+
+    /// Formato de data e hora (chrono)
+    pub fn datetime_format(&self) -> &'static str {
+        match self {
+            Locale::PtBr => "%d/%m/%Y %H:%M",
+            Locale::EnUs => "%m/%d/%Y %I:%M %p",
+        }
+    }
+
+    /// Formato de data mostrado ao usuário nos prompts
+    pub fn date_hint(&self) -> &'static str {
+        match self {
+            Locale::PtBr => "DD/MM/AAAA",
+            Locale::EnUs => "MM/DD/YYYY",
+        }
+    }
+
+    fn catalog(&self) -> &'static [(&'static str, &'static str)] {
+        match self {
+            Locale::PtBr => pt_br::MESSAGES,
+            Locale::EnUs => en_us::MESSAGES,
+        }
+    }
+}
+
+thread_local! {
+    static CURRENT: Cell<Locale> = const { Cell::new(Locale::PtBr) };
+    static DATE_FORMAT: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Define o idioma da thread atual
+pub fn set_locale(locale: Locale) {
+    CURRENT.with(|current| current.set(locale));
+}
+
+/// Idioma da thread atual (pt-BR por padrão)
+pub fn locale() -> Locale {
+    CURRENT.with(|current| current.get())
+}
+
+/// Substitui o formato de data do idioma (None volta ao padrão)
+pub fn set_date_format(format: Option<String>) {
+    DATE_FORMAT.with(|current| *current.borrow_mut() = format);
+}
+
+/// Formato de data em uso: o configurado ou o do idioma atual
+pub fn date_format() -> String {
+    DATE_FORMAT
+        .with(|current| current.borrow().clone())
+        .unwrap_or_else(|| locale().date_format().to_string())
+}
+
+/// Formato de data e hora em uso
+pub fn datetime_format() -> String {
+    match DATE_FORMAT.with(|current| current.borrow().clone()) {
+        Some(format) => format!("{} %H:%M", format),
+        None => locale().datetime_format().to_string(),
+    }
+}
+
+/// Formato de data em uso, legível para prompts (ex.: "DD/MM/AAAA")
+pub fn date_hint() -> String {
+    match DATE_FORMAT.with(|current| current.borrow().clone()) {
+        Some(format) => {
+            let year = if locale() == Locale::PtBr { "AAAA" } else { "YYYY" };
+            format
+                .replace("%d", "DD")
+                .replace("%m", "MM")
+                .replace("%Y", year)
+        }
+        None => locale().date_hint().to_string(),
+    }
+}
+
+/// Busca uma mensagem no catálogo do idioma atual
+///
+/// Chaves ausentes caem para o pt-BR e, por último, para a própria chave.
+pub fn t(key: &'static str) -> &'static str {
+    t_in(locale(), key)
+}
+
+/// Busca uma mensagem e substitui cada `{}` pelos argumentos, em ordem
+pub fn tf(key: &'static str, args: &[&dyn Display]) -> String {
+    let mut result = String::new();
+    let mut args = args.iter();
+
+    for (i, part) in t(key).split("{}").enumerate() {
+        if i > 0 {
+            match args.next() {
+                Some(arg) => result.push_str(&arg.to_string()),
+                None => result.push_str("{}"),
+            }
+        }
+        result.push_str(part);
+    }
+
+    result
+}
+
+/// Busca uma mensagem no catálogo de um idioma específico
+pub fn t_in(locale: Locale, key: &'static str) -> &'static str {
+    lookup(locale, key)
+        .or_else(|| lookup(Locale::PtBr, key))
+        .unwrap_or(key)
+}
+
+fn lookup(locale: Locale, key: &str) -> Option<&'static str> {
+    locale
+        .catalog()
+        .iter()
+        .find(|(k, _)| *k == key)
+        .map(|(_, v)| *v)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Category, Priority, Status};
+
+    #[test]
+    fn test_catalogs_have_same_keys() {
+        let pt: Vec<&str> = pt_br::MESSAGES.iter().map(|(k, _)| *k).collect();
+        let en: Vec<&str> = en_us::MESSAGES.iter().map(|(k, _)| *k).collect();
+
+        for key in &pt {
+            assert!(en.contains(key), "chave ausente em en-US: {}", key);
+        }
+        for key in &en {
+            assert!(pt.contains(key), "chave ausente em pt-BR: {}", key);
+        }
+    }
+
+    #[test]
+    fn test_placeholders_match() {
+        for (key, pt) in pt_br::MESSAGES {
+            let en = lookup(Locale::EnUs, key).unwrap();
+            assert_eq!(
+                pt.matches("{}").count(),
+                en.matches("{}").count(),
+                "placeholders diferentes em {}",
+                key
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_locale() {
+        assert_eq!(Locale::parse("pt_BR.UTF-8"), Some(Locale::PtBr));
+        assert_eq!(Locale::parse("en-US"), Some(Locale::EnUs));
+        assert_eq!(Locale::parse("en"), Some(Locale::EnUs));
+        assert_eq!(Locale::parse("C"), None);
+    }
+
+    #[test]
+    fn test_translate_enums() {
+        assert_eq!(Category::Work.as_str(), "Trabalho");
+
+        set_locale(Locale::EnUs);
+        assert_eq!(Category::Work.as_str(), "Work");
+        assert_eq!(Priority::High.as_str(), "High");
+        assert_eq!(Status::InProgress.as_str(), "In Progress");
+        set_locale(Locale::PtBr);
+
+        assert_eq!(Status::InProgress.as_str(), "Em Andamento");
+    }
+
+    #[test]
+    fn test_date_format_override() {
+        assert_eq!(date_format(), "%d/%m/%Y");
+
+        set_date_format(Some("%Y-%m-%d".to_string()));
+        assert_eq!(date_format(), "%Y-%m-%d");
+        assert_eq!(datetime_format(), "%Y-%m-%d %H:%M");
+        assert_eq!(date_hint(), "AAAA-MM-DD");
+        set_date_format(None);
+
+        assert_eq!(date_hint(), "DD/MM/AAAA");
+    }
+
+    #[test]
+    fn test_tf_and_fallback() {
+        set_locale(Locale::EnUs);
+        assert_eq!(tf("menu.task_created", &[&7]), "Task created with ID: 7");
+        set_locale(Locale::PtBr);
+
+        assert_eq!(tf("menu.task_created", &[&7]), "Tarefa criada com ID: 7");
+        assert_eq!(t("chave.inexistente"), "chave.inexistente");
+    }
+}
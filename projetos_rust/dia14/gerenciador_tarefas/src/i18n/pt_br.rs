@@ -0,0 +1,384 @@
+// src/i18n/pt_br.rs
+
+/// Catálogo de mensagens (chave, texto)
+pub const MESSAGES: &[(&str, &str)] = &[
+    // Categorias
+    ("category.work", "Trabalho"),
+    ("category.personal", "Pessoal"),
+    ("category.study", "Estudos"),
+    ("category.health", "Saúde"),
+    ("category.other", "Outro"),
+    // Prioridades
+    ("priority.high", "Alta"),
+    ("priority.medium", "Média"),
+    ("priority.low", "Baixa"),
+    // Status
+    ("status.pending", "Pendente"),
+    ("status.in_progress", "Em Andamento"),
+    ("status.completed", "Concluída"),
+    // Cores de projeto
+    ("color.red", "Vermelho"),
+    ("color.green", "Verde"),
+    ("color.yellow", "Amarelo"),
+    ("color.blue", "Azul"),
+    ("color.magenta", "Magenta"),
+    ("color.cyan", "Ciano"),
+    ("color.white", "Branco"),
+    // Erros
+    ("error.task_not_found", "Tarefa não encontrada"),
+    ("error.file_not_found", "Arquivo não encontrado"),
+    ("error.end_of_input", "Fim da entrada"),
+    ("error.tui", "Erro na interface: {}"),
+    ("error.menu", "Erro no menu: {}"),
+    ("error.event_log", "Log de eventos desativado: {}"),
+    ("error.timer_running", "Já existe um cronômetro rodando na tarefa {} ({})"),
+    ("error.timer_not_running", "O cronômetro desta tarefa não está rodando"),
+    ("error.invalid_minutes", "Informe uma quantidade de minutos maior que zero"),
+    ("error.wip_limit", "Limite de WIP da coluna \"{}\" atingido ({} tarefas)"),
+    ("error.archive_not_completed", "Só tarefas concluídas podem ser arquivadas"),
+    ("error.invalid_dependency", "Uma tarefa não pode depender de si mesma"),
+    ("error.empty_project_name", "Nome do projeto não pode ser vazio"),
+    ("error.project_exists", "Já existe um projeto chamado '{}'"),
+    ("error.project_not_found", "Projeto não encontrado: {}"),
+    ("error.empty_template_name", "Nome do modelo não pode ser vazio"),
+    ("error.template_exists", "Já existe um modelo chamado '{}'"),
+    ("error.template_not_found", "Modelo não encontrado: {}"),
+    ("error.empty_template", "O modelo precisa de pelo menos uma tarefa"),
+    ("error.template_dependency", "A tarefa {} do modelo depende de uma tarefa que não está nele"),
+    ("error.template_missing_variable", "Faltou o valor da variável '{}'"),
+    ("error.template_due_out_of_range", "Prazo fora do calendário em '{}': {} dia(s)"),
+    ("error.empty_note", "A nota não pode ser vazia"),
+    ("error.invalid_attachment", "Arquivo inválido para anexar: {}"),
+    ("error.attachment_exists", "A tarefa já tem o anexo '{}'"),
+    ("error.attachment_not_found", "Anexo não encontrado"),
+    ("error.attachment_broken", "Anexo ausente ou corrompido: {}"),
+    ("error.attachment_hash", "Hash de anexo inválido: {}"),
+    ("error.invalid_task_file", "Arquivo de tarefa inválido: o cabeçalho precisa estar entre linhas {}"),
+    ("error.front_matter_line", "Uma linha só com {} num campo de texto não pôde ser protegida na tarefa #{}"),
+    ("error.not_a_task_file", "Não é um arquivo de tarefas: {}"),
+    ("error.wrong_passphrase", "Senha incorreta (ou arquivo adulterado): não foi possível abrir {}"),
+    ("error.passphrase_required", "{} está criptografado; informe a senha"),
+    ("error.storage_encrypted", "{} está criptografado; abra com a senha para salvar"),
+    ("error.unsupported_encryption", "{}: formato de criptografia não suportado"),
+    ("error.encryption_failed", "Falha ao criptografar os dados"),
+    ("error.empty_passphrase", "A senha não pode ser vazia"),
+    ("error.passphrase_mismatch", "As senhas não conferem"),
+    ("error.encryption_needs_json", "A criptografia só funciona com storage_format = \"json\""),
+    // Entrada
+    ("input.press_enter", "\nPressione Enter para continuar..."),
+    ("input.yes_no", "(s/n)"),
+    // Campos da tarefa
+    ("field.id", "ID"),
+    ("field.uuid", "UUID"),
+    ("field.title", "Título"),
+    ("field.description", "Descrição"),
+    ("field.category", "Categoria"),
+    ("field.priority", "Prioridade"),
+    ("field.status", "Status"),
+    ("field.due_date", "Vencimento"),
+    ("field.created_at", "Criada em"),
+    ("field.completed_at", "Concluída em"),
+    ("field.time_spent", "Tempo gasto"),
+    ("field.estimate", "Estimativa"),
+    ("field.blocked_by", "Bloqueada por"),
+    ("field.assignee", "Responsável"),
+    ("field.created_by", "Criada por"),
+    ("field.project", "Projeto"),
+    ("field.pomodoros", "Pomodoros"),
+    // Saída
+    ("output.error", "Erro: {}"),
+    ("output.no_tasks", "Nenhuma tarefa encontrada."),
+    ("output.overdue_tag", " (ATRASADA!)"),
+    ("output.total_tasks", "Total: {} tarefa(s)"),
+    ("output.timer_running_tag", " (⏱ rodando)"),
+    ("output.over_estimate_tag", " (acima da estimativa!)"),
+    ("output.notes", "Notas:"),
+    ("output.attachments", "Anexos:"),
+    ("output.broken_attachment", "Anexo ausente ou corrompido: tarefa #{}, {}"),
+    ("output.skipped_task_file", "Arquivo de tarefa ilegível, ignorado até ser corrigido: {}"),
+    // Estatísticas
+    ("stats.title", "ESTATÍSTICAS"),
+    ("stats.summary", "Resumo Geral:"),
+    ("stats.total", "Total de tarefas: {}"),
+    ("stats.completed", "Concluídas: {}"),
+    ("stats.in_progress", "Em andamento: {}"),
+    ("stats.pending", "Pendentes: {}"),
+    ("stats.overdue", "Atrasadas: {}"),
+    ("stats.by_category", "Por Categoria:"),
+    ("stats.by_priority", "Por Prioridade:"),
+    ("stats.time", "Tempo:"),
+    ("stats.tracked", "Registrado: {}"),
+    ("stats.estimate_vs_actual", "Estimado x real: {} / {}"),
+    ("stats.over_estimate", "Acima da estimativa: {}"),
+    ("stats.focus", "Foco:"),
+    ("stats.pomodoros", "{} pomodoro(s), {} de foco"),
+    ("stats.by_user", "Por Responsável:"),
+    ("stats.group_line", "{}: {} tarefa(s), {} concluída(s), {} atrasada(s)"),
+    ("stats.unassigned", "(sem responsável)"),
+    ("stats.by_project", "Por Projeto:"),
+    ("stats.no_project", "(sem projeto)"),
+    // Análise de produtividade
+    ("analytics.title", "ANÁLISE DE PRODUTIVIDADE"),
+    ("analytics.period", "Período: {} a {}"),
+    ("analytics.per_day", "Concluídas por dia:"),
+    ("analytics.per_week", "Concluídas por semana:"),
+    ("analytics.lead_time", "Tempo médio até a conclusão, por categoria:"),
+    ("analytics.overdue_rate", "Taxa de atraso por semana de vencimento:"),
+    ("analytics.burndown", "Burndown (tarefas em aberto):"),
+    ("analytics.no_data", "Sem dados no período."),
+    // Quadro Kanban
+    ("board.title", "QUADRO KANBAN"),
+    ("board.legend", "↑ alta  → média  ↓ baixa  ! atrasada"),
+    // Pontuação
+    ("score.priority", "prioridade {}"),
+    ("score.due_today", "vence hoje"),
+    ("score.due_in", "vence em {} dia(s)"),
+    ("score.overdue", "atrasada há {} dia(s)"),
+    ("score.age", "criada há {} dia(s)"),
+    ("score.blocked", "bloqueada por outra tarefa"),
+    ("score.points", "{} pts"),
+    // Menu
+    ("menu.title", "GERENCIADOR DE TAREFAS"),
+    ("menu.create", "Criar nova tarefa"),
+    ("menu.list", "Listar todas as tarefas"),
+    ("menu.view", "Ver detalhes de uma tarefa"),
+    ("menu.update", "Atualizar tarefa"),
+    ("menu.delete", "Deletar tarefa"),
+    ("menu.filter", "Filtrar tarefas"),
+    ("menu.statistics", "Estatísticas"),
+    ("menu.analytics", "Análise de produtividade"),
+    ("menu.board", "Quadro Kanban"),
+    ("menu.trash_archive", "Lixeira e arquivo"),
+    ("menu.bulk", "Operações em lote"),
+    ("menu.quick_add", "Adição rápida"),
+    ("menu.next_up", "O que fazer agora?"),
+    ("menu.projects", "Projetos"),
+    ("menu.encryption", "Criptografia"),
+    ("menu.templates", "Modelos"),
+    ("menu.prompt_from", "Data inicial ({}) ou Enter para {}: "),
+    ("menu.prompt_to", "Data final ({}) ou Enter para {}: "),
+    ("menu.invalid_range", "A data inicial deve ser anterior à final!"),
+    ("menu.exit", "Sair"),
+    ("menu.choose_option", "Escolha uma opção: "),
+    ("menu.goodbye", "Até logo! 👋"),
+    ("menu.invalid_option", "Opção inválida!"),
+    ("menu.create_title", "CRIAR NOVA TAREFA"),
+    ("menu.prompt_title", "Título: "),
+    ("menu.empty_title", "Título não pode ser vazio!"),
+    ("menu.prompt_description", "Descrição: "),
+    ("menu.categories", "\nCategorias:"),
+    ("menu.choose_category", "Escolha a categoria: "),
+    ("menu.invalid_category", "Categoria inválida!"),
+    ("menu.priorities", "\nPrioridades:"),
+    ("menu.choose_priority", "Escolha a prioridade: "),
+    ("menu.invalid_priority", "Prioridade inválida!"),
+    (
+        "menu.prompt_due_date",
+        "Data de vencimento ({}) ou Enter para pular: ",
+    ),
+    ("menu.invalid_date", "Data inválida! Use o formato {}"),
+    ("menu.task_created", "Tarefa criada com ID: {}"),
+    ("menu.create_error", "Erro ao criar tarefa: {}"),
+    ("menu.list_title", "TODAS AS TAREFAS"),
+    ("menu.view_title", "VER DETALHES DA TAREFA"),
+    ("menu.prompt_id", "ID da tarefa: "),
+    ("menu.invalid_id", "ID inválido!"),
+    ("menu.actions", "Ações:"),
+    ("menu.action_start", "Iniciar tarefa"),
+    ("menu.action_complete", "Marcar como concluída"),
+    ("menu.action_start_timer", "Iniciar cronômetro"),
+    ("menu.action_stop_timer", "Parar cronômetro"),
+    ("menu.action_log_time", "Registrar tempo manualmente"),
+    ("menu.action_set_estimate", "Definir estimativa"),
+    ("menu.back", "Voltar"),
+    ("menu.choose_action", "\nEscolha uma ação: "),
+    ("menu.task_started", "Tarefa iniciada!"),
+    ("menu.task_completed", "Tarefa concluída!"),
+    ("menu.timer_started", "Cronômetro iniciado!"),
+    ("menu.timer_stopped", "Cronômetro parado: {}"),
+    ("menu.prompt_minutes", "Minutos trabalhados: "),
+    ("menu.time_logged", "Tempo registrado!"),
+    ("menu.prompt_estimate", "Estimativa em minutos (Enter para remover): "),
+    ("menu.estimate_set", "Estimativa atualizada!"),
+    ("menu.invalid_minutes", "Minutos inválidos!"),
+    ("menu.task_not_found", "Tarefa não encontrada!"),
+    ("menu.update_title", "ATUALIZAR TAREFA"),
+    (
+        "menu.keep_blank",
+        "Deixe em branco para manter o valor atual.",
+    ),
+    ("menu.prompt_new_title", "Novo título: "),
+    ("menu.prompt_new_description", "Nova descrição: "),
+    ("menu.task_updated", "Tarefa atualizada com sucesso!"),
+    ("menu.update_error", "Erro ao atualizar: {}"),
+    ("menu.delete_title", "DELETAR TAREFA"),
+    (
+        "menu.confirm_delete",
+        "\nTem certeza que deseja deletar esta tarefa?",
+    ),
+    ("menu.task_deleted", "Tarefa movida para a lixeira!"),
+    ("menu.delete_error", "Erro ao deletar: {}"),
+    ("menu.cancelled", "Operação cancelada."),
+    ("menu.trash_title", "LIXEIRA E ARQUIVO"),
+    ("menu.trash_list", "Ver lixeira"),
+    ("menu.trash_restore", "Restaurar da lixeira"),
+    ("menu.trash_purge", "Apagar de vez da lixeira"),
+    ("menu.trash_empty", "Esvaziar lixeira"),
+    ("menu.archive_completed", "Arquivar tarefas concluídas"),
+    ("menu.archive_list", "Ver arquivadas"),
+    ("menu.archive_restore", "Desarquivar tarefa"),
+    ("menu.statistics_all", "Estatísticas incluindo arquivadas"),
+    ("menu.trash_header", "NA LIXEIRA"),
+    ("menu.archive_header", "ARQUIVADAS"),
+    ("menu.task_restored", "Tarefa restaurada!"),
+    ("menu.task_purged", "Tarefa apagada de vez!"),
+    ("menu.confirm_purge", "Apagar de vez? Não será possível desfazer "),
+    ("menu.trash_emptied", "{} tarefa(s) apagada(s) de vez."),
+    ("menu.tasks_archived", "{} tarefa(s) arquivada(s)."),
+    ("menu.bulk_title", "OPERAÇÕES EM LOTE"),
+    ("menu.prompt_ids", "IDs (ex.: 3-7,10): "),
+    ("menu.invalid_ids", "Lista de IDs inválida!"),
+    ("menu.bulk_set_priority", "Alterar prioridade"),
+    ("menu.bulk_set_category", "Alterar categoria"),
+    ("menu.bulk_set_due_date", "Alterar vencimento"),
+    ("menu.prompt_bulk_due_date", "Novo vencimento ({}) ou Enter para remover: "),
+    ("menu.confirm_bulk_delete", "Mover {} tarefa(s) para a lixeira?"),
+    ("menu.bulk_summary", "{} tarefa(s) alterada(s), {} com erro."),
+    ("menu.quick_add_title", "ADIÇÃO RÁPIDA"),
+    ("menu.prompt_quick_add", "Tarefa (ex.: Revisar PR amanhã 15h #trabalho !alta): "),
+    ("menu.next_up_title", "O QUE FAZER AGORA"),
+    ("menu.action_dependencies", "Definir dependências"),
+    ("menu.prompt_dependencies", "IDs que bloqueiam esta tarefa (ex.: 3,5) ou Enter para nenhuma: "),
+    ("menu.dependencies_set", "Dependências atualizadas!"),
+    ("menu.action_assign", "Atribuir responsável"),
+    ("menu.prompt_assignee", "Responsável (Enter remove): "),
+    ("menu.task_assigned", "Responsável atualizado!"),
+    ("menu.no_current_user", "Nenhum usuário atual definido (use --user ou a chave user)"),
+    ("menu.active_project", "📁 Projeto ativo: {}"),
+    ("menu.projects_title", "PROJETOS"),
+    ("menu.project_list", "Listar projetos"),
+    ("menu.project_create", "Criar projeto"),
+    ("menu.project_switch", "Trocar projeto ativo"),
+    ("menu.project_move", "Mover tarefa para outro projeto"),
+    ("menu.project_delete", "Excluir projeto"),
+    ("menu.project_statistics", "Estatísticas por projeto"),
+    ("menu.prompt_project_name", "Nome do projeto: "),
+    ("menu.prompt_project_description", "Descrição: "),
+    ("menu.prompt_project_switch", "Projeto (Enter = todas as tarefas): "),
+    ("menu.prompt_project_move", "Projeto de destino (Enter = sem projeto): "),
+    ("menu.choose_color", "Escolha a cor (Enter = {}): "),
+    ("menu.project_created", "Projeto '{}' criado!"),
+    ("menu.project_switched", "Projeto ativo: {}"),
+    ("menu.project_cleared", "Mostrando todas as tarefas"),
+    ("menu.task_moved", "Tarefa movida!"),
+    ("menu.confirm_delete_project", "Excluir o projeto? As tarefas ficam sem projeto"),
+    ("menu.project_deleted", "Projeto excluído!"),
+    ("menu.no_projects", "Nenhum projeto criado."),
+    ("menu.project_line", "{} ({}) — {} tarefa(s)"),
+    ("menu.encryption_title", "CRIPTOGRAFIA"),
+    ("menu.encryption_on", "🔐 Tarefas criptografadas no disco"),
+    ("menu.encryption_off", "🔓 Tarefas em texto puro no disco"),
+    ("menu.passphrase_change", "Trocar senha"),
+    ("menu.passphrase_set", "Criptografar com uma senha"),
+    ("menu.decrypt_export", "Exportar tarefas sem criptografia"),
+    ("menu.prompt_passphrase", "Senha: "),
+    ("menu.prompt_current_passphrase", "Senha atual: "),
+    ("menu.prompt_new_passphrase", "Nova senha: "),
+    ("menu.prompt_confirm_passphrase", "Confirme a nova senha: "),
+    ("menu.passphrase_changed", "Senha definida; arquivos criptografados de novo!"),
+    ("menu.prompt_export_path", "Arquivo de destino: "),
+    ("menu.export_warning", "O arquivo exportado fica em texto puro, sem proteção"),
+    ("menu.exported", "{} tarefa(s) exportada(s) para {}"),
+    ("menu.action_add_note", "Adicionar nota"),
+    ("menu.action_attach", "Anexar arquivo"),
+    ("menu.action_attachments", "Anexos"),
+    ("menu.action_focus", "Sessão de foco (Pomodoro)"),
+    ("menu.no_attachments", "Nenhum anexo."),
+    ("menu.prompt_note", "Nota: "),
+    ("menu.note_added", "Nota adicionada!"),
+    ("menu.prompt_attachment_path", "Caminho do arquivo: "),
+    ("menu.file_attached", "Arquivo '{}' anexado!"),
+    ("menu.prompt_remove_attachment", "Número do anexo para remover (Enter volta): "),
+    ("menu.attachment_encrypted", "Criptografado; salve uma cópia para abrir"),
+    ("menu.prompt_save_attachment", "Número do anexo para salvar uma cópia (Enter pula): "),
+    ("menu.prompt_save_attachment_path", "Salvar a cópia em: "),
+    ("menu.attachment_saved", "Cópia de '{}' salva em {}"),
+    ("menu.attachment_removed", "Anexo '{}' removido!"),
+    ("menu.filter_title", "FILTRAR TAREFAS"),
+    ("menu.filter_status", "Por Status"),
+    ("menu.filter_category", "Por Categoria"),
+    ("menu.filter_priority", "Por Prioridade"),
+    ("menu.filter_overdue", "Tarefas Atrasadas"),
+    ("menu.filter_mine", "Minhas Tarefas"),
+    ("menu.filter_assigned_by_me", "Atribuídas por Mim"),
+    ("menu.choose_filter", "\nEscolha o filtro: "),
+    ("menu.status_pending", "Pendentes"),
+    ("menu.status_in_progress", "Em Andamento"),
+    ("menu.status_completed", "Concluídas"),
+    ("menu.choose_status", "Escolha o status: "),
+    ("menu.choose_with_default", "Escolha (Enter = {}): "),
+    ("menu.templates_title", "MODELOS"),
+    ("menu.template_list", "Listar modelos"),
+    ("menu.template_use", "Criar tarefas a partir de um modelo"),
+    ("menu.template_save", "Salvar tarefas como modelo"),
+    ("menu.template_delete", "Excluir modelo"),
+    ("menu.no_templates", "Nenhum modelo criado."),
+    ("menu.template_line", "{} — {} tarefa(s)"),
+    ("menu.choose_template", "Escolha o modelo: "),
+    ("menu.prompt_variable", "Valor para '{}': "),
+    ("menu.prompt_template_start", "Contar os prazos a partir de ({}) ou Enter para {}: "),
+    ("menu.template_used", "{} tarefa(s) criada(s): {}"),
+    ("menu.prompt_template_name", "Nome do modelo: "),
+    ("menu.template_saved", "Modelo '{}' salvo!"),
+    ("menu.confirm_delete_template", "Excluir o modelo? As tarefas já criadas continuam"),
+    ("menu.template_deleted", "Modelo excluído!"),
+    // Interface em tela cheia
+    ("tui.tasks", "Tarefas"),
+    ("tui.tasks_filtered", "Tarefas (filtro: {})"),
+    ("tui.details", "Detalhes"),
+    (
+        "tui.help",
+        "↑↓ mover  / filtrar  n nova  e título  E descrição  s iniciar  c concluir  t cronômetro  p prioridade  g categoria  d deletar  q sair",
+    ),
+    (
+        "tui.filter_prompt",
+        "Filtro: {}▏  (Enter aplica, Esc limpa)",
+    ),
+    ("tui.prompt_new_task", "Nova tarefa (ex.: Revisar PR amanhã #trabalho !alta): "),
+    ("tui.priority_changed", "Prioridade alterada!"),
+    ("tui.category_changed", "Categoria alterada!"),
+    ("tui.timer_stopped", "Cronômetro parado!"),
+    // Configuração
+    ("config.file_not_found", "Arquivo de configuração não encontrado: {}"),
+    ("config.invalid_file", "Arquivo de configuração inválido: {}"),
+    ("config.invalid_value", "Valor inválido para '{}': {}"),
+    ("config.unknown_key", "Chave de configuração desconhecida: {}"),
+    ("config.empty_storage", "storage_path não pode ser vazio"),
+    ("config.zero_pomodoro", "pomodoro_work_minutes precisa ser maior que zero"),
+    ("config.unsupported_locale", "Idioma não suportado: {}"),
+    ("config.invalid_date_format", "Formato de data inválido: {} (precisa conter dia, mês e ano, ex.: %d/%m/%Y)"),
+    ("config.missing_value", "Faltou o valor de {}"),
+    ("config.set_usage", "Use --set chave=valor: {}"),
+    ("config.unknown_argument", "Argumento desconhecido: {}"),
+    // Comandos
+    ("command.unknown", "Comando desconhecido: {} (use list, show, stats, next, projects, analytics, merge-file, sync ou decrypt-export)"),
+    ("command.invalid_argument", "Argumento inválido para {}: {}"),
+    ("command.text_only", "merge-file, sync e decrypt-export gravam arquivos e só têm saída em texto (sem --json ou --jsonl)"),
+    // Mesclagem
+    ("merge.conflict_field", "Conflito na tarefa #{}: '{}' mudou dos dois lados"),
+    ("merge.conflict_deleted", "Conflito na tarefa #{}: excluída de um lado e alterada do outro (ficou a alterada)"),
+    ("merge.renumbered", "Tarefa #{} criada dos dois lados; a outra virou #{}"),
+    ("merge.kept_local", "(ficou a versão local)"),
+    // Sincronização
+    ("sync.summary", "Sincronização concluída: {} recebida(s), {} enviada(s), {} atualizada(s), {} excluída(s)"),
+    ("sync.latest_wins", "(ficou a mudança mais recente)"),
+    // Sessão de foco
+    ("focus.hint", "p pausa/retoma · q encerra"),
+    ("focus.work", "🍅 Foco"),
+    ("focus.break", "☕ Pausa"),
+    ("focus.paused", "⏸️  Pausado"),
+    ("focus.completed", "🍅 Pomodoro concluído! ({} nesta sessão)"),
+    ("focus.break_over", "☕ Fim da pausa; p começa o próximo pomodoro"),
+    ("focus.summary", "{} pomodoro(s) concluído(s) em #{}"),
+    ("focus.needs_terminal", "A sessão de foco precisa de um terminal"),
+];
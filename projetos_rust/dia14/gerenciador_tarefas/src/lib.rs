@@ -1,4 +1,6 @@
 // src/lib.rs
+pub mod config;
+pub mod i18n;
 pub mod models;
 pub mod services;
 pub mod storage;
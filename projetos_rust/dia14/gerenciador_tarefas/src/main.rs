@@ -1,14 +1,173 @@
-use gerenciador_tarefas::services::TaskService;
-use gerenciador_tarefas::ui::Menu;
+use gerenciador_tarefas::config::{CliArgs, Config, UiMode};
+use gerenciador_tarefas::i18n::{self, Locale};
+use gerenciador_tarefas::services::{EventLogSubscriber, TaskService};
+use gerenciador_tarefas::storage::Storage;
+use gerenciador_tarefas::ui::commands::{self, Command};
+use gerenciador_tarefas::ui::json::OutputFormat;
+use gerenciador_tarefas::ui::theme::AsciiWriter;
+use gerenciador_tarefas::ui::{text, theme, tui, Menu};
+use std::io::{self, Write};
+use std::process;
 
 fn main() {
-    // Caminho do arquivo de armazenamento
-    let storage_path = "data/tasks.json".to_string();
+    // Idioma provisório (ambiente) para as mensagens de erro da configuração
+    i18n::set_locale(Locale::from_env());
 
-    // Cria o serviço de tarefas
-    let service = TaskService::new(storage_path);
+    // Configuração: padrões < arquivo < ambiente < argumentos
+    let cli = CliArgs::parse(std::env::args().skip(1)).unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        process::exit(2);
+    });
+    let config = Config::load(&cli).unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        process::exit(1);
+    });
 
-    // Cria e executa o menu
-    let mut menu = Menu::new(service);
-    menu.run();
-}
\ No newline at end of file
+    i18n::set_locale(config.locale());
+    i18n::set_date_format(config.date_format());
+
+    // Saída redirecionada ou `NO_COLOR`: sem cores; só a redirecionada
+    // troca emojis e molduras por ASCII (o texto das tarefas fica intacto)
+    text::set_styles(config.colors && !theme::plain_output());
+    theme::set_theme(config.theme);
+    theme::set_ascii(theme::ascii_output());
+
+    // `--show-config` mostra a configuração efetiva e sai
+    if cli.show_config {
+        print!("{}", config.to_toml());
+        return;
+    }
+
+    // Driver de merge do git: não precisa carregar as tarefas
+    if let Ok(Command::MergeFile { base, ours, theirs }) = Command::parse(&cli.command) {
+        if cli.output != OutputFormat::Text {
+            eprintln!("{}", i18n::t("command.text_only"));
+            process::exit(2);
+        }
+        match commands::merge_file(&base, &ours, &theirs, &mut io::stderr()) {
+            Ok(true) => return,
+            Ok(false) => process::exit(1),
+            Err(e) => {
+                eprintln!("{}", e);
+                process::exit(2);
+            }
+        }
+    }
+
+    // Cria o serviço de tarefas (pedindo a senha, se estiverem criptografadas)
+    let mut service = open_service(&config).unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        process::exit(1);
+    });
+
+    // Comandos (ex.: `list --overdue --json`, `sync <caminho>`) respondem e saem
+    if !cli.command.is_empty() {
+        let result = Command::parse(&cli.command).and_then(|command| {
+            // JSON sai sempre intacto; o texto segue as regras do terminal
+            let ascii = theme::ascii_output() && cli.output == OutputFormat::Text;
+            theme::set_ascii(ascii);
+            let mut out = AsciiWriter::new(io::stdout().lock(), ascii);
+            commands::run(&mut service, &command, cli.output, &mut out)?;
+            out.flush()
+        });
+        match result {
+            // Leitor fechado antes do fim (ex.: `| head`) não é erro
+            Err(e) if e.kind() != io::ErrorKind::BrokenPipe => {
+                eprintln!("{}", e);
+                process::exit(1);
+            }
+            _ => return,
+        }
+    }
+
+    // Apaga da lixeira o que passou do prazo de retenção
+    if let Err(e) = service.purge_expired() {
+        eprintln!("{}", e);
+    }
+
+    // Avisa sobre anexos que sumiram ou não batem mais com o hash
+    for (id, name) in service.broken_attachments() {
+        eprintln!("⚠️  {}", i18n::tf("output.broken_attachment", &[id, name]));
+    }
+
+    // Avisa sobre arquivos de tarefa ilegíveis (ex.: conflito do git)
+    for path in service.skipped_files() {
+        eprintln!("⚠️  {}", i18n::tf("output.skipped_task_file", &[&path.display()]));
+    }
+
+    // Registra o log de eventos (caminho vazio desativa); com as tarefas
+    // criptografadas, o log também é, ou fica desativado se não abrir
+    if !config.event_log.is_empty() {
+        let log = EventLogSubscriber::new(config.event_log.clone()).with_user(config.user());
+        if let Err(e) = service.subscribe(log) {
+            eprintln!("⚠️  {}", i18n::tf("error.event_log", &[&e]));
+        }
+    }
+
+    // Escolhe a interface: `ui = "tui"` (ou `--tui`) abre o modo em tela cheia
+    match config.ui {
+        UiMode::Tui => {
+            if let Err(e) = tui::run(service, config.colors) {
+                eprintln!("{}", i18n::tf("error.tui", &[&e]));
+            }
+        }
+        UiMode::Menu => {
+            let mut menu = Menu::new(service).with_config(config);
+            if let Err(e) = menu.run() {
+                eprintln!("{}", i18n::tf("error.menu", &[&e]));
+            }
+        }
+    }
+}
+
+/// Tentativas de senha antes de desistir
+const PASSPHRASE_ATTEMPTS: usize = 3;
+
+/// Abre as tarefas, pedindo a senha quando estão (ou vão ficar) criptografadas
+///
+/// A senha vem de `GERENCIADOR_PASSPHRASE` ou é pedida sem eco no terminal;
+/// na criação de um arquivo criptografado ela é pedida duas vezes.
+fn open_service(config: &Config) -> io::Result<TaskService> {
+    let existing = Storage::new(config.storage_path.clone());
+    if !config.encrypted && !existing.is_encrypted() {
+        return TaskService::from_config(config, None);
+    }
+
+    if let Ok(passphrase) = std::env::var("GERENCIADOR_PASSPHRASE") {
+        return TaskService::from_config(config, Some(&passphrase));
+    }
+
+    if !existing.exists() {
+        let passphrase = prompt_passphrase(config, "menu.prompt_new_passphrase")?;
+        let confirmation = prompt_passphrase(config, "menu.prompt_confirm_passphrase")?;
+        if passphrase != confirmation {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                i18n::t("error.passphrase_mismatch"),
+            ));
+        }
+        return TaskService::from_config(config, Some(&passphrase));
+    }
+
+    let mut attempt = 1;
+    loop {
+        let passphrase = prompt_passphrase(config, "menu.prompt_passphrase")?;
+        match TaskService::from_config(config, Some(&passphrase)) {
+            Err(e) if e.kind() == io::ErrorKind::PermissionDenied && attempt < PASSPHRASE_ATTEMPTS => {
+                eprintln!("{}", e);
+                attempt += 1;
+            }
+            result => return result,
+        }
+    }
+}
+
+/// Pede a senha sem eco; sem terminal, explica que ela é necessária
+fn prompt_passphrase(config: &Config, prompt: &'static str) -> io::Result<String> {
+    rpassword::prompt_password(i18n::t(prompt)).map_err(|_| {
+        io::Error::new(
+            io::ErrorKind::PermissionDenied,
+            i18n::tf("error.passphrase_required", &[&config.storage_path]),
+        )
+    })
+}
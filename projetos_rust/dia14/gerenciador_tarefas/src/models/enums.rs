@@ -1,4 +1,5 @@
-use serde::{Deserialize, Serialize}
+use crate::i18n::{self, Locale, t};
+use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Category { 
@@ -20,18 +21,37 @@ impl Category {
         ]
     }
 
-    pub fn as_str(&self) -> &str {
+    pub fn as_str(&self) -> &'static str {
         match self {
-            Category::Work => "Trabalho",
-            Category::Personal => "Pessoal",
-            Category::Study => "Estudos",
-            Category::Health => "Saúde",
-            Category::Other => "Outro",
+            Category::Work => t("category.work"),
+            Category::Personal => t("category.personal"),
+            Category::Study => t("category.study"),
+            Category::Health => t("category.health"),
+            Category::Other => t("category.other"),
+        }
+    }
+
+    /// Interpreta o nome da variante ou o rótulo em qualquer idioma
+    /// (ex.: "Work", "trabalho", "saude")
+    pub fn parse(value: &str) -> Option<Category> {
+        Category::all()
+            .into_iter()
+            .find(|c| matches_name(value, &format!("{:?}", c), c.label_key()))
+    }
+
+    fn label_key(&self) -> &'static str {
+        match self {
+            Category::Work => "category.work",
+            Category::Personal => "category.personal",
+            Category::Study => "category.study",
+            Category::Health => "category.health",
+            Category::Other => "category.other",
         }
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+/// Ordenada da mais alta para a mais baixa
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum Priority {
     High,
     Medium,
@@ -47,11 +67,27 @@ impl Priority {
         ]
     }
 
-    pub fn as_str(&self) -> &str {
+    pub fn as_str(&self) -> &'static str {
         match self {
-            Priority::High => "Alta",
-            Priority::Medium => "Média",
-            Priority::Low => "Baixa",
+            Priority::High => t("priority.high"),
+            Priority::Medium => t("priority.medium"),
+            Priority::Low => t("priority.low"),
+        }
+    }
+
+    /// Interpreta o nome da variante ou o rótulo em qualquer idioma
+    /// (ex.: "High", "alta", "media")
+    pub fn parse(value: &str) -> Option<Priority> {
+        Priority::all()
+            .into_iter()
+            .find(|p| matches_name(value, &format!("{:?}", p), p.label_key()))
+    }
+
+    fn label_key(&self) -> &'static str {
+        match self {
+            Priority::High => "priority.high",
+            Priority::Medium => "priority.medium",
+            Priority::Low => "priority.low",
         }
     }
 }
@@ -64,11 +100,90 @@ pub enum Status {
 }
 
 impl Status {
-    pub fn as_str(&self) -> &str {
+    pub fn all() -> Vec<Status> {
+        vec![Status::Pending, Status::InProgress, Status::Completed]
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Status::Pending => t("status.pending"),
+            Status::InProgress => t("status.in_progress"),
+            Status::Completed => t("status.completed"),
+        }
+    }
+
+    /// Interpreta o nome da variante ou o rótulo em qualquer idioma
+    /// (ex.: "InProgress", "em andamento")
+    pub fn parse(value: &str) -> Option<Status> {
+        Status::all()
+            .into_iter()
+            .find(|s| matches_name(value, &format!("{:?}", s), s.label_key()))
+    }
+
+    fn label_key(&self) -> &'static str {
         match self {
-            Status::Pending => "Pendente",
-            Status::InProgress => "Em Andamento",
-            Status::Completed => "Concluída",
+            Status::Pending => "status.pending",
+            Status::InProgress => "status.in_progress",
+            Status::Completed => "status.completed",
         }
     }
-}
\ No newline at end of file
+}
+
+/// Compara ignorando maiúsculas e acentos com o nome da variante
+/// e com os rótulos de todos os idiomas
+fn matches_name(value: &str, variant: &str, label_key: &'static str) -> bool {
+    let value = normalize(value);
+    value == normalize(variant)
+        || Locale::all()
+            .into_iter()
+            .any(|locale| value == normalize(i18n::t_in(locale, label_key)))
+}
+
+/// Minúsculas e sem acentos comuns do português
+pub(crate) fn normalize(value: &str) -> String {
+    value
+        .trim()
+        .to_lowercase()
+        .chars()
+        .map(|c| match c {
+            'á' | 'à' | 'â' | 'ã' => 'a',
+            'é' | 'ê' => 'e',
+            'í' => 'i',
+            'ó' | 'ô' | 'õ' => 'o',
+            'ú' => 'u',
+            'ç' => 'c',
+            other => other,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_category() {
+        assert_eq!(Category::parse("Work"), Some(Category::Work));
+        assert_eq!(Category::parse("trabalho"), Some(Category::Work));
+        assert_eq!(Category::parse("SAUDE"), Some(Category::Health));
+        assert_eq!(Category::parse("estudos"), Some(Category::Study));
+        assert_eq!(Category::parse("xyz"), None);
+    }
+
+    #[test]
+    fn test_parse_priority() {
+        assert_eq!(Priority::parse("high"), Some(Priority::High));
+        assert_eq!(Priority::parse("Média"), Some(Priority::Medium));
+        assert_eq!(Priority::parse("media"), Some(Priority::Medium));
+        assert_eq!(Priority::parse("baixa"), Some(Priority::Low));
+        assert_eq!(Priority::parse(""), None);
+    }
+
+    #[test]
+    fn test_parse_status() {
+        assert_eq!(Status::parse("InProgress"), Some(Status::InProgress));
+        assert_eq!(Status::parse("em andamento"), Some(Status::InProgress));
+        assert_eq!(Status::parse("concluida"), Some(Status::Completed));
+        assert_eq!(Status::parse("done"), None);
+    }
+}
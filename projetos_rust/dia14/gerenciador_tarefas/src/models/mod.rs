@@ -1,6 +1,16 @@
 // src/models/mod.rs
 pub mod task;
 pub mod enums;
+pub mod time_entry;
+pub mod project;
+pub mod note;
+pub mod pomodoro;
+pub mod template;
 
 pub use task::Task;
-pub use enums::{Category, Priority, Status};
\ No newline at end of file
+pub use enums::{Category, Priority, Status};
+pub use time_entry::TimeEntry;
+pub use project::{Project, ProjectColor};
+pub use note::{Attachment, Note};
+pub use pomodoro::Pomodoro;
+pub use template::{Template, TemplateTask};
\ No newline at end of file
@@ -0,0 +1,38 @@
+use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
+
+/// Comentário datado em uma tarefa (notas só são acrescentadas, nunca editadas)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Note {
+    pub created_at: DateTime<Local>,
+    /// Usuário que escreveu a nota, se houver um usuário atual
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub author: Option<String>,
+    pub text: String,
+}
+
+impl Note {
+    /// Cria uma nota escrita em `created_at`
+    pub fn new(text: String, author: Option<String>, created_at: DateTime<Local>) -> Self {
+        Note {
+            created_at,
+            author,
+            text,
+        }
+    }
+}
+
+/// Arquivo anexado a uma tarefa
+///
+/// O conteúdo fica na pasta de anexos, em um arquivo cujo nome é o
+/// hash SHA-256 do próprio conteúdo.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Attachment {
+    /// Nome original do arquivo
+    pub name: String,
+    /// SHA-256 do conteúdo, em hexadecimal
+    pub hash: String,
+    /// Tamanho em bytes
+    pub size: u64,
+    pub added_at: DateTime<Local>,
+}
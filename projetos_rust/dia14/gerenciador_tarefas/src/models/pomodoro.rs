@@ -0,0 +1,14 @@
+use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
+
+/// Pomodoro concluído em uma tarefa
+///
+/// Só entram os blocos de foco que chegaram ao fim; pausas no meio não
+/// contam em `minutes`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Pomodoro {
+    pub started_at: DateTime<Local>,
+    pub finished_at: DateTime<Local>,
+    /// Minutos de foco do bloco
+    pub minutes: u32,
+}
@@ -0,0 +1,100 @@
+use crate::i18n::t;
+use crate::models::enums::normalize;
+use serde::{Deserialize, Serialize};
+
+/// Cor de um projeto, usada para destacá-lo nas listagens
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ProjectColor {
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+}
+
+impl ProjectColor {
+    pub fn all() -> Vec<ProjectColor> {
+        vec![
+            ProjectColor::Red,
+            ProjectColor::Green,
+            ProjectColor::Yellow,
+            ProjectColor::Blue,
+            ProjectColor::Magenta,
+            ProjectColor::Cyan,
+            ProjectColor::White,
+        ]
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ProjectColor::Red => t("color.red"),
+            ProjectColor::Green => t("color.green"),
+            ProjectColor::Yellow => t("color.yellow"),
+            ProjectColor::Blue => t("color.blue"),
+            ProjectColor::Magenta => t("color.magenta"),
+            ProjectColor::Cyan => t("color.cyan"),
+            ProjectColor::White => t("color.white"),
+        }
+    }
+
+    /// Interpreta o nome da variante ou o rótulo do idioma atual
+    /// (ex.: "Blue", "azul")
+    pub fn parse(value: &str) -> Option<ProjectColor> {
+        let value = normalize(value);
+        ProjectColor::all()
+            .into_iter()
+            .find(|c| value == normalize(&format!("{:?}", c)) || value == normalize(c.as_str()))
+    }
+}
+
+/// Lista nomeada que agrupa tarefas
+///
+/// As tarefas apontam para o projeto pelo nome; os IDs das tarefas
+/// continuam únicos em todos os projetos.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Project {
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    pub color: ProjectColor,
+}
+
+impl Project {
+    /// Cria um novo projeto
+    pub fn new(name: String, description: String, color: ProjectColor) -> Self {
+        Project {
+            name,
+            description,
+            color,
+        }
+    }
+
+    /// Compara nomes ignorando maiúsculas e acentos
+    pub fn has_name(&self, name: &str) -> bool {
+        normalize(&self.name) == normalize(name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_color() {
+        assert_eq!(ProjectColor::parse("Blue"), Some(ProjectColor::Blue));
+        assert_eq!(ProjectColor::parse("azul"), Some(ProjectColor::Blue));
+        assert_eq!(ProjectColor::parse("AMARELO"), Some(ProjectColor::Yellow));
+        assert_eq!(ProjectColor::parse("roxo"), None);
+    }
+
+    #[test]
+    fn test_has_name() {
+        let project = Project::new("Mudança".to_string(), String::new(), ProjectColor::Red);
+
+        assert!(project.has_name("mudanca"));
+        assert!(project.has_name(" MUDANÇA "));
+        assert!(!project.has_name("Mudar"));
+    }
+}
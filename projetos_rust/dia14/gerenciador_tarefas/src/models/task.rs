@@ -1,23 +1,79 @@
-use chrono::{DateTime, Local, NaiveDate};
+use chrono::{DateTime, Duration, Local, NaiveDate, NaiveTime, TimeZone};
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use uuid::Uuid;
 
 use super::enums::{Category, Priority, Status};
+use super::note::{Attachment, Note};
+use super::pomodoro::Pomodoro;
+use super::time_entry::TimeEntry;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Task {
+    /// Número curto mostrado na interface; nunca é reaproveitado
     pub id: u32,
+    /// Identificador global e permanente, para referências externas
+    #[serde(default)]
+    pub uuid: Uuid,
     pub title: String,
+    /// Descrição em Markdown
+    #[serde(default, skip_serializing_if = "String::is_empty")]
     pub description: String,
     pub category: Category,
     pub priority: Priority,
     pub status: Status,
     pub due_date: Option<NaiveDate>,
+    /// Hora do vencimento, quando informada
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub due_time: Option<NaiveTime>,
     pub created_at: DateTime<Local>,
     pub completed_at: Option<DateTime<Local>>,
+    /// Estimativa de esforço, em minutos
+    #[serde(default)]
+    pub estimate_minutes: Option<u32>,
+    /// Tempo registrado (cronômetro ou manual)
+    #[serde(default)]
+    pub time_entries: Vec<TimeEntry>,
+    /// Quando foi para a lixeira
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deleted_at: Option<DateTime<Local>>,
+    /// Quando foi arquivada
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<DateTime<Local>>,
+    /// IDs das tarefas que precisam ser concluídas antes desta
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub blocked_by: Vec<u32>,
+    /// Usuário que criou a tarefa
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_by: Option<String>,
+    /// Usuário responsável pela tarefa
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub assignee: Option<String>,
+    /// Nome do projeto da tarefa (`None` = sem projeto)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project: Option<String>,
+    /// Comentários datados, em ordem de criação
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub notes: Vec<Note>,
+    /// Arquivos anexados
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub attachments: Vec<Attachment>,
+    /// Pomodoros concluídos nesta tarefa
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub pomodoros: Vec<Pomodoro>,
+    /// Quando cada campo mudou pela última vez, para a sincronização
+    /// (campo sem registro conta como mudado na criação)
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub changed_at: BTreeMap<String, DateTime<Local>>,
 }
 
 impl Task {
-    /// Cria uma nova tarefa
+    /// Cria uma nova tarefa com a hora do sistema, só para os testes
+    ///
+    /// O código do programa usa [`Task::new_at`] com a hora do relógio do
+    /// serviço, para que os testes possam controlá-la.
+    #[cfg(test)]
     pub fn new(
         id: u32,
         title: String,
@@ -25,35 +81,113 @@ impl Task {
         category: Category,
         priority: Priority,
         due_date: Option<NaiveDate>,
+    ) -> Self {
+        Task::new_at(
+            id,
+            title,
+            description,
+            category,
+            priority,
+            due_date,
+            Local::now(),
+        )
+    }
+
+    /// Cria uma nova tarefa criada em `created_at`
+    pub fn new_at(
+        id: u32,
+        title: String,
+        description: String,
+        category: Category,
+        priority: Priority,
+        due_date: Option<NaiveDate>,
+        created_at: DateTime<Local>,
     ) -> Self {
         Task {
             id,
+            uuid: Uuid::new_v4(),
             title,
             description,
             category,
             priority,
             status: Status::Pending,
             due_date,
-            created_at: Local::now(),
+            due_time: None,
+            created_at,
             completed_at: None,
+            estimate_minutes: None,
+            time_entries: Vec::new(),
+            deleted_at: None,
+            archived_at: None,
+            blocked_by: Vec::new(),
+            created_by: None,
+            assignee: None,
+            project: None,
+            notes: Vec::new(),
+            attachments: Vec::new(),
+            pomodoros: Vec::new(),
+            changed_at: BTreeMap::new(),
+        }
+    }
+
+    /// Dá um UUID às tarefas salvas antes de existir o campo
+    ///
+    /// O UUID vem do ID e da data de criação, então cópias da mesma tarefa
+    /// (ex.: dos dois lados de uma sincronização) ganham o mesmo.
+    pub fn fill_uuid(&mut self) {
+        if !self.uuid.is_nil() {
+            return;
         }
+        let digest = Sha256::new()
+            .chain_update(self.id.to_be_bytes())
+            .chain_update(self.created_at.timestamp().to_be_bytes())
+            .chain_update(self.created_at.timestamp_subsec_nanos().to_be_bytes())
+            .finalize();
+        let mut bytes = [0u8; 16];
+        bytes.copy_from_slice(&digest[..16]);
+        self.uuid = uuid::Builder::from_custom_bytes(bytes).into_uuid();
+    }
+
+    /// Quando o campo mudou pela última vez
+    pub fn field_changed_at(&self, field: &str) -> DateTime<Local> {
+        self.changed_at
+            .get(field)
+            .copied()
+            .unwrap_or(self.created_at)
     }
 
-    /// Marca a tarefa como concluída
-    pub fn complete(&mut self) {
+    /// Última mudança em qualquer campo
+    pub fn last_changed_at(&self) -> DateTime<Local> {
+        self.changed_at
+            .values()
+            .copied()
+            .fold(self.created_at, DateTime::max)
+    }
+
+    /// Marca a tarefa como concluída (e para o cronômetro, se houver)
+    pub fn complete(&mut self, now: DateTime<Local>) {
+        self.stop_timer(now);
         self.status = Status::Completed;
-        self.completed_at = Some(Local::now());
+        self.completed_at = Some(now);
     }
 
-    /// Verifica se a tarefa está atrasada
-    pub fn is_overdue(&self) -> bool {
-        if let Some(due_date) = self.due_date {
-            if self.status != Status::Completed {
-                let today = Local::now().date_naive();
-                return due_date < today;
-            }
+    /// Verifica se a tarefa está atrasada no instante `now`
+    ///
+    /// Sem hora, a tarefa vence no fim do dia; com hora, no minuto marcado.
+    /// A comparação é pela hora local de parede, então a mudança para o
+    /// horário de verão não adianta nem atrasa o vencimento.
+    pub fn is_overdue_at<Tz: TimeZone>(&self, now: &DateTime<Tz>) -> bool {
+        let Some(due_date) = self.due_date else {
+            return false;
+        };
+        if self.status == Status::Completed {
+            return false;
+        }
+        let now = now.naive_local();
+        match self.due_time {
+            Some(due_time) => now > due_date.and_time(due_time),
+            None => now.date() > due_date,
         }
-        false
     }
 
     /// Inicia a tarefa (muda status para InProgress)
@@ -62,12 +196,79 @@ impl Task {
             self.status = Status::InProgress;
         }
     }
+
+    /// Verifica se o cronômetro da tarefa está rodando
+    pub fn is_timer_running(&self) -> bool {
+        self.time_entries.iter().any(|e| e.is_running())
+    }
+
+    /// Inicia o cronômetro (e a tarefa, se estiver pendente)
+    ///
+    /// Retorna `false` se o cronômetro já estava rodando.
+    pub fn start_timer(&mut self, now: DateTime<Local>) -> bool {
+        if self.is_timer_running() {
+            return false;
+        }
+        self.start();
+        self.time_entries.push(TimeEntry::start_at(now));
+        true
+    }
+
+    /// Para o cronômetro, retornando a duração do intervalo encerrado
+    pub fn stop_timer(&mut self, now: DateTime<Local>) -> Option<Duration> {
+        let entry = self.time_entries.iter_mut().find(|e| e.is_running())?;
+        entry.end = Some(now.max(entry.start));
+        Some(entry.duration(now))
+    }
+
+    /// Registra tempo trabalhado manualmente, terminando em `now`
+    pub fn log_time(&mut self, minutes: u32, now: DateTime<Local>) {
+        self.time_entries.push(TimeEntry::manual(minutes, now));
+    }
+
+    /// Tempo total registrado até `now`, incluindo o cronômetro em andamento
+    pub fn time_spent(&self, now: DateTime<Local>) -> Duration {
+        self.time_entries
+            .iter()
+            .map(|e| e.duration(now))
+            .fold(Duration::zero(), |total, d| total + d)
+    }
+
+    /// Verifica se o tempo gasto até `now` passou da estimativa
+    pub fn is_over_estimate(&self, now: DateTime<Local>) -> bool {
+        self.estimate_minutes
+            .is_some_and(|estimate| self.time_spent(now).num_minutes() > estimate as i64)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use chrono::Duration;
+    use chrono::{Duration, FixedOffset};
+
+    /// Hora local fixa, ex.: `at("2024-03-10 23:59:59")`
+    fn at(value: &str) -> DateTime<Local> {
+        let naive = chrono::NaiveDateTime::parse_from_str(value, "%Y-%m-%d %H:%M:%S").unwrap();
+        Local.from_local_datetime(&naive).earliest().unwrap()
+    }
+
+    /// Hora com fuso fixo, ex.: `offset_at("2024-10-27T02:30:00+02:00")`
+    fn offset_at(value: &str) -> DateTime<FixedOffset> {
+        DateTime::parse_from_rfc3339(value).unwrap()
+    }
+
+    fn due(date: &str, time: Option<&str>) -> Task {
+        let mut task = Task::new(
+            1,
+            "Test".to_string(),
+            "Desc".to_string(),
+            Category::Work,
+            Priority::High,
+            Some(date.parse().unwrap()),
+        );
+        task.due_time = time.map(|t| t.parse().unwrap());
+        task
+    }
 
     #[test]
     fn test_new_task() {
@@ -97,15 +298,33 @@ mod tests {
             None,
         );
 
-        task.complete();
+        let now = at("2024-03-10 18:30:00");
+        task.complete(now);
 
         assert_eq!(task.status, Status::Completed);
-        assert!(task.completed_at.is_some());
+        assert_eq!(task.completed_at, Some(now));
+    }
+
+    #[test]
+    fn test_new_at() {
+        let created_at = at("2024-03-10 09:00:00");
+        let task = Task::new_at(
+            1,
+            "Test".to_string(),
+            "Desc".to_string(),
+            Category::Work,
+            Priority::Medium,
+            None,
+            created_at,
+        );
+
+        assert_eq!(task.created_at, created_at);
     }
 
     #[test]
     fn test_is_overdue() {
-        let yesterday = Local::now().date_naive() - Duration::days(1);
+        let now = at("2024-03-10 09:00:00");
+        let yesterday = now.date_naive() - Duration::days(1);
         let task = Task::new(
             1,
             "Test".to_string(),
@@ -115,28 +334,88 @@ mod tests {
             Some(yesterday),
         );
 
-        assert!(task.is_overdue());
+        assert!(task.is_overdue_at(&now));
+    }
+
+    #[test]
+    fn test_overdue_at_midnight() {
+        let task = due("2024-03-10", None);
+
+        assert!(!task.is_overdue_at(&at("2024-03-09 12:00:00")));
+        assert!(!task.is_overdue_at(&at("2024-03-10 00:00:00")));
+        assert!(!task.is_overdue_at(&at("2024-03-10 23:59:59")));
+        assert!(task.is_overdue_at(&at("2024-03-11 00:00:00")));
+    }
+
+    #[test]
+    fn test_overdue_at_due_time() {
+        let task = due("2024-03-10", Some("15:00:00"));
+
+        assert!(!task.is_overdue_at(&at("2024-03-10 14:59:59")));
+        assert!(!task.is_overdue_at(&at("2024-03-10 15:00:00")));
+        assert!(task.is_overdue_at(&at("2024-03-10 15:00:01")));
+        assert!(task.is_overdue_at(&at("2024-03-11 08:00:00")));
+
+        // Vencendo à meia-noite, atrasa já no primeiro segundo do dia
+        let task = due("2024-03-10", Some("00:00:00"));
+        assert!(!task.is_overdue_at(&at("2024-03-10 00:00:00")));
+        assert!(task.is_overdue_at(&at("2024-03-10 00:00:01")));
+    }
+
+    #[test]
+    fn test_overdue_uses_wall_clock_across_dst() {
+        // Fim do horário de verão: 02:30 acontece duas vezes, com uma hora
+        // de diferença, e nas duas a tarefa das 02:45 ainda está em dia
+        let task = due("2024-10-27", Some("02:45:00"));
+        assert!(!task.is_overdue_at(&offset_at("2024-10-27T02:30:00+02:00")));
+        assert!(!task.is_overdue_at(&offset_at("2024-10-27T02:30:00+01:00")));
+        assert!(task.is_overdue_at(&offset_at("2024-10-27T02:50:00+01:00")));
+
+        // Início do horário de verão: o dia tem 23 horas, mas vence à meia-noite
+        let task = due("2024-03-31", None);
+        assert!(!task.is_overdue_at(&offset_at("2024-03-31T23:59:59+02:00")));
+        assert!(task.is_overdue_at(&offset_at("2024-04-01T00:00:00+02:00")));
+
+        // O mesmo instante já é o dia seguinte em outro fuso
+        let task = due("2024-03-10", None);
+        assert!(!task.is_overdue_at(&offset_at("2024-03-10T22:00:00+00:00")));
+        assert!(task.is_overdue_at(&offset_at("2024-03-11T01:00:00+03:00")));
+    }
+
+    #[test]
+    fn test_no_due_date_is_never_overdue() {
+        let task = Task::new(1, "A".to_string(), String::new(), Category::Other, Priority::Low, None);
+        assert!(!task.is_overdue_at(&at("2999-12-31 23:59:59")));
     }
 
     #[test]
     fn test_not_overdue_when_completed() {
-        let yesterday = Local::now().date_naive() - Duration::days(1);
+        let mut task = due("2024-03-10", None);
+
+        task.complete(at("2024-03-12 10:00:00"));
+
+        assert!(!task.is_overdue_at(&at("2024-03-12 10:00:00")));
+        assert!(!task.is_overdue_at(&at("2999-12-31 23:59:59")));
+    }
+
+    #[test]
+    fn test_start_task() {
         let mut task = Task::new(
             1,
             "Test".to_string(),
             "Desc".to_string(),
             Category::Work,
-            Priority::High,
-            Some(yesterday),
+            Priority::Medium,
+            None,
         );
 
-        task.complete();
+        task.start();
 
-        assert!(!task.is_overdue());
+        assert_eq!(task.status, Status::InProgress);
     }
 
     #[test]
-    fn test_start_task() {
+    fn test_timer_and_time_spent() {
         let mut task = Task::new(
             1,
             "Test".to_string(),
@@ -146,8 +425,87 @@ mod tests {
             None,
         );
 
-        task.start();
-
+        let start = at("2024-03-10 09:00:00");
+        assert!(task.start_timer(start));
+        assert!(!task.start_timer(start + Duration::minutes(5)));
+        assert!(task.is_timer_running());
         assert_eq!(task.status, Status::InProgress);
+
+        let stop = start + Duration::minutes(30);
+        assert_eq!(task.stop_timer(stop), Some(Duration::minutes(30)));
+        assert!(task.stop_timer(stop).is_none());
+        assert_eq!(task.time_entries.len(), 1);
+
+        task.log_time(45, stop);
+        assert_eq!(task.time_spent(stop).num_minutes(), 75);
+
+        // Com o cronômetro rodando, conta até o instante informado
+        task.start_timer(stop);
+        assert_eq!(task.time_spent(stop + Duration::minutes(10)).num_minutes(), 85);
+    }
+
+    #[test]
+    fn test_over_estimate() {
+        let mut task = Task::new(
+            1,
+            "Test".to_string(),
+            "Desc".to_string(),
+            Category::Work,
+            Priority::Medium,
+            None,
+        );
+
+        let now = at("2024-03-10 12:00:00");
+        task.log_time(90, now);
+        assert!(!task.is_over_estimate(now));
+
+        task.estimate_minutes = Some(60);
+        assert!(task.is_over_estimate(now));
+
+        task.estimate_minutes = Some(120);
+        assert!(!task.is_over_estimate(now));
+
+        // O cronômetro em andamento entra na conta
+        task.start_timer(now);
+        assert!(task.is_over_estimate(now + Duration::minutes(31)));
+    }
+
+    #[test]
+    fn test_complete_stops_timer() {
+        let mut task = Task::new(
+            1,
+            "Test".to_string(),
+            "Desc".to_string(),
+            Category::Work,
+            Priority::Medium,
+            None,
+        );
+
+        let start = at("2024-03-10 09:00:00");
+        task.start_timer(start);
+        task.complete(start + Duration::minutes(20));
+
+        assert!(!task.is_timer_running());
+        assert_eq!(task.time_spent(start + Duration::hours(1)), Duration::minutes(20));
+    }
+
+    #[test]
+    fn test_uuids() {
+        let task = Task::new(1, "A".to_string(), String::new(), Category::Other, Priority::Low, None);
+        let other = Task::new(1, "A".to_string(), String::new(), Category::Other, Priority::Low, None);
+        assert!(!task.uuid.is_nil());
+        assert_ne!(task.uuid, other.uuid);
+
+        // Tarefa antiga, sem o campo: o mesmo UUID em todas as cópias
+        let mut value = serde_json::to_value(&task).unwrap();
+        value.as_object_mut().unwrap().remove("uuid");
+        let mut first: Task = serde_json::from_value(value.clone()).unwrap();
+        let mut second: Task = serde_json::from_value(value).unwrap();
+        assert!(first.uuid.is_nil());
+        first.fill_uuid();
+        second.fill_uuid();
+        assert!(!first.uuid.is_nil());
+        assert_eq!(first.uuid, second.uuid);
+        assert_ne!(first.uuid, task.uuid);
     }
-}
\ No newline at end of file
+}
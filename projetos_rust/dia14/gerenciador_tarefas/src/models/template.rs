@@ -0,0 +1,214 @@
+use crate::i18n::tf;
+use crate::models::enums::normalize;
+use crate::models::{Category, Priority};
+use chrono::{Duration, NaiveDate, NaiveTime};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::io;
+
+/// Modelo de um conjunto de tarefas que se repete (ex.: onboarding)
+///
+/// Títulos e descrições aceitam variáveis como `{nome}`, preenchidas ao
+/// usar o modelo; os prazos são contados em dias a partir de uma data base.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Template {
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    pub tasks: Vec<TemplateTask>,
+}
+
+/// Uma tarefa do modelo
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TemplateTask {
+    pub title: String,
+    #[serde(default)]
+    pub description: String,
+    pub category: Category,
+    pub priority: Priority,
+    /// Prazo em dias depois da data base (0 = no próprio dia)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub due_in_days: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub due_time: Option<NaiveTime>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub estimate_minutes: Option<u32>,
+    /// Posições (a partir de 0) das tarefas do modelo que bloqueiam esta
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub after: Vec<usize>,
+}
+
+impl Template {
+    /// Cria um novo modelo
+    pub fn new(name: String, description: String, tasks: Vec<TemplateTask>) -> Self {
+        Template {
+            name,
+            description,
+            tasks,
+        }
+    }
+
+    /// Compara nomes ignorando maiúsculas e acentos
+    pub fn has_name(&self, name: &str) -> bool {
+        normalize(&self.name) == normalize(name)
+    }
+
+    /// Variáveis usadas nas tarefas, na ordem em que aparecem
+    pub fn variables(&self) -> Vec<String> {
+        let mut variables: Vec<String> = Vec::new();
+        for task in &self.tasks {
+            for text in [&task.title, &task.description] {
+                for variable in placeholders(text) {
+                    if !variables.iter().any(|v| v == variable) {
+                        variables.push(variable.to_string());
+                    }
+                }
+            }
+        }
+        variables
+    }
+}
+
+impl TemplateTask {
+    /// Cria uma tarefa de modelo sem prazo, estimativa nem dependências
+    pub fn new(title: String, category: Category, priority: Priority) -> Self {
+        TemplateTask {
+            title,
+            description: String::new(),
+            category,
+            priority,
+            due_in_days: None,
+            due_time: None,
+            estimate_minutes: None,
+            after: Vec::new(),
+        }
+    }
+
+    /// Prazo contado a partir de `start`
+    ///
+    /// Um `due_in_days` que sai do calendário (o arquivo de modelos pode
+    /// ser editado à mão) é erro.
+    pub fn due_date(&self, start: NaiveDate) -> io::Result<Option<NaiveDate>> {
+        let Some(days) = self.due_in_days else {
+            return Ok(None);
+        };
+        Duration::try_days(days)
+            .and_then(|delta| start.checked_add_signed(delta))
+            .map(Some)
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    tf("error.template_due_out_of_range", &[&self.title, &days]),
+                )
+            })
+    }
+}
+
+/// Substitui as variáveis `{nome}` pelos valores informados
+///
+/// Chaves que não formam um nome (ex.: `{ }` ou `{a-b}`) ficam como estão.
+/// Retorna `Err` com o nome da primeira variável sem valor.
+pub fn fill(text: &str, values: &HashMap<String, String>) -> Result<String, String> {
+    let mut filled = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some((before, variable, after)) = next_placeholder(rest) {
+        let value = values.get(variable).ok_or_else(|| variable.to_string())?;
+        filled.push_str(before);
+        filled.push_str(value);
+        rest = after;
+    }
+    filled.push_str(rest);
+    Ok(filled)
+}
+
+/// Nomes das variáveis de um texto
+fn placeholders(text: &str) -> Vec<&str> {
+    let mut found = Vec::new();
+    let mut rest = text;
+    while let Some((_, variable, after)) = next_placeholder(rest) {
+        found.push(variable);
+        rest = after;
+    }
+    found
+}
+
+/// Próxima variável do texto: (antes, nome, depois)
+fn next_placeholder(text: &str) -> Option<(&str, &str, &str)> {
+    let mut offset = 0;
+    while let Some(open) = text[offset..].find('{').map(|i| offset + i) {
+        let candidate = &text[open + 1..];
+        if let Some(close) = candidate.find('}') {
+            let name = &candidate[..close];
+            if is_variable_name(name) {
+                return Some((&text[..open], name, &candidate[close + 1..]));
+            }
+        }
+        offset = open + 1;
+    }
+    None
+}
+
+fn is_variable_name(name: &str) -> bool {
+    !name.is_empty() && name.chars().all(|c| c.is_alphanumeric() || c == '_')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn values(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_fill() {
+        let values = values(&[("nome", "Ana"), ("equipe", "Dados")]);
+        assert_eq!(
+            fill("Onboarding de {nome} ({equipe})", &values).unwrap(),
+            "Onboarding de Ana (Dados)"
+        );
+        assert_eq!(fill("{nome}{nome}", &values).unwrap(), "AnaAna");
+        assert_eq!(fill("sem variáveis", &values).unwrap(), "sem variáveis");
+        assert_eq!(fill("{ } e {a-b} e {", &values).unwrap(), "{ } e {a-b} e {");
+        assert_eq!(fill("Olá {cargo}", &values), Err("cargo".to_string()));
+    }
+
+    #[test]
+    fn test_variables_and_due_dates() {
+        let mut first = TemplateTask::new(
+            "Criar conta de {nome}".to_string(),
+            Category::Work,
+            Priority::High,
+        );
+        first.due_in_days = Some(0);
+        let mut second = TemplateTask::new(
+            "Apresentar {nome}".to_string(),
+            Category::Work,
+            Priority::Medium,
+        );
+        second.description = "Time {equipe}, com {mentor}".to_string();
+        second.due_in_days = Some(7);
+        let template = Template::new("Onboarding".to_string(), String::new(), vec![first, second]);
+
+        assert_eq!(template.variables(), vec!["nome", "equipe", "mentor"]);
+        assert!(template.has_name("ONBOARDING"));
+
+        let start = NaiveDate::from_ymd_opt(2024, 2, 26).unwrap();
+        assert_eq!(template.tasks[0].due_date(start).unwrap(), Some(start));
+        assert_eq!(
+            template.tasks[1].due_date(start).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 3, 4)
+        );
+        let mut task = TemplateTask::new("x".to_string(), Category::Other, Priority::Low);
+        assert_eq!(task.due_date(start).unwrap(), None);
+
+        // Prazos fora do calendário são erro, não pânico
+        for days in [i64::MAX, i64::MIN, 999_999_999_999] {
+            task.due_in_days = Some(days);
+            assert!(task.due_date(start).is_err());
+        }
+    }
+}
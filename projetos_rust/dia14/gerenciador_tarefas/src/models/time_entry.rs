@@ -0,0 +1,72 @@
+use chrono::{DateTime, Duration, Local};
+use serde::{Deserialize, Serialize};
+
+/// Intervalo de tempo trabalhado em uma tarefa
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TimeEntry {
+    pub start: DateTime<Local>,
+    /// `None` enquanto o cronômetro está rodando
+    pub end: Option<DateTime<Local>>,
+    /// Registrado à mão, sem cronômetro
+    #[serde(default)]
+    pub manual: bool,
+}
+
+impl TimeEntry {
+    /// Inicia um intervalo em `start` (cronômetro)
+    pub fn start_at(start: DateTime<Local>) -> Self {
+        TimeEntry {
+            start,
+            end: None,
+            manual: false,
+        }
+    }
+
+    /// Cria um intervalo manual de `minutes` minutos terminando em `end`
+    pub fn manual(minutes: u32, end: DateTime<Local>) -> Self {
+        TimeEntry {
+            start: end - Duration::minutes(minutes as i64),
+            end: Some(end),
+            manual: true,
+        }
+    }
+
+    /// Verifica se o cronômetro ainda está rodando
+    pub fn is_running(&self) -> bool {
+        self.end.is_none()
+    }
+
+    /// Duração do intervalo (até `now`, se ainda estiver rodando)
+    pub fn duration(&self, now: DateTime<Local>) -> Duration {
+        let end = self.end.unwrap_or(now);
+        (end - self.start).max(Duration::zero())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_manual_entry() {
+        let now = Local::now();
+        let entry = TimeEntry::manual(90, now);
+
+        assert!(entry.manual);
+        assert!(!entry.is_running());
+        assert_eq!(entry.duration(now + Duration::hours(1)).num_minutes(), 90);
+    }
+
+    #[test]
+    fn test_running_entry() {
+        let mut entry = TimeEntry::start_at(Local::now());
+        assert!(entry.is_running());
+        assert_eq!(entry.duration(entry.start + Duration::minutes(10)).num_minutes(), 10);
+        // Um `now` anterior ao início não gera duração negativa
+        assert_eq!(entry.duration(entry.start - Duration::minutes(10)), Duration::zero());
+
+        entry.end = Some(entry.start + Duration::minutes(25));
+        assert!(!entry.is_running());
+        assert_eq!(entry.duration(entry.start).num_minutes(), 25);
+    }
+}
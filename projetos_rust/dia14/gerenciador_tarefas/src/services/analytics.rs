@@ -0,0 +1,277 @@
+use crate::models::{Category, Task};
+use chrono::{Datelike, Duration, NaiveDate};
+
+/// Análise de produtividade de um período
+#[derive(Debug)]
+pub struct Analytics {
+    pub from: NaiveDate,
+    pub to: NaiveDate,
+    /// Tarefas concluídas em cada dia do período
+    pub completed_per_day: Vec<(NaiveDate, usize)>,
+    /// Tarefas concluídas por semana (chave: segunda-feira da semana)
+    pub completed_per_week: Vec<(NaiveDate, usize)>,
+    /// Tempo médio entre criação e conclusão, por categoria
+    pub lead_time_by_category: Vec<(Category, Option<Duration>)>,
+    /// Fração das tarefas com vencimento na semana que atrasaram
+    pub overdue_rate_by_week: Vec<(NaiveDate, Option<f64>)>,
+    /// Tarefas em aberto ao fim de cada dia
+    pub burndown: Vec<(NaiveDate, usize)>,
+}
+
+impl Analytics {
+    /// Calcula a análise do período `from..=to`
+    ///
+    /// `today` decide se uma tarefa em aberto com vencimento passado
+    /// já conta como atrasada.
+    pub fn compute(tasks: &[&Task], from: NaiveDate, to: NaiveDate, today: NaiveDate) -> Self {
+        Analytics {
+            from,
+            to,
+            completed_per_day: completed_per_day(tasks, from, to),
+            completed_per_week: completed_per_week(tasks, from, to),
+            lead_time_by_category: lead_time_by_category(tasks),
+            overdue_rate_by_week: overdue_rate_by_week(tasks, from, to, today),
+            burndown: burndown(tasks, from, to),
+        }
+    }
+}
+
+/// Dias de `from` até `to`, inclusive
+fn days(from: NaiveDate, to: NaiveDate) -> impl Iterator<Item = NaiveDate> {
+    from.iter_days().take_while(move |day| *day <= to)
+}
+
+/// Segunda-feira da semana de `date`
+fn week_start(date: NaiveDate) -> NaiveDate {
+    date - Duration::days(date.weekday().num_days_from_monday() as i64)
+}
+
+/// Semanas (segundas-feiras) que cruzam o período
+fn weeks(from: NaiveDate, to: NaiveDate) -> impl Iterator<Item = NaiveDate> {
+    week_start(from)
+        .iter_weeks()
+        .take_while(move |week| *week <= to)
+}
+
+fn completed_on(task: &Task) -> Option<NaiveDate> {
+    task.completed_at.map(|at| at.date_naive())
+}
+
+/// Tarefas concluídas em cada dia
+pub fn completed_per_day(
+    tasks: &[&Task],
+    from: NaiveDate,
+    to: NaiveDate,
+) -> Vec<(NaiveDate, usize)> {
+    days(from, to)
+        .map(|day| {
+            let count = tasks
+                .iter()
+                .filter(|t| completed_on(t) == Some(day))
+                .count();
+            (day, count)
+        })
+        .collect()
+}
+
+/// Tarefas concluídas em cada semana (segunda a domingo)
+pub fn completed_per_week(
+    tasks: &[&Task],
+    from: NaiveDate,
+    to: NaiveDate,
+) -> Vec<(NaiveDate, usize)> {
+    weeks(from, to)
+        .map(|week| {
+            let count = tasks
+                .iter()
+                .filter_map(|t| completed_on(t))
+                .filter(|day| *day >= from && *day <= to && week_start(*day) == week)
+                .count();
+            (week, count)
+        })
+        .collect()
+}
+
+/// Tempo médio entre criação e conclusão, por categoria
+///
+/// Categorias sem tarefas concluídas ficam com `None`.
+pub fn lead_time_by_category(tasks: &[&Task]) -> Vec<(Category, Option<Duration>)> {
+    Category::all()
+        .into_iter()
+        .map(|category| {
+            let lead_times: Vec<Duration> = tasks
+                .iter()
+                .filter(|t| t.category == category)
+                .filter_map(|t| t.completed_at.map(|at| at - t.created_at))
+                .collect();
+
+            let average = if lead_times.is_empty() {
+                None
+            } else {
+                let total = lead_times
+                    .iter()
+                    .fold(Duration::zero(), |total, d| total + *d);
+                Some(total / lead_times.len() as i32)
+            };
+            (category, average)
+        })
+        .collect()
+}
+
+/// Fração das tarefas com vencimento em cada semana que atrasaram
+///
+/// Atrasou = concluída depois do vencimento, ou ainda em aberto com o
+/// vencimento antes de `today`. Semanas sem vencimentos ficam com `None`.
+pub fn overdue_rate_by_week(
+    tasks: &[&Task],
+    from: NaiveDate,
+    to: NaiveDate,
+    today: NaiveDate,
+) -> Vec<(NaiveDate, Option<f64>)> {
+    weeks(from, to)
+        .map(|week| {
+            let due: Vec<&Task> = tasks
+                .iter()
+                .copied()
+                .filter(|t| t.due_date.is_some_and(|d| week_start(d) == week))
+                .collect();
+
+            if due.is_empty() {
+                return (week, None);
+            }
+
+            let late = due
+                .iter()
+                .filter(|t| {
+                    let due_date = t.due_date.unwrap_or(today);
+                    match completed_on(t) {
+                        Some(done) => done > due_date,
+                        None => due_date < today,
+                    }
+                })
+                .count();
+            (week, Some(late as f64 / due.len() as f64))
+        })
+        .collect()
+}
+
+/// Tarefas em aberto ao fim de cada dia do período
+pub fn burndown(tasks: &[&Task], from: NaiveDate, to: NaiveDate) -> Vec<(NaiveDate, usize)> {
+    days(from, to)
+        .map(|day| {
+            let open = tasks
+                .iter()
+                .filter(|t| t.created_at.date_naive() <= day)
+                .filter(|t| completed_on(t).is_none_or(|done| done > day))
+                .count();
+            (day, open)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Priority, Status};
+    use chrono::{Local, TimeZone};
+
+    fn date(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, 6, day).unwrap()
+    }
+
+    /// Tarefa criada no dia `created` e, opcionalmente, concluída no dia `completed`
+    fn task(category: Category, created: u32, completed: Option<u32>, due: Option<u32>) -> Task {
+        let mut task = Task::new(
+            1,
+            "Test".to_string(),
+            "Desc".to_string(),
+            category,
+            Priority::Medium,
+            due.map(date),
+        );
+        let at = |day: u32| Local.with_ymd_and_hms(2024, 6, day, 12, 0, 0).unwrap();
+        task.created_at = at(created);
+        if let Some(day) = completed {
+            task.status = Status::Completed;
+            task.completed_at = Some(at(day));
+        }
+        task
+    }
+
+    fn refs(tasks: &[Task]) -> Vec<&Task> {
+        tasks.iter().collect()
+    }
+
+    #[test]
+    fn test_completed_per_day_and_week() {
+        // 2024-06-03 é uma segunda-feira
+        let tasks = vec![
+            task(Category::Work, 3, Some(4), None),
+            task(Category::Work, 3, Some(4), None),
+            task(Category::Study, 3, Some(11), None),
+            task(Category::Study, 3, None, None),
+        ];
+
+        let per_day = completed_per_day(&refs(&tasks), date(3), date(5));
+        assert_eq!(per_day, vec![(date(3), 0), (date(4), 2), (date(5), 0)]);
+
+        let per_week = completed_per_week(&refs(&tasks), date(3), date(16));
+        assert_eq!(per_week, vec![(date(3), 2), (date(10), 1)]);
+    }
+
+    #[test]
+    fn test_lead_time_by_category() {
+        let tasks = vec![
+            task(Category::Work, 3, Some(4), None),
+            task(Category::Work, 3, Some(6), None),
+            task(Category::Study, 3, None, None),
+        ];
+
+        let lead_times = lead_time_by_category(&refs(&tasks));
+        let work = lead_times
+            .iter()
+            .find(|(c, _)| *c == Category::Work)
+            .unwrap();
+        let study = lead_times
+            .iter()
+            .find(|(c, _)| *c == Category::Study)
+            .unwrap();
+
+        assert_eq!(work.1, Some(Duration::days(2)));
+        assert_eq!(study.1, None);
+    }
+
+    #[test]
+    fn test_overdue_rate_by_week() {
+        let tasks = vec![
+            task(Category::Work, 3, Some(4), Some(5)),
+            task(Category::Work, 3, Some(7), Some(5)),
+            task(Category::Work, 3, None, Some(12)),
+        ];
+
+        let rates = overdue_rate_by_week(&refs(&tasks), date(3), date(23), date(20));
+        assert_eq!(
+            rates,
+            vec![
+                (date(3), Some(0.5)),
+                (date(10), Some(1.0)),
+                (date(17), None)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_burndown() {
+        let tasks = vec![
+            task(Category::Work, 3, Some(5), None),
+            task(Category::Work, 3, Some(6), None),
+            task(Category::Work, 4, None, None),
+        ];
+
+        let burndown = burndown(&refs(&tasks), date(3), date(6));
+        assert_eq!(
+            burndown,
+            vec![(date(3), 2), (date(4), 3), (date(5), 2), (date(6), 1)]
+        );
+    }
+}
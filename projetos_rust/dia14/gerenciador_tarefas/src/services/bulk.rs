@@ -0,0 +1,132 @@
+use crate::models::{Category, Priority};
+use chrono::NaiveDate;
+use std::collections::HashSet;
+use std::io;
+
+/// Ação aplicada de uma vez a várias tarefas
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BulkAction {
+    Complete,
+    Start,
+    Delete,
+    SetPriority(Priority),
+    SetCategory(Category),
+    /// `None` remove a data de vencimento
+    SetDueDate(Option<NaiveDate>),
+}
+
+/// Resultado de uma operação em lote, por ID
+#[derive(Debug, Default)]
+pub struct BulkReport {
+    pub results: Vec<(u32, io::Result<()>)>,
+}
+
+impl BulkReport {
+    /// IDs alterados com sucesso
+    pub fn succeeded(&self) -> Vec<u32> {
+        self.results
+            .iter()
+            .filter(|(_, result)| result.is_ok())
+            .map(|(id, _)| *id)
+            .collect()
+    }
+
+    /// IDs que falharam, com o motivo
+    pub fn failed(&self) -> Vec<(u32, &io::Error)> {
+        self.results
+            .iter()
+            .filter_map(|(id, result)| result.as_ref().err().map(|e| (*id, e)))
+            .collect()
+    }
+}
+
+/// Interpreta uma lista de IDs com intervalos, como "3-7,10" ou "3 - 7"
+///
+/// IDs repetidos aparecem uma vez só, na ordem em que surgiram.
+/// Retorna `None` se algum trecho for inválido ou se um intervalo tiver
+/// mais de `max_range` IDs (ex.: o maior ID existente).
+pub fn parse_ids(text: &str, max_range: u32) -> Option<Vec<u32>> {
+    let mut ids = Vec::new();
+    let mut seen = HashSet::new();
+
+    for part in text.split(',').flat_map(join_ranges) {
+        let range = match part.split_once('-') {
+            Some((start, end)) => {
+                let start: u32 = start.parse().ok()?;
+                let end: u32 = end.parse().ok()?;
+                if start > end || end - start >= max_range {
+                    return None;
+                }
+                start..=end
+            }
+            None => {
+                let id: u32 = part.parse().ok()?;
+                id..=id
+            }
+        };
+
+        ids.extend(range.filter(|id| seen.insert(*id)));
+    }
+
+    if ids.is_empty() { None } else { Some(ids) }
+}
+
+/// Separa um trecho em palavras, juntando os espaços em volta do traço
+/// ("3 - 7 9" vira "3-7" e "9")
+fn join_ranges(text: &str) -> Vec<String> {
+    let mut parts: Vec<String> = Vec::new();
+    for word in text.split_whitespace() {
+        match parts.last_mut() {
+            Some(last) if last.ends_with('-') || word.starts_with('-') => last.push_str(word),
+            _ => parts.push(word.to_string()),
+        }
+    }
+    parts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_ids() {
+        assert_eq!(parse_ids("3-7,10", 100), Some(vec![3, 4, 5, 6, 7, 10]));
+        assert_eq!(parse_ids("1, 2 2,1-3", 100), Some(vec![1, 2, 3]));
+        assert_eq!(parse_ids("5", 100), Some(vec![5]));
+        assert_eq!(parse_ids("3 - 7, 9", 100), Some(vec![3, 4, 5, 6, 7, 9]));
+        assert_eq!(parse_ids("3 -7 9- 10", 100), Some(vec![3, 4, 5, 6, 7, 9, 10]));
+    }
+
+    #[test]
+    fn test_parse_ids_invalid() {
+        assert_eq!(parse_ids("", 100), None);
+        assert_eq!(parse_ids("7-3", 100), None);
+        assert_eq!(parse_ids("1,a", 100), None);
+        assert_eq!(parse_ids("1-", 100), None);
+        assert_eq!(parse_ids("1 - - 3", 100), None);
+    }
+
+    #[test]
+    fn test_parse_ids_limits_ranges() {
+        assert_eq!(parse_ids("1-10", 10).map(|ids| ids.len()), Some(10));
+        assert_eq!(parse_ids("1-11", 10), None);
+        assert_eq!(parse_ids("1-4000000000", 10), None);
+        assert_eq!(parse_ids("0-4294967295", u32::MAX), None);
+        // IDs soltos não contam como intervalo
+        assert_eq!(parse_ids("500", 10), Some(vec![500]));
+    }
+
+    #[test]
+    fn test_report() {
+        let report = BulkReport {
+            results: vec![
+                (1, Ok(())),
+                (2, Err(io::Error::new(io::ErrorKind::NotFound, "x"))),
+            ],
+        };
+
+        assert_eq!(report.succeeded(), vec![1]);
+        assert_eq!(report.failed().len(), 1);
+        assert_eq!(report.failed()[0].0, 2);
+    }
+}
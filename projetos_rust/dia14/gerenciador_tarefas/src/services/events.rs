@@ -0,0 +1,321 @@
+use crate::models::Task;
+use crate::storage::crypto::{self, Cipher};
+use chrono::{DateTime, Local};
+use serde::Serialize;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::Path;
+use std::sync::Arc;
+
+/// Evento emitido pelo TaskService após uma alteração salva
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum TaskEvent {
+    Created { after: Task },
+    Updated { before: Task, after: Task },
+    Started { before: Task, after: Task },
+    Completed { before: Task, after: Task },
+    Deleted { before: Task },
+    Restored { after: Task },
+    Archived { before: Task },
+    Purged { before: Task },
+}
+
+impl TaskEvent {
+    /// Nome do evento (o mesmo usado no log JSON)
+    pub fn name(&self) -> &str {
+        match self {
+            TaskEvent::Created { .. } => "created",
+            TaskEvent::Updated { .. } => "updated",
+            TaskEvent::Started { .. } => "started",
+            TaskEvent::Completed { .. } => "completed",
+            TaskEvent::Deleted { .. } => "deleted",
+            TaskEvent::Restored { .. } => "restored",
+            TaskEvent::Archived { .. } => "archived",
+            TaskEvent::Purged { .. } => "purged",
+        }
+    }
+
+    /// Estado da tarefa antes da alteração
+    pub fn before(&self) -> Option<&Task> {
+        match self {
+            TaskEvent::Created { .. } | TaskEvent::Restored { .. } => None,
+            TaskEvent::Updated { before, .. }
+            | TaskEvent::Started { before, .. }
+            | TaskEvent::Completed { before, .. }
+            | TaskEvent::Deleted { before }
+            | TaskEvent::Archived { before }
+            | TaskEvent::Purged { before } => Some(before),
+        }
+    }
+
+    /// Estado da tarefa depois da alteração
+    pub fn after(&self) -> Option<&Task> {
+        match self {
+            TaskEvent::Created { after }
+            | TaskEvent::Updated { after, .. }
+            | TaskEvent::Started { after, .. }
+            | TaskEvent::Completed { after, .. }
+            | TaskEvent::Restored { after } => Some(after),
+            TaskEvent::Deleted { .. } | TaskEvent::Archived { .. } | TaskEvent::Purged { .. } => {
+                None
+            }
+        }
+    }
+
+    /// ID da tarefa afetada
+    pub fn task_id(&self) -> u32 {
+        self.after().or(self.before()).map(|t| t.id).unwrap_or(0)
+    }
+}
+
+/// Observador de eventos de tarefas
+///
+/// Erros retornados por um subscriber não desfazem a alteração,
+/// que já foi salva quando o evento é emitido.
+pub trait TaskSubscriber {
+    /// Recebe um evento e o horário da alteração, pelo relógio do serviço
+    fn on_event(&mut self, event: &TaskEvent, at: DateTime<Local>) -> io::Result<()>;
+
+    /// Recebe a cifra com que as tarefas são gravadas (`None` = sem
+    /// criptografia), ao ser registrado e a cada troca de senha
+    ///
+    /// Quem grava dados das tarefas em disco deve criptografá-los também.
+    fn on_cipher_changed(&mut self, _cipher: Option<&Arc<Cipher>>) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Linha gravada no log de eventos
+#[derive(Serialize)]
+struct EventRecord<'a> {
+    timestamp: DateTime<Local>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    user: Option<&'a str>,
+    #[serde(flatten)]
+    event: &'a TaskEvent,
+}
+
+/// Subscriber que acrescenta cada evento como uma linha JSON em um arquivo
+///
+/// Com as tarefas criptografadas, cada linha é um envelope criptografado
+/// (ver [`Cipher::encrypt_line`]).
+pub struct EventLogSubscriber {
+    file_path: String,
+    /// Usuário gravado em cada evento
+    user: Option<String>,
+    cipher: Option<Arc<Cipher>>,
+}
+
+impl EventLogSubscriber {
+    /// Cria um novo EventLogSubscriber
+    pub fn new(file_path: String) -> Self {
+        EventLogSubscriber {
+            file_path,
+            user: None,
+            cipher: None,
+        }
+    }
+
+    /// Define o usuário que assina os eventos gravados
+    pub fn with_user(mut self, user: Option<String>) -> Self {
+        self.user = user;
+        self
+    }
+
+    /// Deleta o arquivo de log
+    pub fn delete(&self) -> io::Result<()> {
+        if Path::new(&self.file_path).exists() {
+            fs::remove_file(&self.file_path)?;
+        }
+        Ok(())
+    }
+}
+
+impl TaskSubscriber for EventLogSubscriber {
+    fn on_event(&mut self, event: &TaskEvent, at: DateTime<Local>) -> io::Result<()> {
+        let record = EventRecord {
+            timestamp: at,
+            user: self.user.as_deref(),
+            event,
+        };
+        let mut line = serde_json::to_string(&record)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        if let Some(cipher) = &self.cipher {
+            line = cipher.encrypt_line(line.as_bytes())?;
+        }
+
+        // Cria o diretório se não existir
+        if let Some(parent) = Path::new(&self.file_path).parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.file_path)?;
+        writeln!(file, "{}", line)
+    }
+
+    /// Criptografa de novo as linhas já gravadas com a cifra nova
+    ///
+    /// Linhas em texto puro (de antes da criptografia) também passam a ser
+    /// criptografadas. Sem cifra, o log fica como está.
+    fn on_cipher_changed(&mut self, cipher: Option<&Arc<Cipher>>) -> io::Result<()> {
+        let previous = self.cipher.as_ref();
+        let Some(next) = cipher else {
+            self.cipher = None;
+            return Ok(());
+        };
+        let content = match fs::read_to_string(&self.file_path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(e),
+        };
+
+        let sealed = |line: &str| crypto::is_encrypted(line.as_bytes());
+        // Log de outra sessão: só continua se abrir com esta senha
+        if previous.is_none()
+            && let Some(line) = content.lines().rfind(|line| sealed(line))
+        {
+            next.decrypt(line.as_bytes(), &self.file_path)?;
+        }
+        let same_key = previous.is_none_or(|previous| Arc::ptr_eq(previous, next));
+        if !content.is_empty() && (!same_key || !content.lines().all(sealed)) {
+            // Linhas antigas abrem com a cifra anterior (ou com esta, ao
+            // registrar o log de outra sessão)
+            let reader = previous.unwrap_or(next);
+            let mut rewritten = String::with_capacity(content.len());
+            for line in content.lines() {
+                let plain = if sealed(line) {
+                    reader.decrypt(line.as_bytes(), &self.file_path)?
+                } else {
+                    line.as_bytes().to_vec()
+                };
+                rewritten.push_str(&next.encrypt_line(&plain)?);
+                rewritten.push('\n');
+            }
+            let temporary = format!("{}.tmp", self.file_path);
+            fs::write(&temporary, rewritten)?;
+            fs::rename(&temporary, &self.file_path)?;
+        }
+
+        self.cipher = Some(Arc::clone(next));
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Category, Priority};
+    use chrono::TimeZone;
+
+    fn sample_task() -> Task {
+        Task::new(
+            7,
+            "Test".to_string(),
+            "Desc".to_string(),
+            Category::Work,
+            Priority::High,
+            None,
+        )
+    }
+
+    #[test]
+    fn test_event_accessors() {
+        let task = sample_task();
+        let mut started = task.clone();
+        started.start();
+
+        let event = TaskEvent::Started {
+            before: task,
+            after: started,
+        };
+
+        assert_eq!(event.name(), "started");
+        assert_eq!(event.task_id(), 7);
+        assert!(event.before().is_some());
+        assert!(event.after().is_some());
+
+        let deleted = TaskEvent::Deleted {
+            before: sample_task(),
+        };
+        assert!(deleted.after().is_none());
+        assert_eq!(deleted.task_id(), 7);
+    }
+
+    #[test]
+    fn test_event_log_appends_json_lines() {
+        let mut log = EventLogSubscriber::new("data/test_events.jsonl".to_string())
+            .with_user(Some("ana".to_string()));
+        log.delete().unwrap();
+
+        let at = Local.with_ymd_and_hms(2024, 6, 1, 9, 30, 0).unwrap();
+        log.on_event(
+            &TaskEvent::Created {
+                after: sample_task(),
+            },
+            at,
+        )
+        .unwrap();
+        log.on_event(
+            &TaskEvent::Deleted {
+                before: sample_task(),
+            },
+            at,
+        )
+        .unwrap();
+
+        let content = fs::read_to_string("data/test_events.jsonl").unwrap();
+        let lines: Vec<serde_json::Value> = content
+            .lines()
+            .map(|l| serde_json::from_str(l).unwrap())
+            .collect();
+
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0]["event"], "created");
+        assert_eq!(lines[0]["after"]["id"], 7);
+        assert_eq!(lines[0]["timestamp"], serde_json::to_value(at).unwrap());
+        assert_eq!(lines[0]["user"], "ana");
+        assert_eq!(lines[1]["event"], "deleted");
+
+        log.delete().unwrap();
+    }
+
+    #[test]
+    fn test_event_log_follows_the_cipher() {
+        let path = "data/test_events_encrypted.jsonl";
+        let mut log = EventLogSubscriber::new(path.to_string());
+        log.delete().unwrap();
+        let created = TaskEvent::Created {
+            after: sample_task(),
+        };
+        log.on_event(&created, Local::now()).unwrap();
+
+        // Ao criptografar, a linha antiga também deixa de ser legível
+        let first = Arc::new(Cipher::new("primeira"));
+        log.on_cipher_changed(Some(&first)).unwrap();
+        log.on_event(&created, Local::now()).unwrap();
+        let content = fs::read_to_string(path).unwrap();
+        assert_eq!(content.lines().count(), 2);
+        assert!(!content.contains("Desc"));
+        assert!(content.lines().all(|l| crypto::is_encrypted(l.as_bytes())));
+
+        // Trocar a senha grava tudo de novo com a chave nova
+        let second = Arc::new(Cipher::new("segunda"));
+        log.on_cipher_changed(Some(&second)).unwrap();
+        for line in fs::read_to_string(path).unwrap().lines() {
+            assert!(first.decrypt(line.as_bytes(), path).is_err());
+            let plain = second.decrypt(line.as_bytes(), path).unwrap();
+            let record: serde_json::Value = serde_json::from_slice(&plain).unwrap();
+            assert_eq!(record["event"], "created");
+        }
+
+        // Outra sessão com a senha errada não consegue registrar o log
+        let mut other = EventLogSubscriber::new(path.to_string());
+        assert!(other.on_cipher_changed(Some(&first)).is_err());
+
+        log.delete().unwrap();
+    }
+}
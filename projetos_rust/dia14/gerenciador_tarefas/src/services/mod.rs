@@ -1,4 +1,18 @@
 // src/services/mod.rs
+pub mod analytics;
+pub mod bulk;
+pub mod events;
+pub mod pomodoro;
+pub mod quick_add;
+pub mod scoring;
+pub mod sync;
 pub mod task_service;
 
-pub use task_service::TaskService;
\ No newline at end of file
+pub use analytics::Analytics;
+pub use bulk::{BulkAction, BulkReport};
+pub use events::{EventLogSubscriber, TaskEvent, TaskSubscriber};
+pub use pomodoro::{FocusEvent, FocusSession, Phase, PomodoroSettings};
+pub use quick_add::QuickAdd;
+pub use scoring::Score;
+pub use sync::{SyncReport, SyncStore};
+pub use task_service::{TaskService, WipLimits};
\ No newline at end of file
@@ -0,0 +1,242 @@
+use crate::config::Config;
+use crate::models::Pomodoro;
+use crate::utils::Clock;
+use chrono::{DateTime, Duration, Local};
+use std::sync::Arc;
+
+/// Durações de uma sessão de foco
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PomodoroSettings {
+    pub work_minutes: u32,
+    /// 0 = sem pausa entre os pomodoros
+    pub break_minutes: u32,
+}
+
+impl Default for PomodoroSettings {
+    fn default() -> Self {
+        PomodoroSettings {
+            work_minutes: 25,
+            break_minutes: 5,
+        }
+    }
+}
+
+impl PomodoroSettings {
+    /// Durações configuradas (`pomodoro_work_minutes`, `pomodoro_break_minutes`)
+    pub fn from_config(config: &Config) -> Self {
+        PomodoroSettings {
+            work_minutes: config.pomodoro_work_minutes,
+            break_minutes: config.pomodoro_break_minutes,
+        }
+    }
+}
+
+/// Fase atual da sessão
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Phase {
+    Work,
+    Break,
+}
+
+/// Mudança de fase percebida ao conferir o relógio
+#[derive(Debug, Clone, PartialEq)]
+pub enum FocusEvent {
+    /// Um pomodoro chegou ao fim
+    Completed(Pomodoro),
+    /// A pausa acabou; o próximo pomodoro espera ser retomado
+    BreakOver,
+}
+
+/// Sessão de foco em uma tarefa: pomodoros intercalados com pausas
+///
+/// A sessão não espera nem dorme: a cada [`FocusSession::tick`] ela compara
+/// o relógio com o início da fase, descontando o tempo pausado. Depois de
+/// cada pausa, o pomodoro seguinte começa pausado, para não contar foco de
+/// quem saiu de perto.
+pub struct FocusSession {
+    task_id: u32,
+    settings: PomodoroSettings,
+    clock: Arc<dyn Clock>,
+    phase: Phase,
+    phase_start: DateTime<Local>,
+    /// Tempo pausado na fase atual, sem contar uma pausa em andamento
+    paused: Duration,
+    paused_at: Option<DateTime<Local>>,
+    completed: u32,
+}
+
+impl FocusSession {
+    /// Começa um pomodoro agora
+    pub fn start(task_id: u32, settings: PomodoroSettings, clock: Arc<dyn Clock>) -> Self {
+        let now = clock.now();
+        FocusSession {
+            task_id,
+            settings,
+            clock,
+            phase: Phase::Work,
+            phase_start: now,
+            paused: Duration::zero(),
+            paused_at: None,
+            completed: 0,
+        }
+    }
+
+    /// ID da tarefa em foco
+    pub fn task_id(&self) -> u32 {
+        self.task_id
+    }
+
+    /// Fase atual
+    pub fn phase(&self) -> Phase {
+        self.phase
+    }
+
+    /// Verifica se a contagem está pausada
+    pub fn is_paused(&self) -> bool {
+        self.paused_at.is_some()
+    }
+
+    /// Pomodoros concluídos nesta sessão
+    pub fn completed(&self) -> u32 {
+        self.completed
+    }
+
+    /// Tempo que falta para a fase acabar
+    pub fn remaining(&self) -> Duration {
+        (self.length(self.phase) - self.elapsed()).max(Duration::zero())
+    }
+
+    /// Pausa a contagem (sem efeito se já está pausada)
+    pub fn pause(&mut self) {
+        if self.paused_at.is_none() {
+            self.paused_at = Some(self.clock.now());
+        }
+    }
+
+    /// Retoma a contagem (sem efeito se não está pausada)
+    pub fn resume(&mut self) {
+        if let Some(at) = self.paused_at.take() {
+            self.paused += self.clock.now() - at;
+        }
+    }
+
+    /// Pausa ou retoma
+    pub fn toggle_pause(&mut self) {
+        if self.is_paused() {
+            self.resume();
+        } else {
+            self.pause();
+        }
+    }
+
+    /// Confere o relógio e avança as fases que já acabaram
+    pub fn tick(&mut self) -> Vec<FocusEvent> {
+        let mut events = Vec::new();
+        while !self.is_paused() && self.remaining().is_zero() {
+            // Quando a fase acabou de fato (o relógio pode ter pulado)
+            let end = self.phase_start + self.paused + self.length(self.phase);
+            match self.phase {
+                Phase::Work => {
+                    self.completed += 1;
+                    events.push(FocusEvent::Completed(Pomodoro {
+                        started_at: self.phase_start,
+                        finished_at: end,
+                        minutes: self.settings.work_minutes,
+                    }));
+                    if self.settings.break_minutes > 0 {
+                        self.enter(Phase::Break, end, false);
+                    } else {
+                        self.enter(Phase::Work, end, true);
+                    }
+                }
+                Phase::Break => {
+                    events.push(FocusEvent::BreakOver);
+                    self.enter(Phase::Work, end, true);
+                }
+            }
+        }
+        events
+    }
+
+    fn enter(&mut self, phase: Phase, start: DateTime<Local>, paused: bool) {
+        self.phase = phase;
+        self.phase_start = start;
+        self.paused = Duration::zero();
+        self.paused_at = paused.then_some(start);
+    }
+
+    fn elapsed(&self) -> Duration {
+        let now = self.paused_at.unwrap_or_else(|| self.clock.now());
+        now - self.phase_start - self.paused
+    }
+
+    fn length(&self, phase: Phase) -> Duration {
+        let minutes = match phase {
+            Phase::Work => self.settings.work_minutes,
+            Phase::Break => self.settings.break_minutes,
+        };
+        Duration::minutes(minutes as i64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::ManualClock;
+
+    fn session() -> (Arc<ManualClock>, FocusSession) {
+        let clock = Arc::new(ManualClock::new(Local::now()));
+        let session = FocusSession::start(7, PomodoroSettings::default(), clock.clone());
+        (clock, session)
+    }
+
+    #[test]
+    fn test_countdown_and_pause() {
+        let (clock, mut session) = session();
+        let start = clock.now();
+
+        clock.advance(Duration::minutes(10));
+        assert!(session.tick().is_empty());
+        assert_eq!(session.remaining(), Duration::minutes(15));
+
+        // Pausado, o relógio anda e a contagem não
+        session.pause();
+        clock.advance(Duration::minutes(30));
+        assert!(session.tick().is_empty());
+        assert_eq!(session.remaining(), Duration::minutes(15));
+        session.resume();
+
+        clock.advance(Duration::minutes(15));
+        let events = session.tick();
+        assert_eq!(
+            events,
+            vec![FocusEvent::Completed(Pomodoro {
+                started_at: start,
+                finished_at: start + Duration::minutes(55),
+                minutes: 25,
+            })]
+        );
+        assert_eq!(session.completed(), 1);
+        assert_eq!(session.phase(), Phase::Break);
+        assert_eq!(session.remaining(), Duration::minutes(5));
+    }
+
+    #[test]
+    fn test_next_pomodoro_waits_after_break() {
+        let (clock, mut session) = session();
+
+        // O relógio pulou a pausa inteira: o próximo pomodoro fica esperando
+        clock.advance(Duration::minutes(60));
+        let events = session.tick();
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[1], FocusEvent::BreakOver);
+        assert_eq!(session.phase(), Phase::Work);
+        assert!(session.is_paused());
+        assert_eq!(session.remaining(), Duration::minutes(25));
+
+        session.toggle_pause();
+        clock.advance(Duration::minutes(25));
+        assert!(matches!(session.tick()[..], [FocusEvent::Completed(_)]));
+        assert_eq!(session.completed(), 2);
+    }
+}
@@ -0,0 +1,299 @@
+use crate::i18n;
+use crate::models::enums::normalize;
+use crate::models::{Category, Priority};
+use chrono::{Datelike, Duration, NaiveDate, NaiveTime, Weekday};
+
+/// Tarefa descrita em uma linha, como "Revisar PR amanhã 15h #trabalho !alta"
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QuickAdd {
+    pub title: String,
+    pub category: Option<Category>,
+    pub priority: Option<Priority>,
+    pub due_date: Option<NaiveDate>,
+    pub due_time: Option<NaiveTime>,
+}
+
+/// Palavras que só ligam o título a uma data ou hora ("até sexta", "às 15h")
+const CONNECTORS: &[&str] = &["ate", "para", "as", "a", "by", "on", "at", "due"];
+
+/// Interpreta uma linha de adição rápida
+///
+/// Reconhece:
+/// - `#categoria` e `!prioridade` (em qualquer idioma, sem acentos);
+/// - datas relativas: hoje, amanhã, depois de amanhã, dias da semana
+///   ("sexta", "próxima segunda", "next monday"), "em 3 dias", "in 2 weeks";
+/// - datas no formato do idioma ou ISO (2030-12-31);
+/// - horas: 15h, 15h30, 15:30, 3pm.
+///
+/// O que não for reconhecido fica no título. `today` é a referência
+/// das datas relativas.
+pub fn parse(text: &str, today: NaiveDate) -> QuickAdd {
+    let tokens: Vec<&str> = text.split_whitespace().collect();
+    let words: Vec<String> = tokens.iter().map(|t| normalize(t)).collect();
+    let mut used = vec![false; tokens.len()];
+
+    let mut quick = QuickAdd {
+        title: String::new(),
+        category: None,
+        priority: None,
+        due_date: None,
+        due_time: None,
+    };
+
+    let mut i = 0;
+    while i < tokens.len() {
+        let word = words[i].as_str();
+
+        let consumed = if let Some(tag) = word.strip_prefix('#') {
+            Category::parse(tag).map(|category| {
+                quick.category = Some(category);
+                1
+            })
+        } else if let Some(tag) = word.strip_prefix('!') {
+            Priority::parse(tag).map(|priority| {
+                quick.priority = Some(priority);
+                1
+            })
+        } else if quick.due_time.is_none()
+            && let Some(time) = parse_time(word)
+        {
+            quick.due_time = Some(time);
+            Some(1)
+        } else if quick.due_date.is_none()
+            && let Some((date, len)) = parse_date(&words[i..], today)
+        {
+            quick.due_date = Some(date);
+            Some(len)
+        } else {
+            None
+        };
+
+        match consumed {
+            Some(len) => {
+                if i > 0 && !used[i - 1] && CONNECTORS.contains(&words[i - 1].as_str()) {
+                    used[i - 1] = true;
+                }
+                used[i..i + len].iter_mut().for_each(|u| *u = true);
+                i += len;
+            }
+            None => i += 1,
+        }
+    }
+
+    // Hora sem data vale para hoje
+    if quick.due_time.is_some() && quick.due_date.is_none() {
+        quick.due_date = Some(today);
+    }
+
+    quick.title = tokens
+        .iter()
+        .zip(&used)
+        .filter(|(_, used)| !**used)
+        .map(|(token, _)| *token)
+        .collect::<Vec<_>>()
+        .join(" ");
+    quick
+}
+
+/// Interpreta uma expressão de data no início de `words`
+///
+/// Retorna a data e quantas palavras ela ocupa.
+fn parse_date(words: &[String], today: NaiveDate) -> Option<(NaiveDate, usize)> {
+    let word = |i: usize| words.get(i).map(String::as_str).unwrap_or("");
+
+    match (word(0), word(1), word(2)) {
+        ("depois", "de", "amanha") => return Some((today + Duration::days(2), 3)),
+        ("day", "after", "tomorrow") => return Some((today + Duration::days(2), 3)),
+        ("hoje" | "today", _, _) => return Some((today, 1)),
+        ("amanha" | "tomorrow", _, _) => return Some((today + Duration::days(1), 1)),
+        ("em" | "in", n, unit) => {
+            if let Ok(n) = n.parse::<i64>() {
+                let days = match unit {
+                    "dia" | "dias" | "day" | "days" => Some(n),
+                    "semana" | "semanas" | "week" | "weeks" => n.checked_mul(7),
+                    _ => None,
+                };
+                // Prazos fora do calendário não são datas: ficam no título
+                let due = days
+                    .and_then(Duration::try_days)
+                    .and_then(|delta| today.checked_add_signed(delta));
+                if let Some(due) = due {
+                    return Some((due, 3));
+                }
+            }
+        }
+        ("proxima" | "proximo" | "next", day, _) => {
+            if let Some(weekday) = parse_weekday(day) {
+                return Some((next_weekday(today, weekday), 2));
+            }
+        }
+        (day, _, _) => {
+            if let Some(weekday) = parse_weekday(day) {
+                return Some((next_weekday(today, weekday), 1));
+            }
+        }
+    }
+
+    let text = word(0);
+    NaiveDate::parse_from_str(text, &i18n::date_format())
+        .or_else(|_| NaiveDate::parse_from_str(text, "%Y-%m-%d"))
+        .ok()
+        .map(|date| (date, 1))
+}
+
+/// Dia da semana em português ou inglês ("sexta", "sexta-feira", "friday")
+fn parse_weekday(word: &str) -> Option<Weekday> {
+    let word = word.trim_end_matches("-feira");
+    let weekday = match word {
+        "segunda" | "monday" => Weekday::Mon,
+        "terca" | "tuesday" => Weekday::Tue,
+        "quarta" | "wednesday" => Weekday::Wed,
+        "quinta" | "thursday" => Weekday::Thu,
+        "sexta" | "friday" => Weekday::Fri,
+        "sabado" | "saturday" => Weekday::Sat,
+        "domingo" | "sunday" => Weekday::Sun,
+        _ => return None,
+    };
+    Some(weekday)
+}
+
+/// Próxima ocorrência do dia da semana, sempre depois de `today`
+fn next_weekday(today: NaiveDate, weekday: Weekday) -> NaiveDate {
+    let ahead = (weekday.num_days_from_monday() as i64
+        - today.weekday().num_days_from_monday() as i64)
+        .rem_euclid(7);
+    today + Duration::days(if ahead == 0 { 7 } else { ahead })
+}
+
+/// Hora como "15h", "15h30", "15:30", "3pm" ou "11am"
+fn parse_time(word: &str) -> Option<NaiveTime> {
+    let (text, meridiem) = if let Some(rest) = word.strip_suffix("pm") {
+        (rest, Some(12))
+    } else if let Some(rest) = word.strip_suffix("am") {
+        (rest, Some(0))
+    } else {
+        (word, None)
+    };
+
+    let separator = if text.contains('h') {
+        'h'
+    } else if text.contains(':') || meridiem.is_some() {
+        ':'
+    } else {
+        return None;
+    };
+
+    let (mut hour, minute) = split_time(text, separator)?;
+    if let Some(offset) = meridiem {
+        if !(1..=12).contains(&hour) {
+            return None;
+        }
+        hour = hour % 12 + offset;
+    }
+    NaiveTime::from_hms_opt(hour, minute, 0)
+}
+
+/// Separa "15h30" / "15:30" / "15h" em hora e minuto
+fn split_time(text: &str, separator: char) -> Option<(u32, u32)> {
+    let (hour, minute) = match text.split_once(separator) {
+        Some((hour, "")) => (hour, "0"),
+        Some((hour, minute)) => (hour, minute),
+        None => (text, "0"),
+    };
+    if hour.is_empty() || !hour.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    if !minute.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    Some((hour.parse().ok()?, minute.parse().ok()?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Quarta-feira, 12/06/2024
+    fn today() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, 6, 12).unwrap()
+    }
+
+    fn date(month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, month, day).unwrap()
+    }
+
+    #[test]
+    fn test_full_example() {
+        let quick = parse("Revisar PR amanhã 15h #trabalho !alta", today());
+
+        assert_eq!(quick.title, "Revisar PR");
+        assert_eq!(quick.due_date, Some(date(6, 13)));
+        assert_eq!(quick.due_time, NaiveTime::from_hms_opt(15, 0, 0));
+        assert_eq!(quick.category, Some(Category::Work));
+        assert_eq!(quick.priority, Some(Priority::High));
+    }
+
+    #[test]
+    fn test_relative_dates() {
+        let due = |text: &str| parse(text, today()).due_date;
+
+        assert_eq!(due("Pagar conta hoje"), Some(today()));
+        assert_eq!(due("Ligar até sexta"), Some(date(6, 14)));
+        assert_eq!(due("Reunião quarta-feira"), Some(date(6, 19)));
+        assert_eq!(due("Call mom next monday"), Some(date(6, 17)));
+        assert_eq!(due("Treino próxima terça"), Some(date(6, 18)));
+        assert_eq!(due("Relatório depois de amanhã"), Some(date(6, 14)));
+        assert_eq!(due("Ship it in 2 weeks"), Some(date(6, 26)));
+        assert_eq!(due("Entregar em 3 dias"), Some(date(6, 15)));
+        assert_eq!(due("Viagem 2024-07-01"), Some(date(7, 1)));
+        assert_eq!(due("Viagem 01/07/2024"), Some(date(7, 1)));
+        assert_eq!(due("Sem data"), None);
+    }
+
+    #[test]
+    fn test_huge_offsets_are_not_dates() {
+        for text in [
+            "Ler em 99999999999 dias",
+            "Ler em 9223372036854775807 semanas",
+            "Ler in 2000000000000 weeks",
+            "Ler em -99999999999 dias",
+        ] {
+            let quick = parse(text, today());
+            assert_eq!(quick.due_date, None, "{}", text);
+            assert_eq!(quick.title, text);
+        }
+    }
+
+    #[test]
+    fn test_connectors_and_title() {
+        let quick = parse("Enviar proposta até sexta às 9h30", today());
+
+        assert_eq!(quick.title, "Enviar proposta");
+        assert_eq!(quick.due_time, NaiveTime::from_hms_opt(9, 30, 0));
+
+        // Conectores sem data continuam no título
+        assert_eq!(
+            parse("Ler a documentação", today()).title,
+            "Ler a documentação"
+        );
+    }
+
+    #[test]
+    fn test_times() {
+        assert_eq!(parse_time("15:45"), NaiveTime::from_hms_opt(15, 45, 0));
+        assert_eq!(parse_time("3pm"), NaiveTime::from_hms_opt(15, 0, 0));
+        assert_eq!(parse_time("12am"), NaiveTime::from_hms_opt(0, 0, 0));
+        assert_eq!(parse_time("25h"), None);
+        assert_eq!(parse_time("hoje"), None);
+        assert_eq!(parse_time("h"), None);
+    }
+
+    #[test]
+    fn test_unknown_tags_stay_in_title() {
+        let quick = parse("Comprar pão #padaria !urgente", today());
+
+        assert_eq!(quick.title, "Comprar pão #padaria !urgente");
+        assert_eq!(quick.category, None);
+        assert_eq!(quick.priority, None);
+    }
+}
@@ -0,0 +1,160 @@
+use crate::i18n::{t, tf};
+use crate::models::{Priority, Task};
+use chrono::NaiveDate;
+
+/// Pontos pela prioridade
+const PRIORITY_POINTS: [(Priority, f64); 3] = [
+    (Priority::High, 30.0),
+    (Priority::Medium, 20.0),
+    (Priority::Low, 10.0),
+];
+/// Pontos para uma tarefa que vence hoje; caem até zero em `DUE_HORIZON_DAYS`
+const DUE_POINTS: f64 = 30.0;
+const DUE_HORIZON_DAYS: f64 = 14.0;
+/// Pontos por estar atrasada, mais um tanto por dia de atraso (com teto)
+const OVERDUE_POINTS: f64 = 40.0;
+const OVERDUE_POINTS_PER_DAY: f64 = 2.0;
+const OVERDUE_MAX_POINTS: f64 = 60.0;
+/// Pontos por dia desde a criação (com teto), para nada ficar esquecido
+const AGE_POINTS_PER_DAY: f64 = 0.5;
+const AGE_MAX_POINTS: f64 = 15.0;
+/// Penalidade de uma tarefa bloqueada por outra ainda não concluída
+const BLOCKED_PENALTY: f64 = -100.0;
+
+/// Um componente da pontuação, com o motivo legível
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScoreFactor {
+    pub reason: String,
+    pub points: f64,
+}
+
+/// Pontuação de uma tarefa e como ela foi composta
+#[derive(Debug, Clone, PartialEq)]
+pub struct Score {
+    pub total: f64,
+    pub factors: Vec<ScoreFactor>,
+}
+
+impl Score {
+    fn add(&mut self, reason: String, points: f64) {
+        if points != 0.0 {
+            self.total += points;
+            self.factors.push(ScoreFactor { reason, points });
+        }
+    }
+}
+
+/// Calcula a pontuação de uma tarefa
+///
+/// Combina prioridade, proximidade do vencimento, atraso, idade desde
+/// `created_at` e bloqueio. Quanto maior, mais urgente.
+pub fn score(task: &Task, blocked: bool, today: NaiveDate) -> Score {
+    let mut score = Score {
+        total: 0.0,
+        factors: Vec::new(),
+    };
+
+    let priority_points = PRIORITY_POINTS
+        .iter()
+        .find(|(p, _)| *p == task.priority)
+        .map(|(_, points)| *points)
+        .unwrap_or(0.0);
+    score.add(
+        tf("score.priority", &[&task.priority.as_str()]),
+        priority_points,
+    );
+
+    if let Some(due_date) = task.due_date {
+        let days = (due_date - today).num_days();
+        if days < 0 {
+            let late = -days;
+            let points =
+                (OVERDUE_POINTS + OVERDUE_POINTS_PER_DAY * late as f64).min(OVERDUE_MAX_POINTS);
+            score.add(tf("score.overdue", &[&late]), points);
+        } else {
+            let points = DUE_POINTS * (1.0 - days as f64 / DUE_HORIZON_DAYS).max(0.0);
+            let reason = if days == 0 {
+                t("score.due_today").to_string()
+            } else {
+                tf("score.due_in", &[&days])
+            };
+            score.add(reason, points);
+        }
+    }
+
+    let age = (today - task.created_at.date_naive()).num_days().max(0);
+    let age_points = (AGE_POINTS_PER_DAY * age as f64).min(AGE_MAX_POINTS);
+    score.add(tf("score.age", &[&age]), age_points);
+
+    if blocked {
+        score.add(t("score.blocked").to_string(), BLOCKED_PENALTY);
+    }
+
+    score
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Category;
+    use chrono::{Duration, Local, TimeZone};
+
+    fn today() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, 6, 12).unwrap()
+    }
+
+    fn task(priority: Priority, due_in: Option<i64>, age: i64) -> Task {
+        let mut task = Task::new(
+            1,
+            "Test".to_string(),
+            "Desc".to_string(),
+            Category::Work,
+            priority,
+            due_in.map(|d| today() + Duration::days(d)),
+        );
+        let created = today() - Duration::days(age);
+        task.created_at = Local
+            .from_local_datetime(&created.and_hms_opt(12, 0, 0).unwrap())
+            .unwrap();
+        task
+    }
+
+    #[test]
+    fn test_priority_and_age() {
+        let score = score(&task(Priority::High, None, 4), false, today());
+
+        assert_eq!(score.total, 32.0);
+        assert_eq!(score.factors.len(), 2);
+        assert_eq!(score.factors[0].reason, "prioridade Alta");
+    }
+
+    #[test]
+    fn test_due_date_proximity() {
+        let due_today = score(&task(Priority::Low, Some(0), 0), false, today());
+        let due_week = score(&task(Priority::Low, Some(7), 0), false, today());
+        let due_later = score(&task(Priority::Low, Some(30), 0), false, today());
+
+        assert_eq!(due_today.total, 40.0);
+        assert_eq!(due_week.total, 25.0);
+        assert_eq!(due_later.total, 10.0);
+    }
+
+    #[test]
+    fn test_overdue_is_capped() {
+        let late = score(&task(Priority::Low, Some(-3), 0), false, today());
+        let very_late = score(&task(Priority::Low, Some(-90), 0), false, today());
+
+        assert_eq!(late.total, 10.0 + 46.0);
+        assert_eq!(very_late.total, 10.0 + OVERDUE_MAX_POINTS);
+        assert_eq!(late.factors[1].reason, "atrasada há 3 dia(s)");
+    }
+
+    #[test]
+    fn test_blocked_penalty() {
+        let free = score(&task(Priority::High, Some(0), 0), false, today());
+        let blocked = score(&task(Priority::High, Some(0), 0), true, today());
+
+        assert_eq!(free.total - blocked.total, -BLOCKED_PENALTY);
+        assert!(blocked.total < 0.0);
+    }
+}
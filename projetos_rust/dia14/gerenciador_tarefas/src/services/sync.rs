@@ -0,0 +1,475 @@
+use crate::models::Task;
+use crate::services::task_service::sibling_path;
+use crate::storage::merge::{Conflict, latest_changes, to_map};
+use crate::storage::{Cipher, Storage, StorageFormat, TaskStorage};
+use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::io;
+use std::sync::Arc;
+
+/// Estado de sincronização de um armazenamento
+///
+/// Fica ao lado das tarefas (ex.: `tasks.sync.json`) e só existe depois da
+/// primeira sincronização; a partir daí as exclusões viram lápides.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SyncState {
+    /// Quando este armazenamento foi sincronizado pela última vez
+    #[serde(default)]
+    pub last_sync: Option<DateTime<Local>>,
+    /// Tarefas que saíram do conjunto (ID -> quando saíram)
+    #[serde(default)]
+    pub tombstones: BTreeMap<u32, DateTime<Local>>,
+}
+
+/// Resumo de uma sincronização
+#[derive(Debug, Default, PartialEq)]
+pub struct SyncReport {
+    /// Tarefas que vieram do outro armazenamento
+    pub received: Vec<u32>,
+    /// Tarefas que foram para o outro armazenamento
+    pub sent: Vec<u32>,
+    /// Tarefas presentes nos dois com algum campo atualizado
+    pub updated: Vec<u32>,
+    /// Tarefas removidas por uma exclusão do outro lado
+    pub deleted: Vec<u32>,
+    /// Tarefas diferentes com o mesmo ID: (ID, novo ID da remota)
+    pub renumbered: Vec<(u32, u32)>,
+    /// Edições simultâneas, resolvidas pela mais recente
+    pub conflicts: Vec<Conflict>,
+}
+
+/// Resultado de [`merge`]: o conjunto comum e o estado dos dois lados
+#[derive(Debug)]
+pub struct SyncOutcome {
+    pub tasks: Vec<Task>,
+    pub state: SyncState,
+    pub report: SyncReport,
+}
+
+/// Um conjunto de tarefas sincronizável, com o seu estado
+pub struct SyncStore {
+    pub tasks: Box<dyn TaskStorage>,
+    pub state: Storage,
+}
+
+impl SyncStore {
+    /// Abre as tarefas em `path` (arquivo JSON ou pasta Markdown)
+    pub fn open(path: &str, format: StorageFormat) -> Self {
+        SyncStore::with_cipher(path, format, None)
+    }
+
+    /// Abre as tarefas criptografando os arquivos JSON com a cifra
+    pub fn with_cipher(path: &str, format: StorageFormat, cipher: Option<Arc<Cipher>>) -> Self {
+        let json = |path: String| match &cipher {
+            Some(cipher) => Storage::encrypted(path, Arc::clone(cipher)),
+            None => Storage::new(path),
+        };
+        let tasks: Box<dyn TaskStorage> = match format {
+            StorageFormat::Json => Box::new(json(path.to_string())),
+            StorageFormat::Markdown => format.open(path.to_string()),
+        };
+        SyncStore {
+            tasks,
+            state: json(state_path(path)),
+        }
+    }
+
+    /// Tarefas salvas (nenhuma, se o armazenamento ainda não existe)
+    ///
+    /// Qualquer outro erro (ex.: senha errada) interrompe a sincronização,
+    /// para não sobrescrever o outro lado com um conjunto vazio.
+    pub fn load_tasks(&self) -> io::Result<Vec<Task>> {
+        or_empty(self.tasks.load_tasks())
+    }
+
+    /// Estado salvo (vazio antes da primeira sincronização)
+    pub fn load_state(&self) -> io::Result<SyncState> {
+        or_empty(self.state.load())
+    }
+
+    /// Salva o resultado de uma sincronização
+    pub fn save(&self, outcome: &SyncOutcome) -> io::Result<()> {
+        self.tasks.save_tasks(&outcome.tasks)?;
+        self.state.save(&outcome.state)
+    }
+}
+
+/// Arquivo de estado de um armazenamento (ex.: "data/tasks.sync.json")
+pub(crate) fn state_path(path: &str) -> String {
+    sibling_path(path, "sync")
+}
+
+/// Sincroniza dois armazenamentos; os dois terminam com as mesmas tarefas
+pub fn sync(local: &SyncStore, remote: &SyncStore, now: DateTime<Local>) -> io::Result<SyncReport> {
+    let outcome = merge(
+        &local.load_tasks()?,
+        &local.load_state()?,
+        &remote.load_tasks()?,
+        &remote.load_state()?,
+        1,
+        now,
+    )?;
+    remote.save(&outcome)?;
+    local.save(&outcome)?;
+    Ok(outcome.report)
+}
+
+/// Junta dois conjuntos de tarefas pela identidade (ID e UUID)
+///
+/// Presente dos dois lados, cada campo fica com a versão mudada por último;
+/// se os dois mudaram o campo desde a última sincronização, vale o mesmo
+/// critério, mas a mudança é relatada como conflito. Presente de um lado
+/// só, a tarefa é copiada para o outro, a não ser que haja uma lápide mais
+/// recente que a última mudança dela.
+///
+/// Uma tarefa renumerada ganha um ID a partir de `first_free_id` (ex.: o
+/// próximo ID do serviço, que já pula lixeira, arquivo e IDs apagados) ou
+/// depois de qualquer ID dos dois lados e das lápides, o que for maior.
+pub fn merge(
+    local: &[Task],
+    local_state: &SyncState,
+    remote: &[Task],
+    remote_state: &SyncState,
+    first_free_id: u32,
+    now: DateTime<Local>,
+) -> io::Result<SyncOutcome> {
+    // Mudanças depois disso podem não ter sido vistas pelo outro lado
+    let since = local_state.last_sync.min(remote_state.last_sync);
+
+    let mut tombstones = local_state.tombstones.clone();
+    for (id, at) in &remote_state.tombstones {
+        let entry = tombstones.entry(*id).or_insert(*at);
+        *entry = (*entry).max(*at);
+    }
+
+    let local_by_id: HashMap<u32, &Task> = local.iter().map(|t| (t.id, t)).collect();
+    let remote_by_id: HashMap<u32, &Task> = remote.iter().map(|t| (t.id, t)).collect();
+    let ids: BTreeSet<u32> = local_by_id
+        .keys()
+        .chain(remote_by_id.keys())
+        .copied()
+        .collect();
+    let mut next_id = ids
+        .iter()
+        .chain(tombstones.keys())
+        .max()
+        .map_or(1, |max| max + 1)
+        .max(first_free_id);
+
+    let mut tasks = Vec::new();
+    let mut report = SyncReport::default();
+
+    for id in ids {
+        match (local_by_id.get(&id), remote_by_id.get(&id)) {
+            (Some(l), Some(r)) if l.uuid == r.uuid => {
+                let (task, conflicts) = merge_fields(l, r, since)?;
+                if !same(&task, l)? || !same(&task, r)? {
+                    report.updated.push(id);
+                }
+                report.conflicts.extend(conflicts);
+                tasks.push(task);
+            }
+            // Mesmo ID, tarefas diferentes: a remota ganha um ID novo
+            (Some(l), Some(r)) => {
+                let mut task = (*r).clone();
+                task.id = next_id;
+                next_id += 1;
+                report.renumbered.push((id, task.id));
+                report.received.push(task.id);
+                report.sent.push(id);
+                tasks.push((*l).clone());
+                tasks.push(task);
+            }
+            (Some(task), None) | (None, Some(task)) => {
+                let is_local = local_by_id.contains_key(&id);
+                match tombstones.get(&id) {
+                    Some(deleted_at) if *deleted_at >= task.last_changed_at() => {
+                        if is_local {
+                            report.deleted.push(id);
+                        }
+                        continue;
+                    }
+                    // Editada depois de excluída do outro lado: fica
+                    Some(deleted_at) if since.is_none_or(|s| *deleted_at > s) => {
+                        report.conflicts.push(Conflict::DeletedAndModified { id });
+                    }
+                    _ => {}
+                }
+                if is_local {
+                    report.sent.push(id);
+                } else {
+                    report.received.push(id);
+                }
+                tasks.push((*task).clone());
+            }
+            (None, None) => {}
+        }
+    }
+
+    tasks.sort_by_key(|t| t.id);
+    for task in &tasks {
+        tombstones.remove(&task.id);
+    }
+
+    Ok(SyncOutcome {
+        tasks,
+        state: SyncState {
+            last_sync: Some(now),
+            tombstones,
+        },
+        report,
+    })
+}
+
+/// Última escrita vence, campo a campo
+fn merge_fields(
+    local: &Task,
+    remote: &Task,
+    since: Option<DateTime<Local>>,
+) -> io::Result<(Task, Vec<Conflict>)> {
+    let (l, r) = (to_map(local)?, to_map(remote)?);
+    let keys: BTreeSet<&String> = l
+        .keys()
+        .chain(r.keys())
+        .filter(|key| *key != "changed_at")
+        .collect();
+
+    let mut merged = Map::new();
+    let mut conflicts = Vec::new();
+    for key in keys {
+        let (lv, rv) = (l.get(key), r.get(key));
+        let (l_at, r_at) = (local.field_changed_at(key), remote.field_changed_at(key));
+        let value = if lv == rv || l_at >= r_at { lv } else { rv };
+
+        if lv != rv && since.is_none_or(|s| l_at > s && r_at > s) {
+            conflicts.push(Conflict::Field {
+                id: local.id,
+                field: key.clone(),
+            });
+        }
+        if let Some(value) = value {
+            merged.insert(key.clone(), value.clone());
+        }
+    }
+
+    let mut task: Task = serde_json::from_value(Value::Object(merged))
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    task.changed_at = latest_changes(local, remote);
+    Ok((task, conflicts))
+}
+
+/// Registra em `changed_at` os campos que mudaram desde o último salvamento
+///
+/// Uma tarefa que entrou no conjunto (nova, restaurada ou desarquivada)
+/// marca o campo `id`, para não ser vencida por uma lápide antiga.
+pub(crate) fn stamp_changes(before: &[Task], after: &mut [Task], now: DateTime<Local>) {
+    let before: HashMap<u32, &Task> = before.iter().map(|t| (t.id, t)).collect();
+
+    for task in after.iter_mut() {
+        let Some(old) = before.get(&task.id) else {
+            task.changed_at.insert("id".to_string(), now);
+            continue;
+        };
+        let (Ok(old), Ok(new)) = (to_map(old), to_map(task)) else {
+            continue;
+        };
+        let changed: Vec<String> = old
+            .keys()
+            .chain(new.keys())
+            .filter(|key| *key != "changed_at" && old.get(*key) != new.get(*key))
+            .cloned()
+            .collect();
+        for field in changed {
+            task.changed_at.insert(field, now);
+        }
+    }
+}
+
+/// Registra lápides das tarefas que saíram do conjunto, se o armazenamento
+/// já foi sincronizado alguma vez
+pub(crate) fn record_tombstones(
+    state: &Storage,
+    before: &[Task],
+    after: &[Task],
+    now: DateTime<Local>,
+) -> io::Result<()> {
+    if !state.exists() {
+        return Ok(());
+    }
+
+    let mut sync_state: SyncState = state.load()?;
+    let present: BTreeSet<u32> = after.iter().map(|t| t.id).collect();
+    let mut changed = false;
+    for task in before.iter().filter(|t| !present.contains(&t.id)) {
+        sync_state.tombstones.insert(task.id, now);
+        changed = true;
+    }
+    for id in &present {
+        changed |= sync_state.tombstones.remove(id).is_some();
+    }
+
+    if changed {
+        state.save(&sync_state)?;
+    }
+    Ok(())
+}
+
+/// Arquivo ausente conta como vazio
+fn or_empty<T: Default>(result: io::Result<T>) -> io::Result<T> {
+    match result {
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(T::default()),
+        result => result,
+    }
+}
+
+fn same(a: &Task, b: &Task) -> io::Result<bool> {
+    Ok(to_map(a)? == to_map(b)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Category, Priority, Status};
+    use chrono::{TimeZone};
+    use uuid::Uuid;
+
+    fn at(hour: u32) -> DateTime<Local> {
+        Local.with_ymd_and_hms(2024, 6, 1, hour, 0, 0).unwrap()
+    }
+
+    fn task(id: u32, title: &str) -> Task {
+        let mut task = Task::new(
+            id,
+            title.to_string(),
+            String::new(),
+            Category::Work,
+            Priority::Medium,
+            None,
+        );
+        task.created_at = at(8);
+        // As cópias da mesma tarefa precisam do mesmo UUID
+        task.uuid = Uuid::nil();
+        task.fill_uuid();
+        task
+    }
+
+    fn synced(hour: u32) -> SyncState {
+        SyncState {
+            last_sync: Some(at(hour)),
+            tombstones: BTreeMap::new(),
+        }
+    }
+
+    #[test]
+    fn test_stamp_changes() {
+        let before = vec![task(1, "A")];
+        let mut after = vec![task(1, "A"), task(2, "B")];
+        after[0].status = Status::InProgress;
+
+        stamp_changes(&before, &mut after, at(10));
+
+        assert_eq!(
+            after[0].changed_at.keys().collect::<Vec<_>>(),
+            vec!["status"]
+        );
+        assert_eq!(after[0].field_changed_at("status"), at(10));
+        assert_eq!(after[0].field_changed_at("title"), at(8));
+        assert_eq!(after[1].last_changed_at(), at(10));
+    }
+
+    #[test]
+    fn test_last_writer_wins_per_field() {
+        let mut local = task(1, "Local");
+        local.changed_at.insert("title".to_string(), at(10));
+        let mut remote = task(1, "Remota");
+        remote.changed_at.insert("title".to_string(), at(11));
+        remote.priority = Priority::High;
+        remote.changed_at.insert("priority".to_string(), at(9));
+
+        let outcome = merge(&[local], &synced(9), &[remote], &synced(9), 1, at(12)).unwrap();
+        let merged = &outcome.tasks[0];
+
+        assert_eq!(merged.title, "Remota");
+        assert_eq!(merged.priority, Priority::High);
+        assert_eq!(merged.field_changed_at("title"), at(11));
+        assert_eq!(outcome.report.updated, vec![1]);
+        // Título mudou dos dois lados depois da sincronização das 9h
+        assert_eq!(
+            outcome.report.conflicts,
+            vec![Conflict::Field {
+                id: 1,
+                field: "title".to_string()
+            }]
+        );
+        assert_eq!(outcome.state.last_sync, Some(at(12)));
+    }
+
+    #[test]
+    fn test_tombstones() {
+        let mut edited = task(2, "Editada depois");
+        edited.changed_at.insert("title".to_string(), at(11));
+
+        let mut remote_state = synced(9);
+        remote_state.tombstones.insert(1, at(10));
+        remote_state.tombstones.insert(2, at(10));
+
+        let local = vec![task(1, "Excluída lá"), edited];
+        let remote = vec![task(3, "Nova lá")];
+        let outcome = merge(&local, &synced(9), &remote, &remote_state, 1, at(12)).unwrap();
+
+        let ids: Vec<u32> = outcome.tasks.iter().map(|t| t.id).collect();
+        assert_eq!(ids, vec![2, 3]);
+        assert_eq!(outcome.report.deleted, vec![1]);
+        assert_eq!(outcome.report.sent, vec![2]);
+        assert_eq!(outcome.report.received, vec![3]);
+        assert_eq!(
+            outcome.report.conflicts,
+            vec![Conflict::DeletedAndModified { id: 2 }]
+        );
+        assert_eq!(
+            outcome.state.tombstones.keys().collect::<Vec<_>>(),
+            vec![&1]
+        );
+    }
+
+    #[test]
+    fn test_same_id_different_tasks_are_renumbered() {
+        let local = task(1, "Daqui");
+        let mut remote = task(1, "De lá");
+        remote.uuid = Uuid::new_v4();
+
+        let outcome = merge(
+            &[local],
+            &SyncState::default(),
+            &[remote],
+            &SyncState::default(),
+            1,
+            at(9),
+        )
+        .unwrap();
+
+        let titles: Vec<(u32, &str)> = outcome
+            .tasks
+            .iter()
+            .map(|t| (t.id, t.title.as_str()))
+            .collect();
+        assert_eq!(titles, vec![(1, "Daqui"), (2, "De lá")]);
+        assert_eq!(outcome.report.renumbered, vec![(1, 2)]);
+
+        // IDs reservados do lado local (lixeira, arquivo) não são usados
+        let mut remote = task(1, "De lá");
+        remote.uuid = Uuid::new_v4();
+        let outcome = merge(
+            &[task(1, "Daqui")],
+            &SyncState::default(),
+            &[remote],
+            &SyncState::default(),
+            7,
+            at(9),
+        )
+        .unwrap();
+        assert_eq!(outcome.report.renumbered, vec![(1, 7)]);
+    }
+}
@@ -1,41 +1,603 @@
-use crate::models::{Category, Priority, Status, Task};
-use crate::storage::Storage;
+use crate::config::Config;
+use crate::i18n::{t, tf};
+use crate::models::template;
+use crate::models::{
+    Attachment, Category, Note, Pomodoro, Priority, Project, ProjectColor, Status, Task, Template,
+    TemplateTask,
+};
+use crate::services::analytics::Analytics;
+use crate::services::bulk::{BulkAction, BulkReport};
+use crate::services::events::{TaskEvent, TaskSubscriber};
+use crate::services::quick_add;
+use crate::services::scoring::{self, Score};
+use crate::services::sync::{self, SyncReport, SyncState, SyncStore};
+use crate::storage::attachment_store;
+use crate::storage::{AttachmentStore, Cipher, Storage, StorageFormat, TaskStorage};
+use crate::utils::{Clock, SystemClock};
+use chrono::{DateTime, Duration, Local, NaiveDate};
+use serde::{Deserialize, Serialize, Serializer};
+use std::collections::HashMap;
+use std::fs;
 use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use uuid::Uuid;
 
 /// Serviço para gerenciar tarefas
 pub struct TaskService {
     tasks: Vec<Task>,
     next_id: u32,
-    pub storage: Storage,
+    /// Onde `next_id` é gravado, para nenhum ID ser reaproveitado
+    id_counter: Storage,
+    pub storage: Box<dyn TaskStorage>,
+    storage_path: String,
+    format: StorageFormat,
+    /// Cifra dos arquivos, quando as tarefas são criptografadas
+    cipher: Option<Arc<Cipher>>,
+    /// Tarefas como estão no disco, para registrar o que mudou ao salvar
+    saved: Vec<Task>,
+    /// Última sincronização e lápides das tarefas que saíram do conjunto
+    sync_state: Storage,
+    /// Tarefas excluídas, que ainda podem ser restauradas
+    trash: Vec<Task>,
+    trash_storage: Box<dyn TaskStorage>,
+    /// Tarefas concluídas guardadas fora do conjunto ativo
+    archive: Vec<Task>,
+    archive_storage: Box<dyn TaskStorage>,
+    /// Dias que uma tarefa fica na lixeira antes de ser apagada de vez
+    trash_retention_days: u32,
+    subscribers: Vec<Box<dyn TaskSubscriber>>,
+    default_category: Category,
+    default_priority: Priority,
+    wip_limits: WipLimits,
+    /// Usuário que cria as tarefas e responde por "minhas tarefas"
+    current_user: Option<String>,
+    /// Projetos (listas) que agrupam as tarefas
+    projects: Vec<Project>,
+    projects_storage: Storage,
+    /// Modelos de tarefas repetidas
+    templates: Vec<Template>,
+    templates_storage: Storage,
+    /// Projeto ativo: recebe as tarefas novas e limita as listagens
+    active_project: Option<String>,
+    /// Conteúdo dos anexos, endereçado pelo hash
+    attachment_store: AttachmentStore,
+    /// Anexos ausentes ou corrompidos encontrados ao carregar (ID, nome)
+    broken_attachments: Vec<(u32, String)>,
+    /// Arquivos de tarefa ilegíveis pulados ao carregar (só no Markdown)
+    skipped_files: Vec<PathBuf>,
+    /// Fonte da data e hora de criação, conclusão, atraso etc.
+    clock: Arc<dyn Clock>,
+}
+
+/// Limites de WIP (trabalho em andamento) por coluna do quadro Kanban
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct WipLimits {
+    pub pending: Option<usize>,
+    pub in_progress: Option<usize>,
+}
+
+impl WipLimits {
+    /// Limite da coluna de um status (concluídas não têm limite)
+    pub fn limit(&self, status: Status) -> Option<usize> {
+        match status {
+            Status::Pending => self.pending,
+            Status::InProgress => self.in_progress,
+            Status::Completed => None,
+        }
+    }
 }
 
 impl TaskService {
     /// Cria um novo TaskService
+    ///
+    /// A lixeira e o arquivo ficam em arquivos ao lado do principal
+    /// (ex.: `tasks.trash.json` e `tasks.archive.json`), e os anexos na
+    /// pasta `tasks.attachments/`, verificados ao carregar.
     pub fn new(storage_path: String) -> Self {
-        let storage = Storage::new(storage_path);
-        let (tasks, next_id) = Self::load_from_storage(&storage);
+        TaskService::with_format(storage_path, StorageFormat::Json)
+    }
+
+    /// Cria um TaskService com as tarefas no formato indicado
+    ///
+    /// Em Markdown, `storage_path` é uma pasta com um arquivo por tarefa,
+    /// e a lixeira e o arquivo são pastas irmãs (ex.: `tasks.trash/`).
+    pub fn with_format(storage_path: String, format: StorageFormat) -> Self {
+        let mut load_error = None;
+        TaskService::build(storage_path, format, None, &mut load_error)
+    }
+
+    /// Abre as tarefas, criptografadas com a senha, se houver
+    ///
+    /// Ao contrário de [`TaskService::with_format`], um arquivo
+    /// criptografado que não abre (senha errada ou ausente) é erro, e não
+    /// uma lista vazia. Com senha, arquivos ainda em texto puro são
+    /// criptografados na hora.
+    pub fn open(
+        storage_path: String,
+        format: StorageFormat,
+        passphrase: Option<&str>,
+    ) -> io::Result<Self> {
+        let cipher = match passphrase {
+            Some(_) if format != StorageFormat::Json => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    t("error.encryption_needs_json"),
+                ));
+            }
+            Some(passphrase) => Some(Arc::new(Cipher::new(passphrase))),
+            None => None,
+        };
+
+        let mut load_error = None;
+        let service = TaskService::build(storage_path, format, cipher, &mut load_error);
+        if let Some(e) = load_error {
+            return Err(e);
+        }
+        if service.cipher.is_some() && service.has_plaintext_files() {
+            service.rewrite_all(service.load_sync_state()?)?;
+            service.attachment_store.rewrite_from(&service.attachment_store)?;
+        }
+        Ok(service)
+    }
 
-        TaskService {
+    /// Monta o serviço; erros de senha ao carregar vão para `load_error`
+    fn build(
+        storage_path: String,
+        format: StorageFormat,
+        cipher: Option<Arc<Cipher>>,
+        load_error: &mut Option<io::Error>,
+    ) -> Self {
+        let attachments = sibling_dir(&storage_path, "attachments");
+        let attachment_store = match &cipher {
+            Some(cipher) => AttachmentStore::encrypted(attachments, Arc::clone(cipher)),
+            None => AttachmentStore::new(attachments),
+        };
+        let storages = Storages::open(&storage_path, format, cipher.as_ref());
+
+        let mut tasks = loaded(storages.tasks.load_tasks(), load_error);
+        let mut trash = loaded(storages.trash.load_tasks(), load_error);
+        let mut archive = loaded(storages.archive.load_tasks(), load_error);
+        let rejected = reject_invalid_hashes([&mut tasks, &mut trash, &mut archive]);
+        let skipped_files = [&storages.tasks, &storages.trash, &storages.archive]
+            .iter()
+            .flat_map(|storage| storage.skipped_files())
+            .collect();
+        let projects: Vec<Project> = loaded(storages.projects.load(), load_error);
+        let templates: Vec<Template> = loaded(storages.templates.load(), load_error);
+        let counter: IdCounter = loaded(storages.ids.load(), load_error);
+
+        // IDs nunca são reaproveitados: nem os da lixeira e do arquivo, nem
+        // os de tarefas já apagadas de vez (guardados no contador)
+        let next_id = tasks
+            .iter()
+            .chain(&trash)
+            .chain(&archive)
+            .map(|t| t.id + 1)
+            .max()
+            .unwrap_or(1)
+            .max(counter.next_id);
+
+        let mut service = TaskService {
+            saved: tasks.clone(),
             tasks,
             next_id,
-            storage,
+            id_counter: storages.ids,
+            storage: storages.tasks,
+            storage_path,
+            format,
+            cipher,
+            sync_state: storages.sync_state,
+            trash,
+            trash_storage: storages.trash,
+            archive,
+            archive_storage: storages.archive,
+            trash_retention_days: 30,
+            subscribers: Vec::new(),
+            default_category: Category::Other,
+            default_priority: Priority::Medium,
+            wip_limits: WipLimits::default(),
+            current_user: None,
+            projects,
+            projects_storage: storages.projects,
+            templates,
+            templates_storage: storages.templates,
+            active_project: None,
+            attachment_store,
+            broken_attachments: Vec::new(),
+            skipped_files,
+            clock: Arc::new(SystemClock),
+        };
+        service.broken_attachments = rejected;
+        service.broken_attachments.extend(service.check_attachments());
+        service
+    }
+
+    /// Cria um TaskService a partir da configuração
+    pub fn from_config(config: &Config, passphrase: Option<&str>) -> io::Result<Self> {
+        let mut service =
+            TaskService::open(config.storage_path.clone(), config.storage_format, passphrase)?;
+        service.default_category = config.default_category;
+        service.default_priority = config.default_priority;
+        service.trash_retention_days = config.trash_retention_days;
+        service.current_user = config.user();
+        service.wip_limits = WipLimits {
+            pending: Some(config.wip_limit_pending).filter(|n| *n > 0),
+            in_progress: Some(config.wip_limit_in_progress).filter(|n| *n > 0),
+        };
+        Ok(service)
+    }
+
+    /// Categoria usada quando nenhuma é escolhida
+    pub fn default_category(&self) -> Category {
+        self.default_category
+    }
+
+    /// Prioridade usada quando nenhuma é escolhida
+    pub fn default_priority(&self) -> Priority {
+        self.default_priority
+    }
+
+    /// Limites de WIP do quadro Kanban
+    pub fn wip_limits(&self) -> WipLimits {
+        self.wip_limits
+    }
+
+    /// Altera os limites de WIP
+    pub fn set_wip_limits(&mut self, limits: WipLimits) {
+        self.wip_limits = limits;
+    }
+
+    /// Usuário atual, se houver
+    pub fn current_user(&self) -> Option<&str> {
+        self.current_user.as_deref()
+    }
+
+    /// Altera o usuário atual
+    pub fn set_current_user(&mut self, user: Option<String>) {
+        self.current_user = user.filter(|u| !u.trim().is_empty());
+    }
+
+    /// Relógio usado pelo serviço
+    pub fn clock(&self) -> &Arc<dyn Clock> {
+        &self.clock
+    }
+
+    /// Troca o relógio (ex.: um [`ManualClock`](crate::utils::ManualClock) nos testes)
+    pub fn set_clock(&mut self, clock: Arc<dyn Clock>) {
+        self.clock = clock;
+    }
+
+    /// Verifica se mais `incoming` tarefas cabem na coluna `status`
+    ///
+    /// Conta as tarefas do quadro, ou seja, as do projeto ativo (todas, se
+    /// nenhum estiver ativo).
+    fn check_wip_limit(&self, status: Status, incoming: usize) -> io::Result<()> {
+        let Some(limit) = self.wip_limits.limit(status) else {
+            return Ok(());
+        };
+        let count = self
+            .active_tasks()
+            .iter()
+            .filter(|t| t.status == status)
+            .count();
+
+        if incoming > 0 && count + incoming > limit {
+            return Err(io::Error::new(
+                io::ErrorKind::QuotaExceeded,
+                tf("error.wip_limit", &[&status.as_str(), &limit]),
+            ));
         }
+        Ok(())
+    }
+
+    /// Verifica se a tarefa pode entrar em andamento sem estourar o limite
+    fn check_can_start(&self, id: u32) -> io::Result<()> {
+        let starting = self
+            .get_by_id(id)
+            .is_some_and(|task| task.status == Status::Pending && self.in_active_project(task));
+        self.check_wip_limit(Status::InProgress, starting as usize)
+    }
+
+    /// Verifica se uma tarefa que volta ao conjunto ativo cabe no quadro
+    fn check_can_return(&self, task: &Task) -> io::Result<()> {
+        self.check_wip_limit(task.status, self.in_active_project(task) as usize)
+    }
+
+    /// Registra um subscriber para receber os eventos de tarefas
+    ///
+    /// O subscriber recebe antes a cifra atual; se não conseguir usá-la
+    /// (ex.: um log que não abre com esta senha), não é registrado.
+    pub fn subscribe<S>(&mut self, mut subscriber: S) -> io::Result<()>
+    where
+        S: TaskSubscriber + 'static,
+    {
+        subscriber.on_cipher_changed(self.cipher.as_ref())?;
+        self.subscribers.push(Box::new(subscriber));
+        Ok(())
+    }
+
+    /// Notifica todos os subscribers
+    fn emit(&mut self, event: TaskEvent) {
+        let now = self.clock.now();
+        for subscriber in &mut self.subscribers {
+            // A alteração já foi salva; falhas de efeitos colaterais são ignoradas
+            let _ = subscriber.on_event(&event, now);
+        }
+    }
+
+    /// Salva tarefas no storage, registrando quando cada campo mudou
+    fn save(&mut self) -> io::Result<()> {
+        let now = self.clock.now();
+        sync::stamp_changes(&self.saved, &mut self.tasks, now);
+        self.storage.save_tasks(&self.tasks)?;
+        sync::record_tombstones(&self.sync_state, &self.saved, &self.tasks, now)?;
+        self.saved = self.tasks.clone();
+        Ok(())
+    }
+
+    /// Grava o próximo ID antes de ele ser usado
+    fn save_next_id(&self) -> io::Result<()> {
+        self.id_counter.save(&IdCounter {
+            next_id: self.next_id,
+        })
     }
 
-    /// Carrega tarefas do storage
-    fn load_from_storage(storage: &Storage) -> (Vec<Task>, u32) {
-        match storage.load::<Vec<Task>>() {
-            Ok(tasks) => {
-                let max_id = tasks.iter().map(|t| t.id).max().unwrap_or(0);
-                (tasks, max_id + 1)
+    /// Salva a lixeira e o arquivo (conjuntos vazios não deixam arquivo)
+    fn save_removed(&self) -> io::Result<()> {
+        for (storage, tasks) in [
+            (&self.trash_storage, &self.trash),
+            (&self.archive_storage, &self.archive),
+        ] {
+            if !tasks.is_empty() {
+                storage.save_tasks(tasks)?;
+            } else if storage.exists() {
+                storage.delete()?;
             }
-            Err(_) => (Vec::new(), 1),
         }
+        Ok(())
     }
 
-    /// Salva tarefas no storage
-    fn save(&self) -> io::Result<()> {
-        self.storage.save(&self.tasks)
+    /// Salva uma tarefa que mudou de conjunto (ativas, lixeira ou arquivo)
+    ///
+    /// Se o salvamento falhar, volta os três conjuntos ao estado anterior e
+    /// tenta deixar os arquivos como estavam.
+    fn save_moved(&mut self, before: [Vec<Task>; 3]) -> io::Result<()> {
+        let saved = self.save_removed().and_then(|_| self.save());
+        if let Err(e) = saved {
+            let [tasks, trash, archive] = before;
+            self.tasks = tasks;
+            self.trash = trash;
+            self.archive = archive;
+            let _ = self.save_removed().and_then(|_| self.save());
+            return Err(e);
+        }
+        Ok(())
+    }
+
+    /// Conjuntos ativos, lixeira e arquivo, para desfazer uma mudança
+    fn snapshot(&self) -> [Vec<Task>; 3] {
+        [self.tasks.clone(), self.trash.clone(), self.archive.clone()]
+    }
+
+    /// Apaga os arquivos de tarefas, lixeira, arquivo, projetos, modelos e anexos
+    pub fn delete_storage(&self) -> io::Result<()> {
+        for storage in [
+            self.storage.as_ref(),
+            self.trash_storage.as_ref(),
+            self.archive_storage.as_ref(),
+            &self.projects_storage as &dyn TaskStorage,
+            &self.templates_storage,
+            &self.sync_state,
+            &self.id_counter,
+        ] {
+            if storage.exists() {
+                storage.delete()?;
+            }
+        }
+        self.attachment_store.delete_all()
+    }
+
+    /// Formato em que as tarefas estão salvas
+    pub fn storage_format(&self) -> StorageFormat {
+        self.format
+    }
+
+    /// Verifica se as tarefas são gravadas criptografadas
+    pub fn is_encrypted(&self) -> bool {
+        self.cipher.is_some()
+    }
+
+    /// Cifra dos arquivos, quando as tarefas são criptografadas
+    pub fn cipher(&self) -> Option<&Arc<Cipher>> {
+        self.cipher.as_ref()
+    }
+
+    /// Define uma senha nova e criptografa de novo todos os arquivos
+    ///
+    /// Se as tarefas já são criptografadas, `current` precisa ser a senha
+    /// atual; se não, passam a ser. Os anexos e o log de eventos (pelos
+    /// subscribers) também são criptografados de novo. Se algo falhar no
+    /// meio, tudo volta para a cifra anterior.
+    pub fn change_passphrase(&mut self, current: &str, new: &str) -> io::Result<()> {
+        if new.is_empty() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                t("error.empty_passphrase"),
+            ));
+        }
+        if self.format != StorageFormat::Json {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                t("error.encryption_needs_json"),
+            ));
+        }
+        if let Some(cipher) = &self.cipher
+            && !cipher.matches(current)
+        {
+            return Err(io::Error::new(
+                io::ErrorKind::PermissionDenied,
+                tf("error.wrong_passphrase", &[&self.storage_path]),
+            ));
+        }
+
+        // O estado de sincronização é lido ainda com a chave antiga
+        let sync_state = self.load_sync_state()?;
+        let cipher = Arc::new(Cipher::new(new));
+
+        // Os anexos vão para uma pasta ao lado, que só substitui a atual
+        // depois que todo o resto foi gravado
+        let attachments = sibling_dir(&self.storage_path, "attachments");
+        let staged_dir = format!("{}.new", attachments);
+        let staged = AttachmentStore::encrypted(&staged_dir, Arc::clone(&cipher));
+        staged.delete_all()?;
+        if let Err(e) = staged.rewrite_from(&self.attachment_store) {
+            let _ = staged.delete_all();
+            return Err(e);
+        }
+
+        let previous = self.cipher.clone();
+        self.use_cipher(Some(Arc::clone(&cipher)));
+        let mut notified = 0;
+        let result = self.rewrite_all(sync_state.clone()).and_then(|()| {
+            for subscriber in &mut self.subscribers {
+                subscriber.on_cipher_changed(Some(&cipher))?;
+                notified += 1;
+            }
+            replace_dir(&staged_dir, &attachments)
+        });
+        if let Err(e) = result {
+            self.use_cipher(previous.clone());
+            let _ = self.rewrite_all(sync_state);
+            for subscriber in &mut self.subscribers[..notified] {
+                let _ = subscriber.on_cipher_changed(previous.as_ref());
+            }
+            let _ = staged.delete_all();
+            return Err(e);
+        }
+        Ok(())
+    }
+
+    /// Passa a gravar todos os arquivos com outra cifra, sem regravá-los
+    fn use_cipher(&mut self, cipher: Option<Arc<Cipher>>) {
+        let attachments = sibling_dir(&self.storage_path, "attachments");
+        self.attachment_store = match &cipher {
+            Some(cipher) => AttachmentStore::encrypted(attachments, Arc::clone(cipher)),
+            None => AttachmentStore::new(attachments),
+        };
+        let storages = Storages::open(&self.storage_path, self.format, cipher.as_ref());
+        self.storage = storages.tasks;
+        self.trash_storage = storages.trash;
+        self.archive_storage = storages.archive;
+        self.projects_storage = storages.projects;
+        self.templates_storage = storages.templates;
+        self.sync_state = storages.sync_state;
+        self.id_counter = storages.ids;
+        self.cipher = cipher;
+    }
+
+    /// Grava uma cópia de todos os dados em JSON puro, sem criptografia
+    ///
+    /// A cópia tem a mesma forma dos arquivos de origem: as tarefas ativas
+    /// em `path` e a lixeira, o arquivo, os projetos e os modelos em
+    /// arquivos irmãos (ex.: `copia.trash.json`), e abre como um conjunto
+    /// de tarefas comum. Retorna quantas tarefas foram exportadas, contando
+    /// as da lixeira e do arquivo.
+    pub fn export_decrypted(&self, path: &Path) -> io::Result<usize> {
+        let path = path.to_string_lossy();
+        Storage::new(path.to_string()).save(&self.tasks)?;
+        export_set(sibling_path(&path, "trash"), &self.trash)?;
+        export_set(sibling_path(&path, "archive"), &self.archive)?;
+        export_set(sibling_path(&path, "projects"), &self.projects)?;
+        export_set(sibling_path(&path, "templates"), &self.templates)?;
+        Ok(self.tasks.len() + self.trash.len() + self.archive.len())
+    }
+
+    /// Algum arquivo existente ainda está em texto puro
+    fn has_plaintext_files(&self) -> bool {
+        [
+            self.storage.as_ref(),
+            self.trash_storage.as_ref(),
+            self.archive_storage.as_ref(),
+            &self.projects_storage,
+            &self.templates_storage,
+            &self.sync_state,
+            &self.id_counter,
+        ]
+        .into_iter()
+        .any(|storage| storage.exists() && !storage.is_encrypted())
+            || self.attachment_store.has_plaintext()
+    }
+
+    /// Grava de novo todos os arquivos (ex.: depois de trocar a cifra)
+    fn rewrite_all(&self, sync_state: Option<SyncState>) -> io::Result<()> {
+        self.storage.save_tasks(&self.tasks)?;
+        self.save_removed()?;
+        self.save_projects()?;
+        self.save_templates()?;
+        self.save_next_id()?;
+        if let Some(state) = sync_state {
+            self.sync_state.save(&state)?;
+        }
+        Ok(())
+    }
+
+    /// Estado de sincronização salvo (`None` antes da primeira)
+    ///
+    /// Como em [`SyncStore::load_state`], qualquer outro erro (ex.: arquivo
+    /// corrompido ou senha errada) interrompe em vez de virar estado vazio.
+    fn load_sync_state(&self) -> io::Result<Option<SyncState>> {
+        match self.sync_state.load() {
+            Ok(state) => Ok(Some(state)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Sincroniza as tarefas ativas com outro armazenamento
+    ///
+    /// Os dois ficam com o mesmo conjunto; a lixeira e o arquivo
+    /// continuam locais, mas os IDs deles não são dados a tarefas
+    /// renumeradas. Tarefas recebidas, atualizadas ou excluídas do outro
+    /// lado viram eventos para os subscribers.
+    pub fn sync_with(&mut self, remote: &SyncStore) -> io::Result<SyncReport> {
+        let first_free_id = self.next_id.max(self.highest_id() + 1);
+        let outcome = sync::merge(
+            &self.tasks,
+            &self.load_sync_state()?.unwrap_or_default(),
+            &remote.load_tasks()?,
+            &remote.load_state()?,
+            first_free_id,
+            self.clock.now(),
+        )?;
+        remote.save(&outcome)?;
+        self.storage.save_tasks(&outcome.tasks)?;
+        self.sync_state.save(&outcome.state)?;
+
+        let max = outcome.tasks.iter().map(|t| t.id).max().unwrap_or(0);
+        self.next_id = first_free_id.max(max + 1);
+        self.save_next_id()?;
+        let before = std::mem::replace(&mut self.tasks, outcome.tasks);
+        self.saved = self.tasks.clone();
+
+        let report = outcome.report;
+        let find = |tasks: &[Task], id: u32| tasks.iter().find(|t| t.id == id).cloned();
+        let mut events = Vec::new();
+        for &id in &report.received {
+            events.extend(find(&self.tasks, id).map(|after| TaskEvent::Created { after }));
+        }
+        for &id in &report.updated {
+            if let (Some(before), Some(after)) = (find(&before, id), find(&self.tasks, id)) {
+                events.push(TaskEvent::Updated { before, after });
+            }
+        }
+        for &id in &report.deleted {
+            events.extend(find(&before, id).map(|before| TaskEvent::Deleted { before }));
+        }
+        for event in events {
+            self.emit(event);
+        }
+        Ok(report)
     }
 
     /// Adiciona uma nova tarefa
@@ -47,32 +609,95 @@ impl TaskService {
         priority: Priority,
         due_date: Option<chrono::NaiveDate>,
     ) -> io::Result<&Task> {
-        let task = Task::new(
+        let task = Task::new_at(
             self.next_id,
             title,
             description,
             category,
             priority,
             due_date,
+            self.clock.now(),
         );
 
-        self.tasks.push(task);
+        self.insert(task)
+    }
+
+    /// Cria uma tarefa a partir de uma linha de adição rápida
+    ///
+    /// Ex.: "Revisar PR amanhã 15h #trabalho !alta". Categoria e prioridade
+    /// não informadas usam os padrões da configuração.
+    pub fn quick_add(&mut self, text: &str) -> io::Result<&Task> {
+        let quick = quick_add::parse(text, self.clock.now().date_naive());
+        if quick.title.is_empty() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                t("menu.empty_title"),
+            ));
+        }
+
+        let mut task = Task::new_at(
+            self.next_id,
+            quick.title,
+            String::new(),
+            quick.category.unwrap_or(self.default_category),
+            quick.priority.unwrap_or(self.default_priority),
+            quick.due_date,
+            self.clock.now(),
+        );
+        task.due_time = quick.due_time;
+
+        self.insert(task)
+    }
+
+    /// Adiciona uma tarefa já montada, salva e avisa os subscribers
+    ///
+    /// Tarefas novas ficam com o usuário atual como criador e responsável,
+    /// no projeto ativo.
+    fn insert(&mut self, mut task: Task) -> io::Result<&Task> {
+        self.check_wip_limit(task.status, 1)?;
+        self.claim(&mut task);
+        self.tasks.push(task.clone());
         self.next_id += 1;
+        self.save_next_id()?;
         self.save()?;
+        self.emit(TaskEvent::Created { after: task });
 
         Ok(self.tasks.last().unwrap())
     }
 
+    /// Põe uma tarefa nova no nome do usuário atual e no projeto ativo
+    fn claim(&self, task: &mut Task) {
+        task.created_by = self.current_user.clone();
+        task.assignee = self.current_user.clone();
+        task.project = self.active_project.clone();
+    }
+
     /// Retorna todas as tarefas
     pub fn list_all(&self) -> &[Task] {
         &self.tasks
     }
 
+    /// Maior ID existente entre ativas, lixeira e arquivo (0 se não houver)
+    pub fn highest_id(&self) -> u32 {
+        self.tasks
+            .iter()
+            .chain(&self.trash)
+            .chain(&self.archive)
+            .map(|t| t.id)
+            .max()
+            .unwrap_or(0)
+    }
+
     /// Busca uma tarefa por ID
     pub fn get_by_id(&self, id: u32) -> Option<&Task> {
         self.tasks.iter().find(|t| t.id == id)
     }
 
+    /// Busca uma tarefa pelo identificador permanente
+    pub fn get_by_uuid(&self, uuid: Uuid) -> Option<&Task> {
+        self.tasks.iter().find(|t| t.uuid == uuid)
+    }
+
     /// Atualiza uma tarefa
     pub fn update_task(
         &mut self,
@@ -87,7 +712,8 @@ impl TaskService {
             .tasks
             .iter_mut()
             .find(|t| t.id == id)
-            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "Tarefa não encontrada"))?;
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, t("error.task_not_found")))?;
+        let before = task.clone();
 
         if let Some(t) = title {
             task.title = t;
@@ -104,129 +730,1311 @@ impl TaskService {
         if let Some(dd) = due_date {
             task.due_date = dd;
         }
+        let after = task.clone();
 
-        self.save()
+        self.save()?;
+        self.emit(TaskEvent::Updated { before, after });
+        Ok(())
     }
 
-    /// Deleta uma tarefa
+    /// Deleta uma tarefa, movendo-a para a lixeira
     pub fn delete_task(&mut self, id: u32) -> io::Result<()> {
-        let index = self
+        let snapshot = self.snapshot();
+        let before = take(&mut self.tasks, id)?;
+        let now = self.clock.now();
+        let mut task = before.clone();
+        task.stop_timer(now);
+        task.deleted_at = Some(now);
+        self.trash.push(task);
+
+        self.save_moved(snapshot)?;
+        self.emit(TaskEvent::Deleted { before });
+        Ok(())
+    }
+
+    /// Tarefas na lixeira
+    pub fn list_trash(&self) -> &[Task] {
+        &self.trash
+    }
+
+    /// Restaura uma tarefa da lixeira
+    ///
+    /// Respeita o limite de WIP da coluna em que a tarefa volta.
+    pub fn restore_task(&mut self, id: u32) -> io::Result<()> {
+        if let Some(task) = self.trash.iter().find(|t| t.id == id) {
+            self.check_can_return(task)?;
+        }
+        let mut task = take(&mut self.trash, id)?;
+        task.deleted_at = None;
+        self.tasks.push(task.clone());
+
+        self.save()?;
+        self.save_removed()?;
+        self.emit(TaskEvent::Restored { after: task });
+        Ok(())
+    }
+
+    /// Apaga de vez uma tarefa da lixeira
+    pub fn purge_task(&mut self, id: u32) -> io::Result<()> {
+        let before = take(&mut self.trash, id)?;
+        self.save_removed()?;
+        for attachment in &before.attachments {
+            self.release_attachment(&attachment.hash)?;
+        }
+        self.emit(TaskEvent::Purged { before });
+        Ok(())
+    }
+
+    /// Apaga de vez as tarefas que estão na lixeira há mais de `days` dias
+    ///
+    /// Retorna quantas tarefas foram apagadas.
+    pub fn purge_trash_older_than(&mut self, days: u32) -> io::Result<usize> {
+        let cutoff = self.clock.now() - Duration::days(days as i64);
+        let expired: Vec<u32> = self
+            .trash
+            .iter()
+            .filter(|t| t.deleted_at.is_none_or(|at| at < cutoff))
+            .map(|t| t.id)
+            .collect();
+
+        for id in &expired {
+            self.purge_task(*id)?;
+        }
+        Ok(expired.len())
+    }
+
+    /// Apaga as tarefas vencidas na lixeira, conforme a configuração
+    pub fn purge_expired(&mut self) -> io::Result<usize> {
+        self.purge_trash_older_than(self.trash_retention_days)
+    }
+
+    /// Tarefas arquivadas
+    pub fn list_archived(&self) -> &[Task] {
+        &self.archive
+    }
+
+    /// Arquiva uma tarefa concluída
+    pub fn archive_task(&mut self, id: u32) -> io::Result<()> {
+        if self.get_by_id(id).is_some_and(|t| t.status != Status::Completed) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                t("error.archive_not_completed"),
+            ));
+        }
+
+        let snapshot = self.snapshot();
+        let before = take(&mut self.tasks, id)?;
+        let mut task = before.clone();
+        task.archived_at = Some(self.clock.now());
+        self.archive.push(task);
+
+        self.save_moved(snapshot)?;
+        self.emit(TaskEvent::Archived { before });
+        Ok(())
+    }
+
+    /// Arquiva todas as tarefas concluídas, retornando quantas foram
+    pub fn archive_completed(&mut self) -> io::Result<usize> {
+        let ids: Vec<u32> = self
             .tasks
             .iter()
-            .position(|t| t.id == id)
-            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "Tarefa não encontrada"))?;
+            .filter(|t| t.status == Status::Completed)
+            .map(|t| t.id)
+            .collect();
 
-        self.tasks.remove(index);
-        self.save()
+        for id in &ids {
+            self.archive_task(*id)?;
+        }
+        Ok(ids.len())
+    }
+
+    /// Devolve uma tarefa arquivada ao conjunto ativo
+    ///
+    /// Respeita o limite de WIP da coluna em que a tarefa volta.
+    pub fn unarchive_task(&mut self, id: u32) -> io::Result<()> {
+        if let Some(task) = self.archive.iter().find(|t| t.id == id) {
+            self.check_can_return(task)?;
+        }
+        let mut task = take(&mut self.archive, id)?;
+        task.archived_at = None;
+        self.tasks.push(task.clone());
+
+        self.save()?;
+        self.save_removed()?;
+        self.emit(TaskEvent::Restored { after: task });
+        Ok(())
     }
 
     /// Marca uma tarefa como concluída
     pub fn complete_task(&mut self, id: u32) -> io::Result<()> {
+        let now = self.clock.now();
         let task = self
             .tasks
             .iter_mut()
             .find(|t| t.id == id)
-            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "Tarefa não encontrada"))?;
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, t("error.task_not_found")))?;
+
+        let before = task.clone();
+        task.complete(now);
+        let after = task.clone();
 
-        task.complete();
-        self.save()
+        self.save()?;
+        self.emit(TaskEvent::Completed { before, after });
+        Ok(())
     }
 
     /// Inicia uma tarefa
+    ///
+    /// Respeita o limite de WIP da coluna "em andamento".
     pub fn start_task(&mut self, id: u32) -> io::Result<()> {
+        self.check_can_start(id)?;
+
         let task = self
             .tasks
             .iter_mut()
             .find(|t| t.id == id)
-            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "Tarefa não encontrada"))?;
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, t("error.task_not_found")))?;
 
+        let before = task.clone();
         task.start();
-        self.save()
+        let after = task.clone();
+
+        self.save()?;
+        if before.status != after.status {
+            self.emit(TaskEvent::Started { before, after });
+        }
+        Ok(())
     }
 
-    /// Filtra tarefas por status
-    pub fn filter_by_status(&self, status: Status) -> Vec<&Task> {
+    /// Inicia o cronômetro de uma tarefa
+    ///
+    /// Só um cronômetro roda por vez: se outra tarefa estiver sendo
+    /// cronometrada, retorna erro.
+    pub fn start_timer(&mut self, id: u32) -> io::Result<()> {
+        if let Some(running) = self.running_timer() {
+            return Err(io::Error::new(
+                io::ErrorKind::AlreadyExists,
+                tf("error.timer_running", &[&running.id, &running.title]),
+            ));
+        }
+
+        self.check_can_start(id)?;
+        let now = self.clock.now();
+        let task = self.find_mut(id)?;
+        let before = task.clone();
+        task.start_timer(now);
+        let after = task.clone();
+
+        self.save()?;
+        if before.status != after.status {
+            self.emit(TaskEvent::Started { before, after });
+        } else {
+            self.emit(TaskEvent::Updated { before, after });
+        }
+        Ok(())
+    }
+
+    /// Para o cronômetro de uma tarefa, retornando a duração do intervalo
+    pub fn stop_timer(&mut self, id: u32) -> io::Result<Duration> {
+        let now = self.clock.now();
+        let task = self.find_mut(id)?;
+        let before = task.clone();
+        let elapsed = task.stop_timer(now).ok_or_else(|| {
+            io::Error::new(io::ErrorKind::InvalidInput, t("error.timer_not_running"))
+        })?;
+        let after = task.clone();
+
+        self.save()?;
+        self.emit(TaskEvent::Updated { before, after });
+        Ok(elapsed)
+    }
+
+    /// Tarefa com o cronômetro rodando, se houver
+    pub fn running_timer(&self) -> Option<&Task> {
+        self.tasks.iter().find(|t| t.is_timer_running())
+    }
+
+    /// Registra tempo trabalhado manualmente em uma tarefa
+    pub fn log_time(&mut self, id: u32, minutes: u32) -> io::Result<()> {
+        if minutes == 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                t("error.invalid_minutes"),
+            ));
+        }
+
+        let now = self.clock.now();
+        let task = self.find_mut(id)?;
+        let before = task.clone();
+        task.log_time(minutes, now);
+        let after = task.clone();
+
+        self.save()?;
+        self.emit(TaskEvent::Updated { before, after });
+        Ok(())
+    }
+
+    /// Define (ou remove, com `None`) a estimativa de uma tarefa, em minutos
+    pub fn set_estimate(&mut self, id: u32, minutes: Option<u32>) -> io::Result<()> {
+        let task = self.find_mut(id)?;
+        let before = task.clone();
+        task.estimate_minutes = minutes.filter(|m| *m > 0);
+        let after = task.clone();
+
+        self.save()?;
+        self.emit(TaskEvent::Updated { before, after });
+        Ok(())
+    }
+
+    /// Define o responsável por uma tarefa (`None` remove)
+    pub fn assign_task(&mut self, id: u32, assignee: Option<String>) -> io::Result<()> {
+        let task = self.find_mut(id)?;
+        let before = task.clone();
+        task.assignee = assignee
+            .map(|a| a.trim().to_string())
+            .filter(|a| !a.is_empty());
+        let after = task.clone();
+
+        self.save()?;
+        self.emit(TaskEvent::Updated { before, after });
+        Ok(())
+    }
+
+    /// Busca uma tarefa para alteração
+    fn find_mut(&mut self, id: u32) -> io::Result<&mut Task> {
         self.tasks
+            .iter_mut()
+            .find(|t| t.id == id)
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, t("error.task_not_found")))
+    }
+
+    /// Aplica uma ação a várias tarefas, salvando uma única vez
+    ///
+    /// IDs inexistentes ou bloqueados (ex.: limite de WIP) ficam com erro
+    /// no relatório sem impedir os demais. Se o salvamento falhar, nada é
+    /// alterado.
+    pub fn bulk(&mut self, ids: &[u32], action: BulkAction) -> io::Result<BulkReport> {
+        let snapshot = self.snapshot();
+
+        let mut report = BulkReport::default();
+        let mut events = Vec::new();
+
+        for &id in ids {
+            if report.results.iter().any(|(done, _)| *done == id) {
+                continue;
+            }
+            let result = self.apply_bulk(id, action).map(|event| events.extend(event));
+            report.results.push((id, result));
+        }
+
+        if !events.is_empty() {
+            self.save_moved(snapshot)?;
+        }
+
+        for event in events {
+            self.emit(event);
+        }
+        Ok(report)
+    }
+
+    /// Aplica uma ação a todas as tarefas ativas que passam pelo filtro
+    pub fn bulk_matching<F>(&mut self, filter: F, action: BulkAction) -> io::Result<BulkReport>
+    where
+        F: Fn(&Task) -> bool,
+    {
+        let ids: Vec<u32> = self
+            .tasks
             .iter()
+            .filter(|t| filter(t))
+            .map(|t| t.id)
+            .collect();
+        self.bulk(&ids, action)
+    }
+
+    /// Altera uma tarefa em memória, retornando o evento a emitir
+    fn apply_bulk(&mut self, id: u32, action: BulkAction) -> io::Result<Option<TaskEvent>> {
+        if action == BulkAction::Start {
+            self.check_can_start(id)?;
+        }
+
+        if action == BulkAction::Delete {
+            let before = take(&mut self.tasks, id)?;
+            let now = self.clock.now();
+            let mut task = before.clone();
+            task.stop_timer(now);
+            task.deleted_at = Some(now);
+            self.trash.push(task);
+            return Ok(Some(TaskEvent::Deleted { before }));
+        }
+
+        let now = self.clock.now();
+        let task = self.find_mut(id)?;
+        let before = task.clone();
+
+        match action {
+            BulkAction::Complete => task.complete(now),
+            BulkAction::Start => task.start(),
+            BulkAction::SetPriority(priority) => task.priority = priority,
+            BulkAction::SetCategory(category) => task.category = category,
+            BulkAction::SetDueDate(due_date) => task.due_date = due_date,
+            // Tratada acima, antes de buscar a tarefa ativa
+            BulkAction::Delete => {}
+        }
+        let after = task.clone();
+
+        let event = match action {
+            BulkAction::Complete => TaskEvent::Completed { before, after },
+            BulkAction::Start if before.status == after.status => return Ok(None),
+            BulkAction::Start => TaskEvent::Started { before, after },
+            _ => TaskEvent::Updated { before, after },
+        };
+        Ok(Some(event))
+    }
+
+    /// Define as tarefas que bloqueiam `id` (lista vazia remove o bloqueio)
+    pub fn set_dependencies(&mut self, id: u32, blocked_by: Vec<u32>) -> io::Result<()> {
+        if blocked_by.contains(&id) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                t("error.invalid_dependency"),
+            ));
+        }
+        if let Some(missing) = blocked_by.iter().find(|dep| self.get_by_id(**dep).is_none()) {
+            return Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("{}: {}", t("error.task_not_found"), missing),
+            ));
+        }
+
+        let task = self.find_mut(id)?;
+        let before = task.clone();
+        task.blocked_by = blocked_by;
+        let after = task.clone();
+
+        self.save()?;
+        self.emit(TaskEvent::Updated { before, after });
+        Ok(())
+    }
+
+    /// Verifica se alguma tarefa que bloqueia esta ainda está ativa e aberta
+    ///
+    /// Dependências excluídas ou arquivadas não bloqueiam mais.
+    pub fn is_blocked(&self, task: &Task) -> bool {
+        task.blocked_by.iter().any(|dep| {
+            self.get_by_id(*dep)
+                .is_some_and(|t| t.status != Status::Completed)
+        })
+    }
+
+    /// As `n` tarefas abertas mais urgentes, com a pontuação de cada uma
+    pub fn next_up(&self, n: usize) -> Vec<(&Task, Score)> {
+        let today = self.clock.now().date_naive();
+        let mut ranked: Vec<(&Task, Score)> = self
+            .active_tasks()
+            .into_iter()
+            .filter(|t| t.status != Status::Completed)
+            .map(|t| (t, scoring::score(t, self.is_blocked(t), today)))
+            .collect();
+
+        ranked.sort_by(|(a, sa), (b, sb)| {
+            sb.total
+                .total_cmp(&sa.total)
+                .then_with(|| a.id.cmp(&b.id))
+        });
+        ranked.truncate(n);
+        ranked
+    }
+
+    /// Filtra as tarefas do projeto ativo por status
+    pub fn filter_by_status(&self, status: Status) -> Vec<&Task> {
+        self.active_tasks()
+            .into_iter()
             .filter(|t| t.status == status)
             .collect()
     }
 
-    /// Filtra tarefas por categoria
+    /// Filtra as tarefas do projeto ativo por categoria
     pub fn filter_by_category(&self, category: Category) -> Vec<&Task> {
-        self.tasks
-            .iter()
+        self.active_tasks()
+            .into_iter()
             .filter(|t| t.category == category)
             .collect()
     }
 
-    /// Filtra tarefas por prioridade
+    /// Filtra as tarefas do projeto ativo por prioridade
     pub fn filter_by_priority(&self, priority: Priority) -> Vec<&Task> {
+        self.active_tasks()
+            .into_iter()
+            .filter(|t| t.priority == priority)
+            .collect()
+    }
+
+    /// Retorna os projetos, na ordem de criação
+    pub fn list_projects(&self) -> &[Project] {
+        &self.projects
+    }
+
+    /// Busca um projeto pelo nome (sem diferenciar maiúsculas e acentos)
+    pub fn get_project(&self, name: &str) -> Option<&Project> {
+        self.projects.iter().find(|p| p.has_name(name))
+    }
+
+    /// Cria um projeto
+    pub fn create_project(
+        &mut self,
+        name: String,
+        description: String,
+        color: ProjectColor,
+    ) -> io::Result<&Project> {
+        let name = name.trim().to_string();
+        if name.is_empty() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                t("error.empty_project_name"),
+            ));
+        }
+        if self.get_project(&name).is_some() {
+            return Err(io::Error::new(
+                io::ErrorKind::AlreadyExists,
+                tf("error.project_exists", &[&name]),
+            ));
+        }
+
+        self.projects.push(Project::new(name, description, color));
+        self.save_projects()?;
+        Ok(self.projects.last().unwrap())
+    }
+
+    /// Exclui um projeto; as tarefas dele ficam sem projeto
+    pub fn delete_project(&mut self, name: &str) -> io::Result<()> {
+        let name = self.project_name(name)?;
+
+        let ids: Vec<u32> = self
+            .tasks
+            .iter()
+            .filter(|t| t.project.as_deref() == Some(name.as_str()))
+            .map(|t| t.id)
+            .collect();
+        for id in ids {
+            self.move_task(id, None)?;
+        }
+
+        // A lixeira e o arquivo também deixam de apontar para o projeto
+        for task in self.trash.iter_mut().chain(self.archive.iter_mut()) {
+            if task.project.as_deref() == Some(name.as_str()) {
+                task.project = None;
+            }
+        }
+        self.save_removed()?;
+
+        self.projects.retain(|p| p.name != name);
+        if self.active_project.as_deref() == Some(name.as_str()) {
+            self.active_project = None;
+        }
+        self.save_projects()
+    }
+
+    /// Projeto ativo, se houver
+    pub fn active_project(&self) -> Option<&Project> {
+        self.active_project
+            .as_deref()
+            .and_then(|name| self.get_project(name))
+    }
+
+    /// Troca o projeto ativo (`None` mostra todas as tarefas)
+    pub fn set_active_project(&mut self, name: Option<&str>) -> io::Result<()> {
+        self.active_project = match name {
+            Some(name) => Some(self.project_name(name)?),
+            None => None,
+        };
+        Ok(())
+    }
+
+    /// Tarefas do projeto ativo (todas, se nenhum estiver ativo)
+    ///
+    /// Listas, filtros, estatísticas e sugestões partem daqui.
+    pub fn active_tasks(&self) -> Vec<&Task> {
+        self.tasks.iter().filter(|t| self.in_active_project(t)).collect()
+    }
+
+    /// Verifica se a tarefa está no projeto ativo (todas estão, se nenhum
+    /// estiver ativo)
+    fn in_active_project(&self, task: &Task) -> bool {
+        self.active_project.is_none() || task.project == self.active_project
+    }
+
+    /// Move uma tarefa para outro projeto (`None` = sem projeto)
+    ///
+    /// Levar a tarefa para o projeto ativo respeita o limite de WIP.
+    pub fn move_task(&mut self, id: u32, project: Option<&str>) -> io::Result<()> {
+        let project = match project {
+            Some(name) => Some(self.project_name(name)?),
+            None => None,
+        };
+        if let Some(task) = self.get_by_id(id)
+            && !self.in_active_project(task)
+            && project == self.active_project
+        {
+            self.check_wip_limit(task.status, 1)?;
+        }
+
+        let task = self.find_mut(id)?;
+        let before = task.clone();
+        task.project = project;
+        let after = task.clone();
+
+        self.save()?;
+        self.emit(TaskEvent::Updated { before, after });
+        Ok(())
+    }
+
+    /// Filtra tarefas por projeto (`None` = tarefas sem projeto)
+    pub fn filter_by_project(&self, project: Option<&str>) -> Vec<&Task> {
         self.tasks
             .iter()
-            .filter(|t| t.priority == priority)
+            .filter(|t| t.project.as_deref() == project)
             .collect()
     }
 
-    /// Retorna tarefas atrasadas
+    /// Estatísticas de cada projeto, na ordem de criação
+    ///
+    /// Tarefas sem projeto ficam no grupo `None`, por último, se houver.
+    pub fn get_statistics_by_project(&self) -> Vec<(Option<String>, Statistics)> {
+        let mut groups: Vec<Option<String>> =
+            self.projects.iter().map(|p| Some(p.name.clone())).collect();
+        if self.tasks.iter().any(|t| t.project.is_none()) {
+            groups.push(None);
+        }
+
+        groups
+            .into_iter()
+            .map(|project| {
+                let tasks = self.filter_by_project(project.as_deref());
+                let stats = Statistics::from_tasks(&tasks, self.clock.now());
+                (project, stats)
+            })
+            .collect()
+    }
+
+    /// Nome canônico de um projeto existente
+    fn project_name(&self, name: &str) -> io::Result<String> {
+        self.get_project(name)
+            .map(|p| p.name.clone())
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::NotFound,
+                    tf("error.project_not_found", &[&name]),
+                )
+            })
+    }
+
+    /// Salva os projetos (sem projetos, não deixa arquivo)
+    fn save_projects(&self) -> io::Result<()> {
+        if !self.projects.is_empty() {
+            self.projects_storage.save(&self.projects)
+        } else if self.projects_storage.exists() {
+            self.projects_storage.delete()
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Retorna os modelos, na ordem de criação
+    pub fn list_templates(&self) -> &[Template] {
+        &self.templates
+    }
+
+    /// Busca um modelo pelo nome (sem diferenciar maiúsculas e acentos)
+    pub fn get_template(&self, name: &str) -> Option<&Template> {
+        self.templates.iter().find(|t| t.has_name(name))
+    }
+
+    /// Cria um modelo
+    pub fn create_template(&mut self, mut template: Template) -> io::Result<&Template> {
+        template.name = template.name.trim().to_string();
+        if template.name.is_empty() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                t("error.empty_template_name"),
+            ));
+        }
+        if self.get_template(&template.name).is_some() {
+            return Err(io::Error::new(
+                io::ErrorKind::AlreadyExists,
+                tf("error.template_exists", &[&template.name]),
+            ));
+        }
+        check_template(&template)?;
+
+        self.templates.push(template);
+        self.save_templates()?;
+        Ok(self.templates.last().unwrap())
+    }
+
+    /// Cria um modelo a partir de tarefas existentes
+    ///
+    /// Os prazos viram dias contados a partir de hoje, e as dependências
+    /// entre as tarefas escolhidas são mantidas.
+    pub fn template_from_tasks(
+        &mut self,
+        name: String,
+        description: String,
+        ids: &[u32],
+    ) -> io::Result<&Template> {
+        let today = self.clock.now().date_naive();
+        let tasks = ids
+            .iter()
+            .map(|id| {
+                let task = self.get_by_id(*id).ok_or_else(|| {
+                    io::Error::new(
+                        io::ErrorKind::NotFound,
+                        format!("{}: {}", t("error.task_not_found"), id),
+                    )
+                })?;
+                Ok(TemplateTask {
+                    title: task.title.clone(),
+                    description: task.description.clone(),
+                    category: task.category,
+                    priority: task.priority,
+                    due_in_days: task.due_date.map(|due| (due - today).num_days()),
+                    due_time: task.due_time,
+                    estimate_minutes: task.estimate_minutes,
+                    after: task
+                        .blocked_by
+                        .iter()
+                        .filter_map(|dep| ids.iter().position(|id| id == dep))
+                        .collect(),
+                })
+            })
+            .collect::<io::Result<Vec<_>>>()?;
+
+        self.create_template(Template::new(name, description, tasks))
+    }
+
+    /// Exclui um modelo (as tarefas criadas com ele continuam)
+    pub fn delete_template(&mut self, name: &str) -> io::Result<()> {
+        let index = self
+            .templates
+            .iter()
+            .position(|t| t.has_name(name))
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::NotFound,
+                    tf("error.template_not_found", &[&name]),
+                )
+            })?;
+        self.templates.remove(index);
+        self.save_templates()
+    }
+
+    /// Cria as tarefas de um modelo, preenchendo as variáveis com `values`
+    ///
+    /// Os prazos são contados a partir de `start`. As tarefas são salvas de
+    /// uma vez: se faltar o valor de alguma variável, ou se a gravação
+    /// falhar, nenhuma é criada. Retorna os IDs, na ordem do modelo.
+    pub fn instantiate_template(
+        &mut self,
+        name: &str,
+        values: &HashMap<String, String>,
+        start: NaiveDate,
+    ) -> io::Result<Vec<u32>> {
+        let template = self.get_template(name).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::NotFound,
+                tf("error.template_not_found", &[&name]),
+            )
+        })?;
+        check_template(template)?;
+        self.check_wip_limit(Status::Pending, template.tasks.len())?;
+
+        let missing = |variable: String| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                tf("error.template_missing_variable", &[&variable]),
+            )
+        };
+        let now = self.clock.now();
+        let first_id = self.next_id;
+        let tasks = template
+            .tasks
+            .iter()
+            .enumerate()
+            .map(|(i, item)| {
+                let mut task = Task::new_at(
+                    first_id + i as u32,
+                    template::fill(&item.title, values).map_err(missing)?,
+                    template::fill(&item.description, values).map_err(missing)?,
+                    item.category,
+                    item.priority,
+                    item.due_date(start)?,
+                    now,
+                );
+                self.claim(&mut task);
+                task.due_time = item.due_time.filter(|_| task.due_date.is_some());
+                task.estimate_minutes = item.estimate_minutes;
+                task.blocked_by = item.after.iter().map(|j| first_id + *j as u32).collect();
+                Ok(task)
+            })
+            .collect::<io::Result<Vec<Task>>>()?;
+
+        let count = self.tasks.len();
+        self.tasks.extend(tasks.iter().cloned());
+        // IDs reservados continuam gastos mesmo se a gravação falhar
+        self.next_id += tasks.len() as u32;
+        let saved = self.save_next_id().and_then(|_| self.save());
+        if let Err(e) = saved {
+            self.tasks.truncate(count);
+            // Tenta deixar os arquivos como estavam
+            let _ = self.save();
+            return Err(e);
+        }
+
+        let ids = tasks.iter().map(|task| task.id).collect();
+        for task in tasks {
+            self.emit(TaskEvent::Created { after: task });
+        }
+        Ok(ids)
+    }
+
+    /// Salva os modelos (sem modelos, não deixa arquivo)
+    fn save_templates(&self) -> io::Result<()> {
+        if !self.templates.is_empty() {
+            self.templates_storage.save(&self.templates)
+        } else if self.templates_storage.exists() {
+            self.templates_storage.delete()
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Acrescenta uma nota datada, assinada pelo usuário atual
+    pub fn add_note(&mut self, id: u32, text: &str) -> io::Result<()> {
+        let text = text.trim();
+        if text.is_empty() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                t("error.empty_note"),
+            ));
+        }
+
+        let author = self.current_user.clone();
+        let now = self.clock.now();
+        let task = self.find_mut(id)?;
+        let before = task.clone();
+        task.notes.push(Note::new(text.to_string(), author, now));
+        let after = task.clone();
+
+        self.save()?;
+        self.emit(TaskEvent::Updated { before, after });
+        Ok(())
+    }
+
+    /// Registra um pomodoro concluído na tarefa
+    pub fn log_pomodoro(&mut self, id: u32, pomodoro: Pomodoro) -> io::Result<()> {
+        let task = self.find_mut(id)?;
+        let before = task.clone();
+        task.pomodoros.push(pomodoro);
+        let after = task.clone();
+
+        self.save()?;
+        self.emit(TaskEvent::Updated { before, after });
+        Ok(())
+    }
+
+    /// Copia um arquivo para a pasta de anexos e o anexa à tarefa
+    pub fn attach_file(&mut self, id: u32, source: &Path) -> io::Result<&Attachment> {
+        self.find_mut(id)?;
+        let name = source
+            .file_name()
+            .map(|n| n.to_string_lossy().into_owned())
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    tf("error.invalid_attachment", &[&source.display()]),
+                )
+            })?;
+        let (hash, size) = self.attachment_store.add(source)?;
+
+        let now = self.clock.now();
+        let task = self.find_mut(id)?;
+        if task.attachments.iter().any(|a| a.hash == hash) {
+            return Err(io::Error::new(
+                io::ErrorKind::AlreadyExists,
+                tf("error.attachment_exists", &[&name]),
+            ));
+        }
+
+        let before = task.clone();
+        task.attachments.push(Attachment {
+            name,
+            hash,
+            size,
+            added_at: now,
+        });
+        let after = task.clone();
+
+        self.save()?;
+        self.emit(TaskEvent::Updated { before, after });
+        let task = self.get_by_id(id).unwrap();
+        Ok(task.attachments.last().unwrap())
+    }
+
+    /// Anexos de uma tarefa
+    pub fn list_attachments(&self, id: u32) -> io::Result<&[Attachment]> {
+        self.get_by_id(id)
+            .map(|t| t.attachments.as_slice())
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, t("error.task_not_found")))
+    }
+
+    /// Caminho do conteúdo de um anexo, para abrir em outro programa
+    ///
+    /// Falha se o arquivo sumiu ou não bate mais com o hash. Com as tarefas
+    /// criptografadas, o arquivo também é: use [`TaskService::save_attachment`].
+    pub fn attachment_path(&self, attachment: &Attachment) -> io::Result<PathBuf> {
+        self.check_attachment(attachment)?;
+        self.attachment_store.path(&attachment.hash)
+    }
+
+    /// Grava em `target` uma cópia aberta (sem criptografia) de um anexo
+    pub fn save_attachment(&self, attachment: &Attachment, target: &Path) -> io::Result<()> {
+        self.check_attachment(attachment)?;
+        self.attachment_store.copy_to(&attachment.hash, target)
+    }
+
+    /// Falha se o conteúdo do anexo sumiu ou não bate mais com o hash
+    fn check_attachment(&self, attachment: &Attachment) -> io::Result<()> {
+        if !self.attachment_store.verify(&attachment.hash) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                tf("error.attachment_broken", &[&attachment.name]),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Remove o anexo de posição `index` (começando em 0) de uma tarefa
+    ///
+    /// O conteúdo só é apagado se nenhuma outra tarefa usar o mesmo arquivo.
+    pub fn remove_attachment(&mut self, id: u32, index: usize) -> io::Result<Attachment> {
+        let task = self.find_mut(id)?;
+        if index >= task.attachments.len() {
+            return Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                t("error.attachment_not_found"),
+            ));
+        }
+
+        let before = task.clone();
+        let removed = task.attachments.remove(index);
+        let after = task.clone();
+
+        self.save()?;
+        self.release_attachment(&removed.hash)?;
+        self.broken_attachments
+            .retain(|(task_id, name)| *task_id != id || *name != removed.name);
+        self.emit(TaskEvent::Updated { before, after });
+        Ok(removed)
+    }
+
+    /// Anexos ausentes ou corrompidos encontrados ao carregar (ID da tarefa, nome)
+    pub fn broken_attachments(&self) -> &[(u32, String)] {
+        &self.broken_attachments
+    }
+
+    /// Arquivos de tarefa que não deram para ler ao carregar (ex.: com
+    /// marcas de conflito do git), deixados no disco até serem corrigidos
+    pub fn skipped_files(&self) -> &[PathBuf] {
+        &self.skipped_files
+    }
+
+    /// Confere o hash de todos os anexos (ativas, lixeira e arquivo)
+    fn check_attachments(&self) -> Vec<(u32, String)> {
+        self.tasks
+            .iter()
+            .chain(&self.trash)
+            .chain(&self.archive)
+            .flat_map(|t| t.attachments.iter().map(move |a| (t.id, a)))
+            .filter(|(_, a)| !self.attachment_store.verify(&a.hash))
+            .map(|(id, a)| (id, a.name.clone()))
+            .collect()
+    }
+
+    /// Apaga o conteúdo de um anexo que nenhuma tarefa usa mais
+    fn release_attachment(&self, hash: &str) -> io::Result<()> {
+        let in_use = self
+            .tasks
+            .iter()
+            .chain(&self.trash)
+            .chain(&self.archive)
+            .any(|t| t.attachments.iter().any(|a| a.hash == hash));
+        if in_use {
+            Ok(())
+        } else {
+            self.attachment_store.remove(hash)
+        }
+    }
+
+    /// Filtra as tarefas do projeto ativo pelo responsável
+    pub fn filter_by_assignee(&self, user: &str) -> Vec<&Task> {
+        self.active_tasks()
+            .into_iter()
+            .filter(|t| t.assignee.as_deref() == Some(user))
+            .collect()
+    }
+
+    /// Tarefas sob responsabilidade do usuário atual
+    pub fn my_tasks(&self) -> Vec<&Task> {
+        match self.current_user() {
+            Some(user) => self.filter_by_assignee(user),
+            None => Vec::new(),
+        }
+    }
+
+    /// Tarefas criadas pelo usuário atual e atribuídas a outra pessoa
+    pub fn assigned_by_me(&self) -> Vec<&Task> {
+        let Some(user) = self.current_user() else {
+            return Vec::new();
+        };
+        self.active_tasks()
+            .into_iter()
+            .filter(|t| t.created_by.as_deref() == Some(user))
+            .filter(|t| t.assignee.as_deref().is_some_and(|a| a != user))
+            .collect()
+    }
+
+    /// Retorna as tarefas atrasadas do projeto ativo
     pub fn get_overdue(&self) -> Vec<&Task> {
-        self.tasks.iter().filter(|t| t.is_overdue()).collect()
+        let now = self.clock.now();
+        self.active_tasks()
+            .into_iter()
+            .filter(|t| t.is_overdue_at(&now))
+            .collect()
     }
 
-    /// Retorna estatísticas
+    /// Retorna a análise de produtividade do período `from..=to`
+    ///
+    /// Inclui as tarefas arquivadas: arquivar não apaga o histórico.
+    pub fn get_analytics(&self, from: NaiveDate, to: NaiveDate) -> Analytics {
+        let tasks = self.active_tasks_with_archived();
+        Analytics::compute(&tasks, from, to, self.clock.now().date_naive())
+    }
+
+    /// Retorna estatísticas das tarefas do projeto ativo (sem as arquivadas)
     pub fn get_statistics(&self) -> Statistics {
-        let total = self.tasks.len();
-        let completed = self.filter_by_status(Status::Completed).len();
-        let in_progress = self.filter_by_status(Status::InProgress).len();
-        let pending = self.filter_by_status(Status::Pending).len();
-        let overdue = self.get_overdue().len();
+        Statistics::from_tasks(&self.active_tasks(), self.clock.now())
+    }
 
-        let by_category = Category::all()
-            .iter()
-            .map(|c| (*c, self.filter_by_category(*c).len()))
-            .collect();
+    /// Estatísticas por responsável, em ordem alfabética
+    ///
+    /// Tarefas sem responsável ficam no grupo `None`, por último.
+    pub fn get_statistics_by_user(&self) -> Vec<(Option<String>, Statistics)> {
+        let active = self.active_tasks();
+        let mut users: Vec<Option<&str>> = active.iter().map(|t| t.assignee.as_deref()).collect();
+        users.sort_by_key(|user| (user.is_none(), *user));
+        users.dedup();
+
+        users
+            .into_iter()
+            .map(|user| {
+                let tasks: Vec<&Task> = active
+                    .iter()
+                    .copied()
+                    .filter(|t| t.assignee.as_deref() == user)
+                    .collect();
+                (
+                    user.map(str::to_string),
+                    Statistics::from_tasks(&tasks, self.clock.now()),
+                )
+            })
+            .collect()
+    }
 
-        let by_priority = Priority::all()
+    /// Retorna estatísticas incluindo as tarefas arquivadas
+    pub fn get_statistics_with_archived(&self) -> Statistics {
+        Statistics::from_tasks(&self.active_tasks_with_archived(), self.clock.now())
+    }
+
+    /// Tarefas do projeto ativo, seguidas das arquivadas do mesmo projeto
+    fn active_tasks_with_archived(&self) -> Vec<&Task> {
+        let mut tasks = self.active_tasks();
+        tasks.extend(self.archive.iter().filter(|t| self.in_active_project(t)));
+        tasks
+    }
+}
+
+/// Confere se o modelo tem tarefas com título e dependências válidas
+fn check_template(template: &Template) -> io::Result<()> {
+    if template.tasks.is_empty() {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            t("error.empty_template"),
+        ));
+    }
+    for (i, task) in template.tasks.iter().enumerate() {
+        if task.title.trim().is_empty() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                t("menu.empty_title"),
+            ));
+        }
+        if task
+            .after
             .iter()
-            .map(|p| (*p, self.filter_by_priority(*p).len()))
-            .collect();
+            .any(|j| *j == i || *j >= template.tasks.len())
+        {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                tf("error.template_dependency", &[&(i + 1)]),
+            ));
+        }
+    }
+    Ok(())
+}
 
-        Statistics {
-            total,
-            completed,
-            in_progress,
-            pending,
-            overdue,
-            by_category,
-            by_priority,
+/// Remove e retorna a tarefa com o ID informado
+fn take(tasks: &mut Vec<Task>, id: u32) -> io::Result<Task> {
+    let index = tasks
+        .iter()
+        .position(|t| t.id == id)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, t("error.task_not_found")))?;
+    Ok(tasks.remove(index))
+}
+
+/// Grava um conjunto exportado; vazio, apaga o arquivo de uma cópia anterior
+fn export_set<T: Serialize>(path: String, items: &[T]) -> io::Result<()> {
+    let storage = Storage::new(path);
+    if items.is_empty() {
+        storage.delete()
+    } else {
+        storage.save(&items)
+    }
+}
+
+/// Caminho de um arquivo irmão (ex.: "data/tasks.json" -> "data/tasks.trash.json")
+pub(crate) fn sibling_path(path: &str, kind: &str) -> String {
+    match path.strip_suffix(".json") {
+        Some(stem) => format!("{}.{}.json", stem, kind),
+        None => format!("{}.{}", path, kind),
+    }
+}
+
+/// Próximo ID numérico, gravado ao lado das tarefas
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct IdCounter {
+    next_id: u32,
+}
+
+/// Arquivos de um TaskService: tarefas, lixeira, arquivo, projetos,
+/// estado de sincronização e contador de IDs
+struct Storages {
+    tasks: Box<dyn TaskStorage>,
+    trash: Box<dyn TaskStorage>,
+    archive: Box<dyn TaskStorage>,
+    projects: Storage,
+    templates: Storage,
+    sync_state: Storage,
+    ids: Storage,
+}
+
+impl Storages {
+    /// Com cifra, todos os arquivos JSON são criptografados
+    fn open(path: &str, format: StorageFormat, cipher: Option<&Arc<Cipher>>) -> Self {
+        let json = |path: String| match cipher {
+            Some(cipher) => Storage::encrypted(path, Arc::clone(cipher)),
+            None => Storage::new(path),
+        };
+        let (tasks, trash, archive): (Box<dyn TaskStorage>, Box<dyn TaskStorage>, Box<dyn TaskStorage>) =
+            match format {
+                StorageFormat::Json => (
+                    Box::new(json(path.to_string())),
+                    Box::new(json(sibling_path(path, "trash"))),
+                    Box::new(json(sibling_path(path, "archive"))),
+                ),
+                StorageFormat::Markdown => (
+                    format.open(path.to_string()),
+                    format.open(sibling_dir(path, "trash")),
+                    format.open(sibling_dir(path, "archive")),
+                ),
+            };
+
+        Storages {
+            tasks,
+            trash,
+            archive,
+            projects: json(sibling_path(path, "projects")),
+            templates: json(sibling_path(path, "templates")),
+            sync_state: json(sync::state_path(path)),
+            ids: json(sibling_path(path, "ids")),
+        }
+    }
+}
+
+/// Resultado de uma carga: arquivo ausente ou ilegível vira conjunto vazio,
+/// mas o primeiro erro de senha é guardado em `error`
+fn loaded<T: Default>(result: io::Result<T>, error: &mut Option<io::Error>) -> T {
+    match result {
+        Ok(value) => value,
+        Err(e) => {
+            if e.kind() == io::ErrorKind::PermissionDenied && error.is_none() {
+                *error = Some(e);
+            }
+            T::default()
+        }
+    }
+}
+
+/// Descarta anexos cujo hash não é um SHA-256 válido (ex.: "../x"),
+/// retornando-os como (ID da tarefa, nome) para o aviso de anexos quebrados
+fn reject_invalid_hashes(sets: [&mut Vec<Task>; 3]) -> Vec<(u32, String)> {
+    let mut rejected = Vec::new();
+    for task in sets.into_iter().flat_map(|tasks| tasks.iter_mut()) {
+        task.attachments.retain(|a| {
+            let valid = attachment_store::is_valid_hash(&a.hash);
+            if !valid {
+                rejected.push((task.id, a.name.clone()));
+            }
+            valid
+        });
+    }
+    rejected
+}
+
+/// Troca a pasta `dir` pela `staged` (se existir)
+///
+/// A pasta antiga é renomeada antes e volta ao lugar se a troca falhar.
+/// Apagá-la depois é o último passo, e uma falha nele não desfaz a troca.
+fn replace_dir(staged: &str, dir: &str) -> io::Result<()> {
+    if !Path::new(staged).exists() {
+        return Ok(());
+    }
+    let old = format!("{}.old", dir);
+    let had_dir = Path::new(dir).exists();
+    if had_dir {
+        if Path::new(&old).exists() {
+            fs::remove_dir_all(&old)?;
+        }
+        fs::rename(dir, &old)?;
+    }
+    if let Err(e) = fs::rename(staged, dir) {
+        if had_dir {
+            let _ = fs::rename(&old, dir);
         }
+        return Err(e);
+    }
+    if had_dir {
+        let _ = fs::remove_dir_all(&old);
     }
+    Ok(())
+}
+
+/// Pasta irmã (ex.: "data/tasks.json" -> "data/tasks.attachments")
+fn sibling_dir(path: &str, kind: &str) -> String {
+    let stem = path.strip_suffix(".json").unwrap_or(path);
+    format!("{}.{}", stem, kind)
 }
 
 /// Estrutura para estatísticas
-#[derive(Debug)]
+///
+/// Em JSON, as contagens por categoria e prioridade viram objetos
+/// (ex.: `{"Work": 2, "Health": 0}`).
+#[derive(Debug, Serialize)]
 pub struct Statistics {
     pub total: usize,
     pub completed: usize,
     pub in_progress: usize,
     pub pending: usize,
     pub overdue: usize,
+    #[serde(serialize_with = "serialize_counts")]
     pub by_category: Vec<(Category, usize)>,
+    #[serde(serialize_with = "serialize_counts")]
     pub by_priority: Vec<(Priority, usize)>,
+    /// Tempo registrado em todas as tarefas, em minutos
+    pub tracked_minutes: i64,
+    /// Soma das estimativas das tarefas estimadas
+    pub estimate_minutes: i64,
+    /// Tempo registrado nas tarefas que têm estimativa
+    pub actual_minutes: i64,
+    /// Tarefas que passaram da estimativa
+    pub over_estimate: usize,
+    /// Pomodoros concluídos
+    pub pomodoros: usize,
+    /// Minutos de foco nos pomodoros concluídos
+    pub focus_minutes: i64,
+}
+
+/// Serializa pares (chave, contagem) como um objeto JSON
+fn serialize_counts<S, K>(counts: &[(K, usize)], serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    K: Serialize,
+{
+    serializer.collect_map(counts.iter().map(|(key, count)| (key, count)))
+}
+
+impl Statistics {
+    /// Calcula as estatísticas de um conjunto de tarefas no instante `now`
+    pub fn from_tasks(tasks: &[&Task], now: DateTime<Local>) -> Self {
+        let count = |pred: &dyn Fn(&Task) -> bool| tasks.iter().filter(|t| pred(t)).count();
+
+        let by_category = Category::all()
+            .into_iter()
+            .map(|c| (c, count(&|t| t.category == c)))
+            .collect();
+
+        let by_priority = Priority::all()
+            .into_iter()
+            .map(|p| (p, count(&|t| t.priority == p)))
+            .collect();
+
+        let estimated: Vec<&&Task> = tasks
+            .iter()
+            .filter(|t| t.estimate_minutes.is_some())
+            .collect();
+
+        Statistics {
+            total: tasks.len(),
+            completed: count(&|t| t.status == Status::Completed),
+            in_progress: count(&|t| t.status == Status::InProgress),
+            pending: count(&|t| t.status == Status::Pending),
+            overdue: count(&|t| t.is_overdue_at(&now)),
+            by_category,
+            by_priority,
+            tracked_minutes: tasks.iter().map(|t| t.time_spent(now).num_minutes()).sum(),
+            estimate_minutes: estimated
+                .iter()
+                .map(|t| t.estimate_minutes.unwrap_or(0) as i64)
+                .sum(),
+            actual_minutes: estimated
+                .iter()
+                .map(|t| t.time_spent(now).num_minutes())
+                .sum(),
+            over_estimate: estimated.iter().filter(|t| t.is_over_estimate(now)).count(),
+            pomodoros: tasks.iter().map(|t| t.pomodoros.len()).sum(),
+            focus_minutes: tasks
+                .iter()
+                .flat_map(|t| &t.pomodoros)
+                .map(|p| p.minutes as i64)
+                .sum(),
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use chrono::Local;
-
-    fn create_test_service() -> TaskService {
-        TaskService::new("data/test_tasks.json".to_string())
+    use std::cell::RefCell;
+    use std::fs;
+    use std::rc::Rc;
+
+    fn create_test_service(name: &str) -> TaskService {
+        let path = format!("data/test_tasks_{}.json", name);
+        TaskService::new(path.clone()).delete_storage().ok();
+        TaskService::new(path)
     }
 
     #[test]
     fn test_add_task() {
-        let mut service = create_test_service();
+        let mut service = create_test_service("add_task");
         let initial_count = service.list_all().len();
 
         service
@@ -242,12 +2050,12 @@ mod tests {
         assert_eq!(service.list_all().len(), initial_count + 1);
 
         // Cleanup
-        service.storage.delete().ok();
+        service.delete_storage().ok();
     }
 
     #[test]
     fn test_get_by_id() {
-        let mut service = create_test_service();
+        let mut service = create_test_service("get_by_id");
 
         let task = service
             .add_task(
@@ -266,12 +2074,12 @@ mod tests {
         assert_eq!(found.unwrap().title, "Test");
 
         // Cleanup
-        service.storage.delete().ok();
+        service.delete_storage().ok();
     }
 
     #[test]
     fn test_update_task() {
-        let mut service = create_test_service();
+        let mut service = create_test_service("update_task");
 
         let task = service
             .add_task(
@@ -301,12 +2109,12 @@ mod tests {
         assert_eq!(updated.priority, Priority::High);
 
         // Cleanup
-        service.storage.delete().ok();
+        service.delete_storage().ok();
     }
 
     #[test]
     fn test_delete_task() {
-        let mut service = create_test_service();
+        let mut service = create_test_service("delete_task");
 
         let task = service
             .add_task(
@@ -327,12 +2135,71 @@ mod tests {
         assert!(service.get_by_id(id).is_none());
 
         // Cleanup
-        service.storage.delete().ok();
+        service.delete_storage().ok();
+    }
+
+    #[test]
+    fn test_highest_id_includes_trash_and_archive() {
+        let mut service = create_test_service("highest_id");
+        assert_eq!(service.highest_id(), 0);
+
+        for title in ["A", "B", "C"] {
+            service
+                .add_task(title.to_string(), "".to_string(), Category::Work, Priority::Low, None)
+                .unwrap();
+        }
+        service.complete_task(2).unwrap();
+        service.archive_task(2).unwrap();
+        service.delete_task(3).unwrap();
+        assert_eq!(service.highest_id(), 3);
+
+        // IDs apagados de vez não contam mais
+        service.purge_task(3).unwrap();
+        assert_eq!(service.highest_id(), 2);
+
+        service.delete_storage().ok();
+    }
+
+    #[test]
+    fn test_sync_renumbers_past_trash_and_archive() {
+        let mut service = create_test_service("sync_trash_ids");
+        let mut remote = create_test_service("sync_trash_ids_remote");
+        for title in ["Fica", "Vai para a lixeira"] {
+            service
+                .add_task(title.to_string(), "".to_string(), Category::Work, Priority::Low, None)
+                .unwrap();
+        }
+        service.delete_task(2).unwrap();
+        remote
+            .add_task("De lá".to_string(), "".to_string(), Category::Work, Priority::Low, None)
+            .unwrap();
+
+        // A lixeira tem o maior ID: a tarefa remota com o mesmo ID 1 não
+        // pode ficar com o 2
+        let store =
+            SyncStore::open("data/test_tasks_sync_trash_ids_remote.json", StorageFormat::Json);
+        let report = service.sync_with(&store).unwrap();
+        assert_eq!(report.renumbered, vec![(1, 3)]);
+        assert_eq!(service.list_trash()[0].id, 2);
+
+        // O contador gravado segue depois do ID novo
+        let mut reloaded = TaskService::new("data/test_tasks_sync_trash_ids.json".to_string());
+        reloaded.delete_task(3).unwrap();
+        reloaded.purge_task(2).unwrap();
+        reloaded.purge_task(3).unwrap();
+        let id = reloaded
+            .add_task("Nova".to_string(), "".to_string(), Category::Work, Priority::Low, None)
+            .unwrap()
+            .id;
+        assert_eq!(id, 4);
+
+        service.delete_storage().ok();
+        remote.delete_storage().ok();
     }
 
     #[test]
     fn test_complete_task() {
-        let mut service = create_test_service();
+        let mut service = create_test_service("complete_task");
 
         let task = service
             .add_task(
@@ -353,12 +2220,73 @@ mod tests {
         assert!(completed.completed_at.is_some());
 
         // Cleanup
-        service.storage.delete().ok();
+        service.delete_storage().ok();
+    }
+
+    #[test]
+    fn test_from_config_defaults() {
+        let config = Config {
+            storage_path: "data/test_tasks_from_config.json".to_string(),
+            default_category: Category::Study,
+            default_priority: Priority::Low,
+            ..Config::default()
+        };
+
+        let service = TaskService::from_config(&config, None).unwrap();
+
+        assert_eq!(service.default_category(), Category::Study);
+        assert_eq!(service.default_priority(), Priority::Low);
+        assert!(!service.storage.exists());
+    }
+
+    #[test]
+    fn test_markdown_storage_format() {
+        let path = "data/test_tasks_markdown".to_string();
+        let mut service = TaskService::with_format(path.clone(), StorageFormat::Markdown);
+        service.delete_storage().ok();
+
+        let id = service
+            .add_task(
+                "Em Markdown".to_string(),
+                "## Passos\n\n1. um".to_string(),
+                Category::Work,
+                Priority::High,
+                None,
+            )
+            .unwrap()
+            .id;
+        let other = service
+            .add_task(
+                "Vai para a lixeira".to_string(),
+                String::new(),
+                Category::Other,
+                Priority::Low,
+                None,
+            )
+            .unwrap()
+            .id;
+        service.delete_task(other).unwrap();
+
+        assert!(Path::new("data/test_tasks_markdown/1.md").exists());
+        assert!(Path::new("data/test_tasks_markdown.trash/2.md").exists());
+
+        assert!(service.skipped_files().is_empty());
+
+        // Arquivo com marcas de conflito: pulado e relatado
+        let conflicted = Path::new("data/test_tasks_markdown/7.md");
+        fs::write(conflicted, "<<<<<<< HEAD\n").unwrap();
+
+        let reloaded = TaskService::with_format(path, StorageFormat::Markdown);
+        assert_eq!(reloaded.get_by_id(id).unwrap().description, "## Passos\n\n1. um");
+        assert_eq!(reloaded.list_trash().len(), 1);
+        assert_eq!(reloaded.skipped_files(), &[conflicted.to_path_buf()]);
+
+        reloaded.delete_storage().ok();
     }
 
     #[test]
     fn test_filter_by_status() {
-        let mut service = create_test_service();
+        let mut service = create_test_service("filter_by_status");
 
         service
             .add_task(
@@ -370,7 +2298,7 @@ mod tests {
             )
             .unwrap();
 
-        let task2 = service
+        let task2_id = service
             .add_task(
                 "Task 2".to_string(),
                 "Desc".to_string(),
@@ -378,9 +2306,10 @@ mod tests {
                 Priority::High,
                 None,
             )
-            .unwrap();
+            .unwrap()
+            .id;
 
-        service.complete_task(task2.id).unwrap();
+        service.complete_task(task2_id).unwrap();
 
         let pending = service.filter_by_status(Status::Pending);
         let completed = service.filter_by_status(Status::Completed);
@@ -389,12 +2318,12 @@ mod tests {
         assert_eq!(completed.len(), 1);
 
         // Cleanup
-        service.storage.delete().ok();
+        service.delete_storage().ok();
     }
 
     #[test]
     fn test_statistics() {
-        let mut service = create_test_service();
+        let mut service = create_test_service("statistics");
 
         service
             .add_task(
@@ -423,6 +2352,1179 @@ mod tests {
         assert_eq!(stats.completed, 0);
 
         // Cleanup
-        service.storage.delete().ok();
+        service.delete_storage().ok();
+    }
+
+    /// Subscriber de teste que guarda os eventos recebidos
+    struct Recorder(Rc<RefCell<Vec<TaskEvent>>>);
+
+    impl TaskSubscriber for Recorder {
+        fn on_event(&mut self, event: &TaskEvent, _at: DateTime<Local>) -> io::Result<()> {
+            self.0.borrow_mut().push(event.clone());
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_subscribers_receive_events() {
+        let mut service = create_test_service("subscribers");
+        let events = Rc::new(RefCell::new(Vec::new()));
+        service.subscribe(Recorder(Rc::clone(&events))).unwrap();
+
+        let id = service
+            .add_task(
+                "Observed".to_string(),
+                "Desc".to_string(),
+                Category::Work,
+                Priority::High,
+                None,
+            )
+            .unwrap()
+            .id;
+
+        service
+            .update_task(id, Some("Renamed".to_string()), None, None, None, None)
+            .unwrap();
+        service.start_task(id).unwrap();
+        service.start_task(id).unwrap();
+        service.complete_task(id).unwrap();
+        service.delete_task(id).unwrap();
+
+        let events = events.borrow();
+        let names: Vec<&str> = events.iter().map(|e| e.name()).collect();
+        assert_eq!(
+            names,
+            vec!["created", "updated", "started", "completed", "deleted"]
+        );
+
+        assert_eq!(events[1].before().unwrap().title, "Observed");
+        assert_eq!(events[1].after().unwrap().title, "Renamed");
+        assert_eq!(events[3].before().unwrap().status, Status::InProgress);
+        assert_eq!(events[3].after().unwrap().status, Status::Completed);
+        assert!(events.iter().all(|e| e.task_id() == id));
+
+        // Cleanup
+        service.delete_storage().ok();
+    }
+
+    #[test]
+    fn test_sync_emits_events_and_stops_on_broken_state() {
+        let remote_path = "data/test_tasks_sync_events_remote.json";
+        let mut service = create_test_service("sync_events");
+        TaskService::new(remote_path.to_string()).delete_storage().ok();
+        for title in ["Editada lá", "Excluída lá"] {
+            service
+                .add_task(title.to_string(), "".to_string(), Category::Work, Priority::Low, None)
+                .unwrap();
+        }
+        let store = SyncStore::open(remote_path, StorageFormat::Json);
+        service.sync_with(&store).unwrap();
+
+        let mut remote = TaskService::new(remote_path.to_string());
+        remote
+            .update_task(1, Some("Renomeada".to_string()), None, None, None, None)
+            .unwrap();
+        remote.delete_task(2).unwrap();
+        remote
+            .add_task("Nova lá".to_string(), "".to_string(), Category::Work, Priority::Low, None)
+            .unwrap();
+
+        let events = Rc::new(RefCell::new(Vec::new()));
+        service.subscribe(Recorder(Rc::clone(&events))).unwrap();
+        service.sync_with(&store).unwrap();
+        let events = events.borrow();
+        let names: Vec<(&str, u32)> = events.iter().map(|e| (e.name(), e.task_id())).collect();
+        assert_eq!(names, vec![("created", 3), ("updated", 1), ("deleted", 2)]);
+        assert_eq!(events[1].after().unwrap().title, "Renomeada");
+
+        // Estado ilegível não vira "nunca sincronizado"
+        std::fs::write("data/test_tasks_sync_events.sync.json", "{ quebrado").unwrap();
+        let error = service.sync_with(&store).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+
+        service.delete_storage().ok();
+        remote.delete_storage().ok();
+    }
+
+    #[test]
+    fn test_only_one_timer_runs() {
+        let mut service = create_test_service("one_timer");
+        let first = service
+            .add_task("A".to_string(), "".to_string(), Category::Work, Priority::High, None)
+            .unwrap()
+            .id;
+        let second = service
+            .add_task("B".to_string(), "".to_string(), Category::Work, Priority::Low, None)
+            .unwrap()
+            .id;
+
+        service.start_timer(first).unwrap();
+        let err = service.start_timer(second).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::AlreadyExists);
+        assert_eq!(service.running_timer().unwrap().id, first);
+        assert_eq!(service.get_by_id(first).unwrap().status, Status::InProgress);
+
+        service.stop_timer(first).unwrap();
+        assert!(service.stop_timer(first).is_err());
+        service.start_timer(second).unwrap();
+        assert_eq!(service.running_timer().unwrap().id, second);
+
+        service.delete_storage().ok();
+    }
+
+    #[test]
+    fn test_time_statistics() {
+        let mut service = create_test_service("time_statistics");
+        let first = service
+            .add_task("A".to_string(), "".to_string(), Category::Work, Priority::High, None)
+            .unwrap()
+            .id;
+        let second = service
+            .add_task("B".to_string(), "".to_string(), Category::Study, Priority::Low, None)
+            .unwrap()
+            .id;
+
+        service.set_estimate(first, Some(60)).unwrap();
+        service.log_time(first, 90).unwrap();
+        service.log_time(second, 30).unwrap();
+        assert!(service.log_time(second, 0).is_err());
+
+        let stats = service.get_statistics();
+        assert_eq!(stats.tracked_minutes, 120);
+        assert_eq!(stats.estimate_minutes, 60);
+        assert_eq!(stats.actual_minutes, 90);
+        assert_eq!(stats.over_estimate, 1);
+
+        // Tempo e estimativa sobrevivem à recarga do arquivo
+        let reloaded = TaskService::new("data/test_tasks_time_statistics.json".to_string());
+        assert_eq!(reloaded.get_by_id(first).unwrap().estimate_minutes, Some(60));
+        let spent = reloaded.get_by_id(first).unwrap().time_spent(reloaded.clock().now());
+        assert_eq!(spent.num_minutes(), 90);
+
+        service.delete_storage().ok();
+    }
+
+    #[test]
+    fn test_wip_limit_enforced_on_start() {
+        let mut service = create_test_service("wip_limit");
+        let mut ids = Vec::new();
+        for title in ["A", "B", "C"] {
+            let task = service
+                .add_task(title.to_string(), "".to_string(), Category::Work, Priority::High, None)
+                .unwrap();
+            ids.push(task.id);
+        }
+        service.set_wip_limits(WipLimits {
+            pending: None,
+            in_progress: Some(2),
+        });
+
+        service.start_task(ids[0]).unwrap();
+        service.start_task(ids[1]).unwrap();
+        // Reiniciar uma tarefa já em andamento não conta de novo
+        service.start_task(ids[1]).unwrap();
+
+        let err = service.start_task(ids[2]).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::QuotaExceeded);
+        assert!(service.start_timer(ids[2]).is_err());
+        assert_eq!(service.get_by_id(ids[2]).unwrap().status, Status::Pending);
+
+        service.complete_task(ids[0]).unwrap();
+        service.start_task(ids[2]).unwrap();
+
+        service.delete_storage().ok();
+    }
+
+    #[test]
+    fn test_ids_are_never_reused() {
+        let mut service = create_test_service("ids");
+        let first = service
+            .add_task("A".to_string(), "".to_string(), Category::Work, Priority::High, None)
+            .unwrap()
+            .clone();
+        let last = service
+            .add_task("B".to_string(), "".to_string(), Category::Work, Priority::High, None)
+            .unwrap()
+            .id;
+        assert_eq!(service.get_by_uuid(first.uuid).unwrap().id, first.id);
+
+        // Apagada de vez a de maior ID, o número continua reservado
+        service.delete_task(last).unwrap();
+        service.purge_task(last).unwrap();
+        let mut reloaded = TaskService::new("data/test_tasks_ids.json".to_string());
+        let next = reloaded
+            .add_task("C".to_string(), "".to_string(), Category::Work, Priority::High, None)
+            .unwrap()
+            .id;
+        assert_eq!(next, last + 1);
+
+        // O UUID acompanha a tarefa entre carregamentos
+        let reloaded = TaskService::new("data/test_tasks_ids.json".to_string());
+        assert_eq!(reloaded.get_by_id(first.id).unwrap().uuid, first.uuid);
+
+        reloaded.delete_storage().ok();
+    }
+
+    #[test]
+    fn test_soft_delete_restore_and_purge() {
+        let mut service = create_test_service("trash");
+        let id = service
+            .add_task("A".to_string(), "".to_string(), Category::Work, Priority::High, None)
+            .unwrap()
+            .id;
+
+        service.delete_task(id).unwrap();
+        assert!(service.get_by_id(id).is_none());
+        assert_eq!(service.list_trash().len(), 1);
+        assert!(service.list_trash()[0].deleted_at.is_some());
+
+        // A lixeira sobrevive à recarga e os IDs não são reaproveitados
+        let mut reloaded = TaskService::new("data/test_tasks_trash.json".to_string());
+        assert_eq!(reloaded.list_trash().len(), 1);
+        let next = reloaded
+            .add_task("B".to_string(), "".to_string(), Category::Work, Priority::Low, None)
+            .unwrap()
+            .id;
+        assert_ne!(next, id);
+
+        reloaded.restore_task(id).unwrap();
+        assert!(reloaded.get_by_id(id).unwrap().deleted_at.is_none());
+        assert!(reloaded.list_trash().is_empty());
+
+        reloaded.delete_task(id).unwrap();
+        assert_eq!(reloaded.purge_trash_older_than(30).unwrap(), 0);
+        reloaded.purge_task(id).unwrap();
+        assert!(reloaded.list_trash().is_empty());
+        assert!(reloaded.restore_task(id).is_err());
+
+        reloaded.delete_storage().ok();
+    }
+
+    #[test]
+    fn test_purge_expired() {
+        let mut service = create_test_service("purge_expired");
+        let id = service
+            .add_task("A".to_string(), "".to_string(), Category::Work, Priority::High, None)
+            .unwrap()
+            .id;
+        service.delete_task(id).unwrap();
+        service.trash[0].deleted_at = Some(Local::now() - Duration::days(31));
+
+        assert_eq!(service.purge_expired().unwrap(), 1);
+        assert!(service.list_trash().is_empty());
+
+        service.delete_storage().ok();
+    }
+
+    #[test]
+    fn test_archive_completed() {
+        let mut service = create_test_service("archive");
+        let done = service
+            .add_task("A".to_string(), "".to_string(), Category::Work, Priority::High, None)
+            .unwrap()
+            .id;
+        let open = service
+            .add_task("B".to_string(), "".to_string(), Category::Study, Priority::Low, None)
+            .unwrap()
+            .id;
+
+        assert!(service.archive_task(open).is_err());
+        service.complete_task(done).unwrap();
+        assert_eq!(service.archive_completed().unwrap(), 1);
+
+        assert_eq!(service.list_all().len(), 1);
+        assert_eq!(service.list_archived()[0].id, done);
+        assert_eq!(service.get_statistics().total, 1);
+        assert_eq!(service.get_statistics().completed, 0);
+        assert_eq!(service.get_statistics_with_archived().total, 2);
+        assert_eq!(service.get_statistics_with_archived().completed, 1);
+
+        service.unarchive_task(done).unwrap();
+        assert_eq!(service.list_all().len(), 2);
+        assert!(service.list_archived().is_empty());
+
+        service.delete_storage().ok();
+    }
+
+    #[test]
+    fn test_delete_and_archive_roll_back_when_save_fails() {
+        let path = "data/test_tasks_move_rollback.json";
+        let mut service = create_test_service("move_rollback");
+        let id = service
+            .add_task("A".to_string(), "".to_string(), Category::Work, Priority::High, None)
+            .unwrap()
+            .id;
+        service.complete_task(id).unwrap();
+
+        // Uma pasta no lugar do arquivo de tarefas faz o salvamento falhar
+        fs::remove_file(path).unwrap();
+        fs::create_dir_all(format!("{}/bloqueio", path)).unwrap();
+
+        assert!(service.delete_task(id).is_err());
+        assert!(service.archive_task(id).is_err());
+        assert!(service.get_by_id(id).is_some());
+        assert!(service.list_trash().is_empty());
+        assert!(service.list_archived().is_empty());
+        assert!(!Path::new("data/test_tasks_move_rollback.trash.json").exists());
+        assert!(!Path::new("data/test_tasks_move_rollback.archive.json").exists());
+
+        fs::remove_dir_all(path).unwrap();
+        service.delete_storage().ok();
+    }
+
+    #[test]
+    fn test_analytics_include_archived() {
+        let mut service = create_test_service("analytics_archive");
+        let monday = NaiveDate::from_ymd_opt(2024, 6, 3).unwrap();
+        let at = |day: NaiveDate, hour: u32| {
+            day.and_hms_opt(hour, 0, 0)
+                .unwrap()
+                .and_local_timezone(Local)
+                .earliest()
+                .unwrap()
+        };
+        let clock = Arc::new(crate::utils::ManualClock::new(at(monday, 9)));
+        service.set_clock(clock.clone());
+
+        let done = service
+            .add_task("A".to_string(), "".to_string(), Category::Work, Priority::High, None)
+            .unwrap()
+            .id;
+        service
+            .add_task("B".to_string(), "".to_string(), Category::Work, Priority::Low, None)
+            .unwrap();
+        clock.set(at(monday + Duration::days(1), 15));
+        service.complete_task(done).unwrap();
+        service.archive_completed().unwrap();
+
+        let to = monday + Duration::days(2);
+        let analytics = service.get_analytics(monday, to);
+        assert_eq!(analytics.completed_per_day[1], (monday + Duration::days(1), 1));
+        assert_eq!(analytics.completed_per_week, vec![(monday, 1)]);
+        assert_eq!(
+            analytics.burndown,
+            vec![(monday, 2), (monday + Duration::days(1), 1), (to, 1)]
+        );
+        let work = analytics
+            .lead_time_by_category
+            .iter()
+            .find(|(c, _)| *c == Category::Work)
+            .unwrap();
+        assert_eq!(work.1, Some(Duration::hours(30)));
+
+        service.delete_storage().ok();
+    }
+
+    #[test]
+    fn test_wip_limit_enforced_on_pending() {
+        let mut service = create_test_service("wip_pending");
+        let first = service
+            .add_task("A".to_string(), "".to_string(), Category::Work, Priority::High, None)
+            .unwrap()
+            .id;
+        service.quick_add("B").unwrap();
+        service.set_wip_limits(WipLimits {
+            pending: Some(2),
+            in_progress: None,
+        });
+
+        let err = service
+            .add_task("C".to_string(), "".to_string(), Category::Work, Priority::High, None)
+            .unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::QuotaExceeded);
+        assert!(service.quick_add("C").is_err());
+        assert_eq!(service.list_all().len(), 2);
+
+        // Uma tarefa que sai da coluna abre espaço, e só ela pode voltar
+        service.delete_task(first).unwrap();
+        let third = service.quick_add("C").unwrap().id;
+        assert!(service.restore_task(first).is_err());
+        assert_eq!(service.list_trash().len(), 1);
+
+        service.complete_task(third).unwrap();
+        service.restore_task(first).unwrap();
+
+        // Concluídas não têm limite ao voltar do arquivo
+        service.archive_task(third).unwrap();
+        service.unarchive_task(third).unwrap();
+        service.archive_task(third).unwrap();
+        service.set_wip_limits(WipLimits {
+            pending: Some(1),
+            in_progress: None,
+        });
+        service.unarchive_task(third).unwrap();
+
+        let mut template = Template::new("Dupla".to_string(), String::new(), Vec::new());
+        for title in ["X", "Y"] {
+            template.tasks.push(TemplateTask::new(
+                title.to_string(),
+                Category::Work,
+                Priority::Low,
+            ));
+        }
+        service.create_template(template).unwrap();
+        let err = service
+            .instantiate_template("Dupla", &HashMap::new(), Local::now().date_naive())
+            .unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::QuotaExceeded);
+        assert_eq!(service.list_all().len(), 3);
+
+        service.delete_storage().ok();
+    }
+
+    #[test]
+    fn test_bulk_reports_per_id() {
+        let mut service = create_test_service("bulk");
+        for title in ["A", "B", "C"] {
+            service
+                .add_task(title.to_string(), "".to_string(), Category::Work, Priority::Low, None)
+                .unwrap();
+        }
+
+        let report = service
+            .bulk(&[1, 2, 42], BulkAction::SetPriority(Priority::High))
+            .unwrap();
+        assert_eq!(report.succeeded(), vec![1, 2]);
+        assert_eq!(report.failed()[0].0, 42);
+        assert_eq!(report.failed()[0].1.kind(), io::ErrorKind::NotFound);
+        assert_eq!(service.get_by_id(2).unwrap().priority, Priority::High);
+        assert_eq!(service.get_by_id(3).unwrap().priority, Priority::Low);
+
+        // Tudo foi salvo
+        let reloaded = TaskService::new("data/test_tasks_bulk.json".to_string());
+        assert_eq!(reloaded.get_by_id(1).unwrap().priority, Priority::High);
+
+        let report = service
+            .bulk_matching(|t| t.priority == Priority::High, BulkAction::Delete)
+            .unwrap();
+        assert_eq!(report.succeeded(), vec![1, 2]);
+        assert_eq!(service.list_all().len(), 1);
+        assert_eq!(service.list_trash().len(), 2);
+
+        service.delete_storage().ok();
+    }
+
+    #[test]
+    fn test_bulk_start_respects_wip_limit() {
+        let mut service = create_test_service("bulk_wip");
+        for title in ["A", "B", "C"] {
+            service
+                .add_task(title.to_string(), "".to_string(), Category::Work, Priority::Low, None)
+                .unwrap();
+        }
+        service.set_wip_limits(WipLimits {
+            pending: None,
+            in_progress: Some(2),
+        });
+
+        let report = service.bulk(&[1, 2, 3], BulkAction::Start).unwrap();
+
+        assert_eq!(report.succeeded(), vec![1, 2]);
+        assert_eq!(report.failed()[0].1.kind(), io::ErrorKind::QuotaExceeded);
+        assert_eq!(service.get_by_id(3).unwrap().status, Status::Pending);
+
+        service.delete_storage().ok();
+    }
+
+    #[test]
+    fn test_quick_add() {
+        let mut service = create_test_service("quick_add");
+
+        let task = service
+            .quick_add("Revisar PR amanhã 15h #trabalho !alta")
+            .unwrap();
+        assert_eq!(task.title, "Revisar PR");
+        assert_eq!(task.category, Category::Work);
+        assert_eq!(task.priority, Priority::High);
+        assert_eq!(
+            task.due_date,
+            Some(Local::now().date_naive() + Duration::days(1))
+        );
+        assert_eq!(task.due_time, chrono::NaiveTime::from_hms_opt(15, 0, 0));
+
+        let task = service.quick_add("Ler livro").unwrap();
+        assert_eq!(task.category, Category::Other);
+        assert_eq!(task.priority, Priority::Medium);
+        assert!(task.due_date.is_none());
+
+        assert!(service.quick_add("hoje #pessoal").is_err());
+
+        service.delete_storage().ok();
+    }
+
+    #[test]
+    fn test_service_uses_its_clock() {
+        let mut service = create_test_service("clock");
+        let start = chrono::NaiveDate::from_ymd_opt(2024, 3, 10)
+            .unwrap()
+            .and_hms_opt(23, 0, 0)
+            .unwrap()
+            .and_local_timezone(Local)
+            .earliest()
+            .unwrap();
+        let clock = Arc::new(crate::utils::ManualClock::new(start));
+        service.set_clock(clock.clone());
+
+        let id = service.quick_add("Entregar hoje 23h30").unwrap().id;
+        let task = service.get_by_id(id).unwrap();
+        assert_eq!(task.created_at, start);
+        assert_eq!(task.due_date, Some(start.date_naive()));
+        let done = service
+            .add_task(
+                "Feita".to_string(),
+                "".to_string(),
+                Category::Work,
+                Priority::Low,
+                Some(start.date_naive()),
+            )
+            .unwrap()
+            .id;
+
+        // Às 23h30 ainda está em dia; um minuto depois, atrasada
+        clock.advance(Duration::minutes(30));
+        assert!(service.get_overdue().is_empty());
+        clock.advance(Duration::minutes(1));
+        assert_eq!(service.get_overdue().len(), 1);
+        assert_eq!(service.get_statistics().overdue, 1);
+
+        // Sem hora, a outra tarefa só atrasa depois da meia-noite
+        clock.advance(Duration::hours(1));
+        assert_eq!(service.get_overdue().len(), 2);
+        service.complete_task(done).unwrap();
+        assert_eq!(service.get_by_id(done).unwrap().completed_at, Some(clock.now()));
+        assert_eq!(service.get_overdue().len(), 1);
+
+        // O cronômetro em andamento conta até a hora do relógio do serviço
+        service.start_timer(id).unwrap();
+        clock.advance(Duration::minutes(25));
+        assert_eq!(service.get_statistics().tracked_minutes, 25);
+        assert_eq!(service.stop_timer(id).unwrap(), Duration::minutes(25));
+
+        service.delete_storage().ok();
+    }
+
+    #[test]
+    fn test_next_up_ranks_and_respects_blocking() {
+        let mut service = create_test_service("next_up");
+        let today = Local::now().date_naive();
+        let low = service
+            .add_task("Baixa".to_string(), "".to_string(), Category::Work, Priority::Low, None)
+            .unwrap()
+            .id;
+        let late = service
+            .add_task(
+                "Atrasada".to_string(),
+                "".to_string(),
+                Category::Work,
+                Priority::Medium,
+                Some(today - Duration::days(2)),
+            )
+            .unwrap()
+            .id;
+        let high = service
+            .add_task("Alta".to_string(), "".to_string(), Category::Work, Priority::High, None)
+            .unwrap()
+            .id;
+        let done = service
+            .add_task("Feita".to_string(), "".to_string(), Category::Work, Priority::High, None)
+            .unwrap()
+            .id;
+        service.complete_task(done).unwrap();
+
+        let ids: Vec<u32> = service.next_up(5).iter().map(|(t, _)| t.id).collect();
+        assert_eq!(ids, vec![late, high, low]);
+
+        // A atrasada depende da de prioridade baixa: cai para o fim
+        service.set_dependencies(late, vec![low]).unwrap();
+        let ids: Vec<u32> = service.next_up(2).iter().map(|(t, _)| t.id).collect();
+        assert_eq!(ids, vec![high, low]);
+
+        assert!(service.set_dependencies(late, vec![late]).is_err());
+        assert!(service.set_dependencies(late, vec![42]).is_err());
+
+        service.complete_task(low).unwrap();
+        assert!(!service.is_blocked(service.get_by_id(late).unwrap()));
+
+        service.delete_storage().ok();
+    }
+
+    #[test]
+    fn test_ownership_and_user_filters() {
+        let mut service = create_test_service("ownership");
+        service.set_current_user(Some("ana".to_string()));
+        let mine = service
+            .add_task("Minha".to_string(), "".to_string(), Category::Work, Priority::Low, None)
+            .unwrap()
+            .id;
+        let delegated = service
+            .add_task("Do Bruno".to_string(), "".to_string(), Category::Work, Priority::Low, None)
+            .unwrap()
+            .id;
+        service.assign_task(delegated, Some(" bruno ".to_string())).unwrap();
+
+        let task = service.get_by_id(delegated).unwrap();
+        assert_eq!(task.created_by.as_deref(), Some("ana"));
+        assert_eq!(task.assignee.as_deref(), Some("bruno"));
+
+        let ids = |tasks: Vec<&Task>| tasks.iter().map(|t| t.id).collect::<Vec<u32>>();
+        assert_eq!(ids(service.my_tasks()), vec![mine]);
+        assert_eq!(ids(service.assigned_by_me()), vec![delegated]);
+
+        service.set_current_user(Some("bruno".to_string()));
+        assert_eq!(ids(service.my_tasks()), vec![delegated]);
+        assert!(service.assigned_by_me().is_empty());
+
+        service.set_current_user(None);
+        assert!(service.my_tasks().is_empty());
+        let unassigned = service
+            .add_task("Sem dono".to_string(), "".to_string(), Category::Work, Priority::Low, None)
+            .unwrap()
+            .id;
+        assert_eq!(service.get_by_id(unassigned).unwrap().assignee, None);
+
+        service.complete_task(mine).unwrap();
+        let by_user = service.get_statistics_by_user();
+        let users: Vec<Option<&str>> = by_user.iter().map(|(u, _)| u.as_deref()).collect();
+        assert_eq!(users, vec![Some("ana"), Some("bruno"), None]);
+        assert_eq!(by_user[0].1.completed, 1);
+        assert_eq!(by_user[1].1.total, 1);
+
+        service.delete_storage().ok();
+    }
+
+    #[test]
+    fn test_projects() {
+        let mut service = create_test_service("projects");
+        service
+            .create_project("Casa".to_string(), "Reforma".to_string(), ProjectColor::Green)
+            .unwrap();
+        service
+            .create_project("Curso".to_string(), String::new(), ProjectColor::Blue)
+            .unwrap();
+        assert!(
+            service
+                .create_project(" casa ".to_string(), String::new(), ProjectColor::Red)
+                .is_err()
+        );
+        assert!(
+            service
+                .create_project(" ".to_string(), String::new(), ProjectColor::Red)
+                .is_err()
+        );
+
+        let loose = service
+            .add_task("Solta".to_string(), "".to_string(), Category::Other, Priority::Low, None)
+            .unwrap()
+            .id;
+        service.set_active_project(Some("casa")).unwrap();
+        let paint = service
+            .add_task("Pintar".to_string(), "".to_string(), Category::Other, Priority::Low, None)
+            .unwrap()
+            .id;
+        assert_eq!(service.get_by_id(paint).unwrap().project.as_deref(), Some("Casa"));
+        assert_eq!(service.active_tasks().len(), 1);
+        assert!(service.set_active_project(Some("Inexistente")).is_err());
+
+        // IDs continuam globais e os projetos persistem
+        service.set_active_project(Some("Curso")).unwrap();
+        let study = service
+            .add_task("Aula 1".to_string(), "".to_string(), Category::Study, Priority::Low, None)
+            .unwrap()
+            .id;
+        assert_eq!(study, paint + 1);
+        service.move_task(loose, Some("curso")).unwrap();
+        service.complete_task(study).unwrap();
+
+        let reloaded = TaskService::new("data/test_tasks_projects.json".to_string());
+        assert_eq!(reloaded.list_projects().len(), 2);
+        assert_eq!(reloaded.filter_by_project(Some("Curso")).len(), 2);
+
+        let by_project = service.get_statistics_by_project();
+        assert_eq!(by_project.len(), 2);
+        assert_eq!(by_project[1].0.as_deref(), Some("Curso"));
+        assert_eq!(by_project[1].1.total, 2);
+        assert_eq!(by_project[1].1.completed, 1);
+
+        service.delete_project("Casa").unwrap();
+        assert_eq!(service.get_by_id(paint).unwrap().project, None);
+        assert_eq!(service.list_projects().len(), 1);
+        assert_eq!(service.active_project().unwrap().name, "Curso");
+
+        service.delete_storage().ok();
+    }
+
+    #[test]
+    fn test_wip_limit_counts_the_active_project_board() {
+        let mut service = create_test_service("wip_project");
+        for name in ["Casa", "Obra"] {
+            service
+                .create_project(name.to_string(), String::new(), ProjectColor::Blue)
+                .unwrap();
+        }
+        service.set_active_project(Some("Casa")).unwrap();
+        let home = service.quick_add("Pintar").unwrap().id;
+        service.start_task(home).unwrap();
+        service.set_active_project(Some("Obra")).unwrap();
+        let first = service.quick_add("Fundação").unwrap().id;
+        let second = service.quick_add("Paredes").unwrap().id;
+        service.set_wip_limits(WipLimits {
+            pending: Some(2),
+            in_progress: Some(1),
+        });
+
+        // A tarefa em andamento de outro projeto não ocupa este quadro
+        service.start_task(first).unwrap();
+        assert!(service.start_task(second).is_err());
+        service.quick_add("Telhado").unwrap();
+        assert!(service.quick_add("Janelas").is_err());
+
+        // Trazer uma tarefa para o projeto ativo também conta
+        service.set_active_project(Some("Casa")).unwrap();
+        let other = service.quick_add("Jardim").unwrap().id;
+        service.set_active_project(Some("Obra")).unwrap();
+        let err = service.move_task(other, Some("Obra")).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::QuotaExceeded);
+        service.move_task(other, None).unwrap();
+
+        service.delete_storage().ok();
+    }
+
+    #[test]
+    fn test_views_follow_active_project() {
+        let mut service = create_test_service("project_views");
+        let clock = Arc::new(crate::utils::ManualClock::new(Local::now()));
+        service.set_clock(clock.clone());
+        let yesterday = clock.now().date_naive() - Duration::days(1);
+        service
+            .create_project("Casa".to_string(), String::new(), ProjectColor::Green)
+            .unwrap();
+        let loose = service
+            .add_task(
+                "Solta".to_string(),
+                "".to_string(),
+                Category::Work,
+                Priority::High,
+                Some(yesterday),
+            )
+            .unwrap()
+            .id;
+        service.log_time(loose, 30).unwrap();
+        service.set_active_project(Some("Casa")).unwrap();
+        let paint = service
+            .add_task(
+                "Pintar".to_string(),
+                "".to_string(),
+                Category::Personal,
+                Priority::Low,
+                Some(yesterday),
+            )
+            .unwrap()
+            .id;
+        let done = service
+            .add_task("Medir".to_string(), "".to_string(), Category::Personal, Priority::High, None)
+            .unwrap()
+            .id;
+        service.complete_task(done).unwrap();
+        service.archive_task(done).unwrap();
+
+        let ids = |tasks: Vec<&Task>| tasks.iter().map(|t| t.id).collect::<Vec<u32>>();
+        assert_eq!(ids(service.filter_by_status(Status::Pending)), vec![paint]);
+        assert!(service.filter_by_category(Category::Work).is_empty());
+        assert!(service.filter_by_priority(Priority::High).is_empty());
+        assert_eq!(ids(service.get_overdue()), vec![paint]);
+        assert_eq!(service.next_up(5).len(), 1);
+        assert_eq!(service.next_up(5)[0].0.id, paint);
+
+        let stats = service.get_statistics();
+        assert_eq!((stats.total, stats.overdue, stats.tracked_minutes), (1, 1, 0));
+        assert_eq!(service.get_statistics_with_archived().total, 2);
+        assert_eq!(service.get_statistics_by_user().len(), 1);
+        assert_eq!(service.get_statistics_by_user()[0].1.total, 1);
+
+        // Sem projeto ativo, tudo volta a aparecer
+        service.set_active_project(None).unwrap();
+        assert_eq!(service.filter_by_status(Status::Pending).len(), 2);
+        assert_eq!(service.get_overdue().len(), 2);
+        assert_eq!(service.get_statistics().tracked_minutes, 30);
+        assert_eq!(service.get_statistics_with_archived().total, 3);
+
+        service.delete_storage().ok();
+    }
+
+    #[test]
+    fn test_templates() {
+        let mut service = create_test_service("templates");
+        let today = NaiveDate::from_ymd_opt(2024, 2, 26).unwrap();
+        let now = today
+            .and_hms_opt(9, 0, 0)
+            .unwrap()
+            .and_local_timezone(Local)
+            .earliest()
+            .unwrap();
+        service.set_clock(Arc::new(crate::utils::ManualClock::new(now)));
+
+        let mut account = TemplateTask::new(
+            "Criar conta de {nome}".to_string(),
+            Category::Work,
+            Priority::High,
+        );
+        account.due_in_days = Some(0);
+        account.due_time = chrono::NaiveTime::from_hms_opt(10, 0, 0);
+        let mut intro = TemplateTask::new(
+            "Apresentar {nome}".to_string(),
+            Category::Work,
+            Priority::Medium,
+        );
+        intro.description = "Time {equipe}".to_string();
+        intro.due_in_days = Some(7);
+        intro.estimate_minutes = Some(30);
+        intro.after = vec![0];
+        service
+            .create_template(Template::new(
+                " Onboarding ".to_string(),
+                String::new(),
+                vec![account, intro],
+            ))
+            .unwrap();
+        assert_eq!(service.list_templates()[0].name, "Onboarding");
+        let duplicate = Template::new("onboarding".to_string(), String::new(), vec![]);
+        assert!(service.create_template(duplicate).is_err());
+        let mut broken = TemplateTask::new("A".to_string(), Category::Other, Priority::Low);
+        broken.after = vec![0];
+        let broken = Template::new("Quebrado".to_string(), String::new(), vec![broken]);
+        assert!(service.create_template(broken).is_err());
+
+        // Faltando uma variável, nenhuma tarefa é criada
+        let mut values = HashMap::new();
+        values.insert("nome".to_string(), "Ana".to_string());
+        assert!(service.instantiate_template("onboarding", &values, today).is_err());
+        assert!(service.list_all().is_empty());
+
+        values.insert("equipe".to_string(), "Dados".to_string());
+        let start = today + Duration::days(7);
+        let ids = service.instantiate_template("ONBOARDING", &values, start).unwrap();
+        assert_eq!(ids, vec![1, 2]);
+        let account = service.get_by_id(1).unwrap();
+        assert_eq!(account.title, "Criar conta de Ana");
+        assert_eq!(account.due_date, Some(start));
+        assert_eq!(account.due_time, chrono::NaiveTime::from_hms_opt(10, 0, 0));
+        assert_eq!(account.created_at, now);
+        let intro = service.get_by_id(2).unwrap();
+        assert_eq!(intro.description, "Time Dados");
+        assert_eq!(intro.due_date, Some(start + Duration::days(7)));
+        assert_eq!(intro.estimate_minutes, Some(30));
+        assert_eq!(intro.blocked_by, vec![1]);
+
+        // De volta a modelo: prazos relativos a hoje, dependências mantidas
+        let template = service
+            .template_from_tasks("Cópia".to_string(), String::new(), &[2, 1])
+            .unwrap();
+        assert_eq!(template.tasks[0].due_in_days, Some(14));
+        assert_eq!(template.tasks[0].after, vec![1]);
+        assert_eq!(template.tasks[1].due_in_days, Some(7));
+        assert!(service.template_from_tasks("Outra".to_string(), String::new(), &[99]).is_err());
+
+        let reloaded = TaskService::new("data/test_tasks_templates.json".to_string());
+        assert_eq!(reloaded.list_templates().len(), 2);
+
+        service.delete_template("cópia").unwrap();
+        assert!(service.delete_template("cópia").is_err());
+        assert_eq!(service.list_templates().len(), 1);
+
+        service.delete_storage().ok();
+        assert!(!Path::new("data/test_tasks_templates.templates.json").exists());
+    }
+
+    #[test]
+    fn test_template_instance_is_all_or_nothing() {
+        let mut service = create_test_service("templates_atomic");
+        let mut first = TemplateTask::new("Primeira".to_string(), Category::Work, Priority::High);
+        first.due_in_days = Some(1);
+        let mut second = TemplateTask::new("Segunda".to_string(), Category::Work, Priority::Low);
+        second.due_in_days = Some(i64::MAX);
+        service
+            .create_template(Template::new("Lote".to_string(), String::new(), vec![first, second]))
+            .unwrap();
+        let start = NaiveDate::from_ymd_opt(2024, 2, 26).unwrap();
+
+        // Prazo fora do calendário: erro, sem pânico e sem tarefas
+        assert!(service.instantiate_template("Lote", &HashMap::new(), start).is_err());
+        assert!(service.list_all().is_empty());
+
+        service.templates[0].tasks[1].due_in_days = Some(2);
+        let events = Rc::new(RefCell::new(Vec::new()));
+        service.subscribe(Recorder(events.clone())).unwrap();
+
+        // Gravação falhando: nada fica na memória nem no disco
+        std::fs::create_dir_all("data").unwrap();
+        std::fs::write("data/test_tasks_templates_atomic.blocker", "").unwrap();
+        let storage = std::mem::replace(
+            &mut service.storage,
+            Box::new(Storage::new(
+                "data/test_tasks_templates_atomic.blocker/tasks.json".to_string(),
+            )),
+        );
+        assert!(service.instantiate_template("Lote", &HashMap::new(), start).is_err());
+        assert!(service.list_all().is_empty());
+        assert!(events.borrow().is_empty());
+        std::fs::remove_file("data/test_tasks_templates_atomic.blocker").ok();
+
+        // De novo com o disco de volta: as duas de uma vez
+        service.storage = storage;
+        let ids = service.instantiate_template("Lote", &HashMap::new(), start).unwrap();
+        assert_eq!(service.list_all().len(), 2);
+        assert_eq!(events.borrow().len(), 2);
+        let reloaded = TaskService::new("data/test_tasks_templates_atomic.json".to_string());
+        assert_eq!(
+            reloaded.list_all().iter().map(|t| t.id).collect::<Vec<_>>(),
+            ids
+        );
+
+        service.delete_storage().ok();
+    }
+
+    #[test]
+    fn test_notes_are_appended_with_author() {
+        let mut service = create_test_service("notes");
+        service.set_current_user(Some("ana".to_string()));
+        let id = service
+            .add_task("Nota".to_string(), "".to_string(), Category::Work, Priority::Low, None)
+            .unwrap()
+            .id;
+
+        service.add_note(id, "Primeira").unwrap();
+        service.add_note(id, "  Segunda  ").unwrap();
+        assert!(service.add_note(id, "   ").is_err());
+        assert!(service.add_note(99, "x").is_err());
+
+        let reloaded = TaskService::new("data/test_tasks_notes.json".to_string());
+        let notes = &reloaded.get_by_id(id).unwrap().notes;
+        assert_eq!(notes.len(), 2);
+        assert_eq!(notes[1].text, "Segunda");
+        assert_eq!(notes[0].author.as_deref(), Some("ana"));
+
+        service.delete_storage().ok();
+    }
+
+    #[test]
+    fn test_attachments_are_content_addressed() {
+        let mut service = create_test_service("attachments");
+        std::fs::write("data/test_attachment_a.txt", "conteúdo").unwrap();
+        let first = service
+            .add_task("A".to_string(), "".to_string(), Category::Work, Priority::Low, None)
+            .unwrap()
+            .id;
+        let second = service
+            .add_task("B".to_string(), "".to_string(), Category::Work, Priority::Low, None)
+            .unwrap()
+            .id;
+
+        let source = Path::new("data/test_attachment_a.txt");
+        let attachment = service.attach_file(first, source).unwrap().clone();
+        assert_eq!(attachment.name, "test_attachment_a.txt");
+        assert!(service.attach_file(first, source).is_err());
+        service.attach_file(second, source).unwrap();
+
+        let path = service.attachment_path(&attachment).unwrap();
+        assert!(path.starts_with("data/test_tasks_attachments.attachments"));
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "conteúdo");
+
+        // O conteúdo compartilhado só some quando ninguém mais o usa
+        service.remove_attachment(first, 0).unwrap();
+        assert!(path.exists());
+        assert!(service.remove_attachment(first, 0).is_err());
+
+        // Corrompido: detectado ao carregar
+        std::fs::write(&path, "adulterado").unwrap();
+        let reloaded = TaskService::new("data/test_tasks_attachments.json".to_string());
+        assert_eq!(
+            reloaded.broken_attachments(),
+            &[(second, "test_attachment_a.txt".to_string())]
+        );
+        assert!(reloaded.attachment_path(&attachment).is_err());
+
+        service.delete_task(second).unwrap();
+        service.purge_task(second).unwrap();
+        assert!(!path.exists());
+
+        service.delete_storage().ok();
+        std::fs::remove_file(source).ok();
+    }
+
+    #[test]
+    fn test_attachments_with_invalid_hash_are_rejected_on_load() {
+        let path = "data/test_tasks_attachment_hash.json";
+        let victim = Path::new("data/test_tasks_attachment_hash.victim");
+        let mut service = create_test_service("attachment_hash");
+        std::fs::write(victim, "não apagar").unwrap();
+        let id = service
+            .add_task("A".to_string(), "".to_string(), Category::Work, Priority::Low, None)
+            .unwrap()
+            .id;
+        let hash = service.attach_file(id, victim).unwrap().hash.clone();
+
+        // Hash adulterado apontando para fora da pasta de anexos
+        let content = std::fs::read_to_string(path).unwrap();
+        let forged = "../test_tasks_attachment_hash.victim";
+        std::fs::write(path, content.replace(&hash, forged)).unwrap();
+
+        let mut reloaded = TaskService::new(path.to_string());
+        assert_eq!(
+            reloaded.broken_attachments(),
+            &[(id, "test_tasks_attachment_hash.victim".to_string())]
+        );
+        assert!(reloaded.get_by_id(id).unwrap().attachments.is_empty());
+
+        reloaded.delete_task(id).unwrap();
+        reloaded.purge_task(id).unwrap();
+        assert!(victim.exists());
+
+        reloaded.delete_storage().ok();
+        std::fs::remove_file(victim).ok();
+    }
+
+    #[test]
+    fn test_encrypted_storage_and_passphrase_change() {
+        let path = "data/test_tasks_encrypted.json".to_string();
+        let export = Path::new("data/test_tasks_encrypted_export.json");
+        TaskService::new(path.clone()).delete_storage().ok();
+
+        let mut service = TaskService::open(path.clone(), StorageFormat::Json, None).unwrap();
+        service
+            .add_task("Cliente ACME".to_string(), "".to_string(), Category::Work, Priority::High, None)
+            .unwrap();
+
+        // Passa a criptografar: nada legível no disco
+        service.change_passphrase("", "primeira").unwrap();
+        assert!(service.is_encrypted());
+        assert!(!std::fs::read_to_string(&path).unwrap().contains("ACME"));
+
+        // Senha errada é erro, não uma lista vazia
+        let error = TaskService::open(path.clone(), StorageFormat::Json, Some("errada")).err();
+        assert_eq!(error.map(|e| e.kind()), Some(io::ErrorKind::PermissionDenied));
+        let error = TaskService::open(path.clone(), StorageFormat::Json, None).err();
+        assert_eq!(error.map(|e| e.kind()), Some(io::ErrorKind::PermissionDenied));
+
+        assert!(service.change_passphrase("errada", "segunda").is_err());
+        service.change_passphrase("primeira", "segunda").unwrap();
+        assert!(TaskService::open(path.clone(), StorageFormat::Json, Some("primeira")).is_err());
+        let reopened = TaskService::open(path.clone(), StorageFormat::Json, Some("segunda")).unwrap();
+        assert_eq!(reopened.list_all()[0].title, "Cliente ACME");
+
+        // A exportação sai em texto puro, com lixeira, projetos e modelos
+        let mut reopened = reopened;
+        let id = reopened.list_all()[0].id;
+        reopened
+            .create_project("Vendas".to_string(), String::new(), ProjectColor::Blue)
+            .unwrap();
+        reopened
+            .template_from_tasks("cliente".to_string(), String::new(), &[id])
+            .unwrap();
+        reopened.delete_task(id).unwrap();
+        assert_eq!(reopened.export_decrypted(export).unwrap(), 1);
+        let copy = TaskService::open(
+            export.to_string_lossy().into_owned(),
+            StorageFormat::Json,
+            None,
+        )
+        .unwrap();
+        assert!(!copy.is_encrypted());
+        assert_eq!(copy.list_trash()[0].title, "Cliente ACME");
+        assert_eq!(copy.list_projects().len(), 1);
+        assert_eq!(copy.list_templates().len(), 1);
+        assert!(std::fs::read_to_string("data/test_tasks_encrypted_export.trash.json")
+            .unwrap()
+            .contains("ACME"));
+
+        reopened.delete_storage().ok();
+        copy.delete_storage().ok();
+    }
+
+    #[test]
+    fn test_passphrase_change_rolls_back_when_a_write_fails() {
+        let path = "data/test_tasks_passphrase_rollback.json".to_string();
+        let templates = "data/test_tasks_passphrase_rollback.templates.json";
+        let attachments = "data/test_tasks_passphrase_rollback.attachments";
+        let source = Path::new("data/test_tasks_passphrase_rollback.txt");
+        TaskService::new(path.clone()).delete_storage().ok();
+        fs::remove_dir_all(templates).ok();
+        fs::create_dir_all("data").unwrap();
+        fs::write(source, "contrato ACME").unwrap();
+
+        let mut service =
+            TaskService::open(path.clone(), StorageFormat::Json, Some("antiga")).unwrap();
+        let id = service
+            .add_task("Cliente".to_string(), "".to_string(), Category::Work, Priority::High, None)
+            .unwrap()
+            .id;
+        let attachment = service.attach_file(id, source).unwrap().clone();
+
+        // Uma pasta no lugar do arquivo de modelos faz a regravação falhar
+        fs::create_dir_all(templates).unwrap();
+        assert!(service.change_passphrase("antiga", "nova").is_err());
+        assert!(service.cipher().unwrap().matches("antiga"));
+        assert!(!Path::new(&format!("{}.new", attachments)).exists());
+
+        // Tudo continua abrindo com a senha antiga, anexos inclusive
+        fs::remove_dir_all(templates).unwrap();
+        assert!(TaskService::open(path.clone(), StorageFormat::Json, Some("nova")).is_err());
+        let reopened = TaskService::open(path, StorageFormat::Json, Some("antiga")).unwrap();
+        assert_eq!(reopened.list_all()[0].title, "Cliente");
+        assert!(reopened.broken_attachments().is_empty());
+        let copy = Path::new("data/test_tasks_passphrase_rollback.copy.txt");
+        reopened.save_attachment(&attachment, copy).unwrap();
+        assert_eq!(fs::read_to_string(copy).unwrap(), "contrato ACME");
+
+        service.delete_storage().ok();
+        fs::remove_file(source).ok();
+        fs::remove_file(copy).ok();
+    }
+
+    #[test]
+    fn test_encryption_covers_attachments_and_event_log() {
+        let path = "data/test_tasks_encrypted_extras.json".to_string();
+        let log = "data/test_tasks_encrypted_extras.events.jsonl";
+        let source = Path::new("data/test_tasks_encrypted_extras.txt");
+        let copy = Path::new("data/test_tasks_encrypted_extras.copy.txt");
+        TaskService::new(path.clone()).delete_storage().ok();
+        std::fs::remove_file(log).ok();
+        std::fs::create_dir_all("data").unwrap();
+        std::fs::write(source, "contrato ACME").unwrap();
+
+        let mut service = TaskService::open(path.clone(), StorageFormat::Json, None).unwrap();
+        service
+            .subscribe(crate::services::EventLogSubscriber::new(log.to_string()))
+            .unwrap();
+        let id = service
+            .add_task("Cliente".to_string(), "".to_string(), Category::Work, Priority::High, None)
+            .unwrap()
+            .id;
+        let attachment = service.attach_file(id, source).unwrap().clone();
+        let stored = service.attachment_path(&attachment).unwrap();
+
+        service.change_passphrase("", "segredo").unwrap();
+        service.add_note(id, "ligar para a ACME").unwrap();
+
+        // Nem o anexo nem o log (linhas antigas e novas) ficam legíveis
+        assert!(!std::fs::read_to_string(&stored).unwrap().contains("ACME"));
+        let events = std::fs::read_to_string(log).unwrap();
+        assert_eq!(events.lines().count(), 3);
+        assert!(!events.contains("Cliente") && !events.contains("ACME"));
+
+        // A cópia salva sai aberta, e o anexo continua íntegro ao reabrir
+        service.save_attachment(&attachment, copy).unwrap();
+        assert_eq!(std::fs::read_to_string(copy).unwrap(), "contrato ACME");
+        let reopened = TaskService::open(path, StorageFormat::Json, Some("segredo")).unwrap();
+        assert!(reopened.broken_attachments().is_empty());
+
+        // O mesmo arquivo anexado de novo é gravado já criptografado
+        service.remove_attachment(id, 0).unwrap();
+        service.attach_file(id, source).unwrap();
+        assert!(!std::fs::read_to_string(&stored).unwrap().contains("ACME"));
+
+        service.delete_storage().ok();
+        for file in [Path::new(log), source, copy] {
+            std::fs::remove_file(file).ok();
+        }
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,262 @@
+use crate::i18n::tf;
+use crate::storage::crypto::{self, Cipher};
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+/// Pasta de anexos endereçada por conteúdo
+///
+/// Cada arquivo é guardado com o SHA-256 do conteúdo como nome, então
+/// anexos iguais ocupam espaço uma vez só. O hash é sempre do conteúdo
+/// original, mesmo quando o arquivo é gravado criptografado.
+pub struct AttachmentStore {
+    dir: PathBuf,
+    /// Com cifra, o conteúdo é gravado criptografado
+    cipher: Option<Arc<Cipher>>,
+}
+
+impl AttachmentStore {
+    /// Cria uma nova instância de AttachmentStore
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        AttachmentStore {
+            dir: dir.into(),
+            cipher: None,
+        }
+    }
+
+    /// Cria uma pasta de anexos que grava o conteúdo criptografado
+    ///
+    /// Conteúdo ainda em texto puro é lido normalmente.
+    pub fn encrypted(dir: impl Into<PathBuf>, cipher: Arc<Cipher>) -> Self {
+        AttachmentStore {
+            dir: dir.into(),
+            cipher: Some(cipher),
+        }
+    }
+
+    /// Copia um arquivo para a pasta e retorna o hash e o tamanho
+    pub fn add(&self, source: &Path) -> io::Result<(String, u64)> {
+        let content = fs::read(source)?;
+        let hash = hash_bytes(&content);
+
+        if !self.path(&hash)?.exists() {
+            self.write(&hash, &content)?;
+        }
+
+        Ok((hash, content.len() as u64))
+    }
+
+    /// Caminho do conteúdo de um hash (criptografado, se a pasta for)
+    ///
+    /// Falha se `hash` não for um SHA-256 em hexadecimal minúsculo, para que
+    /// um hash adulterado no arquivo de tarefas não aponte para fora da pasta.
+    pub fn path(&self, hash: &str) -> io::Result<PathBuf> {
+        if !is_valid_hash(hash) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                tf("error.attachment_hash", &[&hash]),
+            ));
+        }
+        Ok(self.dir.join(hash))
+    }
+
+    /// Lê o conteúdo de um hash, descriptografando se preciso
+    pub fn read(&self, hash: &str) -> io::Result<Vec<u8>> {
+        let path = self.path(hash)?;
+        let content = fs::read(&path)?;
+        if !crypto::is_encrypted(&content) {
+            return Ok(content);
+        }
+        let name = path.display().to_string();
+        let cipher = self.cipher.as_ref().ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::PermissionDenied,
+                tf("error.passphrase_required", &[&name]),
+            )
+        })?;
+        cipher.decrypt(&content, &name)
+    }
+
+    /// Grava uma cópia aberta (sem criptografia) do conteúdo em `target`
+    pub fn copy_to(&self, hash: &str, target: &Path) -> io::Result<()> {
+        fs::write(target, self.read(hash)?)
+    }
+
+    /// Verifica se o conteúdo existe e ainda bate com o hash
+    pub fn verify(&self, hash: &str) -> bool {
+        self.read(hash).is_ok_and(|content| hash_bytes(&content) == hash)
+    }
+
+    /// Verifica se algum conteúdo da pasta está em texto puro
+    pub fn has_plaintext(&self) -> bool {
+        self.hashes().unwrap_or_default().iter().any(|hash| {
+            fs::read(self.dir.join(hash)).is_ok_and(|content| !crypto::is_encrypted(&content))
+        })
+    }
+
+    /// Grava de novo, com a cifra desta pasta, tudo o que `source` lê
+    ///
+    /// `source` pode ser a mesma pasta aberta com a cifra antiga (troca de
+    /// senha) ou a própria pasta (para criptografar o que está aberto).
+    pub fn rewrite_from(&self, source: &AttachmentStore) -> io::Result<()> {
+        for hash in source.hashes()? {
+            let content = source.read(&hash)?;
+            self.write(&hash, &content)?;
+        }
+        Ok(())
+    }
+
+    /// Grava o conteúdo de um hash, trocando o arquivo de uma vez só
+    fn write(&self, hash: &str, content: &[u8]) -> io::Result<()> {
+        let content = match &self.cipher {
+            Some(cipher) => cipher.encrypt(content)?,
+            None => content.to_vec(),
+        };
+        fs::create_dir_all(&self.dir)?;
+        let path = self.path(hash)?;
+        let temporary = self.dir.join(format!("{}.tmp", hash));
+        fs::write(&temporary, content)?;
+        fs::rename(&temporary, path)
+    }
+
+    /// Hashes guardados na pasta (vazia, se ela ainda não existe)
+    fn hashes(&self) -> io::Result<Vec<String>> {
+        if !self.dir.exists() {
+            return Ok(Vec::new());
+        }
+        let mut hashes = Vec::new();
+        for entry in fs::read_dir(&self.dir)? {
+            let name = entry?.file_name().to_string_lossy().into_owned();
+            if is_valid_hash(&name) {
+                hashes.push(name);
+            }
+        }
+        Ok(hashes)
+    }
+
+    /// Remove o conteúdo de um hash
+    pub fn remove(&self, hash: &str) -> io::Result<()> {
+        let path = self.path(hash)?;
+        if path.exists() {
+            fs::remove_file(path)?;
+        }
+        Ok(())
+    }
+
+    /// Apaga a pasta inteira
+    pub fn delete_all(&self) -> io::Result<()> {
+        if self.dir.exists() {
+            fs::remove_dir_all(&self.dir)?;
+        }
+        Ok(())
+    }
+}
+
+/// Verifica se `hash` é um SHA-256 em hexadecimal minúsculo
+pub fn is_valid_hash(hash: &str) -> bool {
+    hash.len() == 64 && hash.bytes().all(|b| matches!(b, b'0'..=b'9' | b'a'..=b'f'))
+}
+
+/// SHA-256 em hexadecimal
+fn hash_bytes(content: &[u8]) -> String {
+    Sha256::digest(content)
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add_verify_remove() {
+        let store = AttachmentStore::new("data/test_attachment_store");
+        store.delete_all().unwrap();
+        fs::create_dir_all("data").unwrap();
+        fs::write("data/test_attachment_source.txt", "olá").unwrap();
+
+        let (hash, size) = store
+            .add(Path::new("data/test_attachment_source.txt"))
+            .unwrap();
+        assert_eq!(size, 4);
+        assert_eq!(hash.len(), 64);
+        assert!(store.verify(&hash));
+
+        // O mesmo conteúdo gera o mesmo hash
+        let (again, _) = store
+            .add(Path::new("data/test_attachment_source.txt"))
+            .unwrap();
+        assert_eq!(again, hash);
+
+        // Conteúdo alterado falha na verificação
+        fs::write(store.path(&hash).unwrap(), "adulterado").unwrap();
+        assert!(!store.verify(&hash));
+
+        store.remove(&hash).unwrap();
+        assert!(!store.verify(&hash));
+        assert!(store.add(Path::new("data/nao_existe.txt")).is_err());
+
+        store.delete_all().unwrap();
+        fs::remove_file("data/test_attachment_source.txt").unwrap();
+    }
+
+    #[test]
+    fn test_rejects_invalid_hashes() {
+        let store = AttachmentStore::new("data/test_attachment_store_hashes");
+        fs::create_dir_all("data").unwrap();
+        fs::write("data/x", "fora da pasta").unwrap();
+
+        for hash in ["../x", "/tmp/x", &"A".repeat(64), &"0".repeat(63)] {
+            let error = store.remove(hash).unwrap_err();
+            assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+            assert!(store.read(hash).is_err());
+            assert!(store.path(hash).is_err());
+        }
+        assert!(Path::new("data/x").exists());
+        assert!(store.path(&"0".repeat(64)).is_ok());
+
+        fs::remove_file("data/x").unwrap();
+    }
+
+    #[test]
+    fn test_encrypted_store() {
+        let dir = "data/test_attachment_store_encrypted";
+        let plain = AttachmentStore::new(dir);
+        plain.delete_all().unwrap();
+        fs::create_dir_all("data").unwrap();
+        fs::write("data/test_attachment_secret.txt", "contrato ACME").unwrap();
+        let source = Path::new("data/test_attachment_secret.txt");
+
+        // Conteúdo de antes da criptografia é gravado de novo, cifrado
+        let (hash, size) = plain.add(source).unwrap();
+        assert_eq!(size, 13);
+        let cipher = Arc::new(Cipher::new("segredo"));
+        let store = AttachmentStore::encrypted(dir, Arc::clone(&cipher));
+        assert!(store.has_plaintext());
+        store.rewrite_from(&store).unwrap();
+        assert!(!store.has_plaintext());
+
+        let on_disk = fs::read(store.path(&hash).unwrap()).unwrap();
+        assert!(!String::from_utf8_lossy(&on_disk).contains("ACME"));
+        assert!(store.verify(&hash));
+        assert!(!plain.verify(&hash));
+        assert_eq!(plain.read(&hash).unwrap_err().kind(), io::ErrorKind::PermissionDenied);
+
+        // Troca de senha: lê com a cifra antiga e grava com a nova
+        let renewed = AttachmentStore::encrypted(dir, Arc::new(Cipher::new("outra")));
+        renewed.rewrite_from(&store).unwrap();
+        assert!(renewed.verify(&hash));
+        assert!(!store.verify(&hash));
+
+        let copy = Path::new("data/test_attachment_copy.txt");
+        renewed.copy_to(&hash, copy).unwrap();
+        assert_eq!(fs::read_to_string(copy).unwrap(), "contrato ACME");
+
+        store.delete_all().unwrap();
+        fs::remove_file(source).unwrap();
+        fs::remove_file(copy).unwrap();
+    }
+}
@@ -0,0 +1,337 @@
+use crate::i18n::{t, tf};
+use argon2::{Algorithm, Argon2, Params, Version};
+use chacha20poly1305::aead::{Aead, KeyInit};
+use chacha20poly1305::{Key, XChaCha20Poly1305, XNonce};
+use serde::{Deserialize, Serialize};
+use std::io;
+use std::sync::{Mutex, MutexGuard};
+use zeroize::{Zeroize, Zeroizing};
+
+/// Marca dos arquivos criptografados (campo `format` do envelope)
+const FORMAT: &str = "gerenciador_tarefas/encrypted";
+const VERSION: u32 = 1;
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 24;
+const KEY_LEN: usize = 32;
+
+/// Custo da derivação da chave (Argon2id)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+struct Cost {
+    memory_kib: u32,
+    iterations: u32,
+    parallelism: u32,
+}
+
+/// Custo máximo aceito ao ler um envelope: um arquivo adulterado não
+/// pode pedir uma derivação que trave o programa ou esgote a memória
+const MAX_COST: Cost = Cost {
+    memory_kib: 256 * 1024,
+    iterations: 16,
+    parallelism: 8,
+};
+
+impl Cost {
+    fn within(&self, max: &Cost) -> bool {
+        self.memory_kib <= max.memory_kib
+            && self.iterations <= max.iterations
+            && self.parallelism <= max.parallelism
+    }
+}
+
+impl Default for Cost {
+    fn default() -> Self {
+        Cost {
+            memory_kib: Params::DEFAULT_M_COST,
+            iterations: Params::DEFAULT_T_COST,
+            parallelism: Params::DEFAULT_P_COST,
+        }
+    }
+}
+
+/// Chave derivada, com o sal e o custo que a produziram
+type DerivedKey = ([u8; SALT_LEN], Cost, [u8; KEY_LEN]);
+
+/// Arquivo criptografado: tudo que é preciso para abrir, menos a senha
+#[derive(Serialize, Deserialize)]
+struct Envelope {
+    format: String,
+    version: u32,
+    kdf: String,
+    #[serde(flatten)]
+    cost: Cost,
+    salt: String,
+    cipher: String,
+    nonce: String,
+    data: String,
+}
+
+/// Só o que identifica um envelope
+#[derive(Deserialize)]
+struct Header {
+    format: String,
+}
+
+/// Criptografia autenticada com chave derivada de uma senha
+///
+/// A chave vem da senha por Argon2id, com um sal aleatório, e os dados são
+/// cifrados com XChaCha20-Poly1305: uma senha errada ou um arquivo
+/// adulterado falham na verificação, em vez de produzir lixo. As chaves
+/// derivadas ficam guardadas pelo sal, para não repetir a derivação (lenta
+/// de propósito) a cada leitura e gravação.
+pub struct Cipher {
+    /// Só para derivar a chave de arquivos com outro sal; apagada da
+    /// memória junto com a cifra
+    passphrase: Zeroizing<String>,
+    cost: Cost,
+    /// Chaves já derivadas (sal, custo, chave); a primeira é usada para gravar
+    keys: Mutex<Vec<DerivedKey>>,
+}
+
+impl Cipher {
+    /// Cria uma nova instância de Cipher
+    pub fn new(passphrase: &str) -> Self {
+        Cipher::with_cost(passphrase, Cost::default())
+    }
+
+    fn with_cost(passphrase: &str, cost: Cost) -> Self {
+        Cipher {
+            passphrase: Zeroizing::new(passphrase.to_string()),
+            cost,
+            keys: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Verifica se a senha é a desta instância
+    ///
+    /// Deriva a chave da senha com o sal de gravação e a compara com a
+    /// chave guardada, sem olhar a senha da instância.
+    pub fn matches(&self, passphrase: &str) -> bool {
+        let Ok((salt, cost, key)) = self.write_key() else {
+            return false;
+        };
+        derive(passphrase, &salt, cost).is_ok_and(|candidate| {
+            // Compara todos os bytes, sem parar no primeiro diferente
+            candidate.iter().zip(key.iter()).fold(0, |diff, (a, b)| diff | (a ^ b)) == 0
+        })
+    }
+
+    /// Cifra os dados num envelope JSON
+    pub fn encrypt(&self, plaintext: &[u8]) -> io::Result<Vec<u8>> {
+        serde_json::to_vec_pretty(&self.seal(plaintext)?)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+
+    /// Cifra os dados num envelope JSON de uma linha só (ex.: para logs
+    /// com um registro por linha)
+    pub fn encrypt_line(&self, plaintext: &[u8]) -> io::Result<String> {
+        serde_json::to_string(&self.seal(plaintext)?)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+
+    fn seal(&self, plaintext: &[u8]) -> io::Result<Envelope> {
+        let (salt, cost, key) = self.write_key()?;
+        let nonce: [u8; NONCE_LEN] = random()?;
+        let data = XChaCha20Poly1305::new(Key::from_slice(key.as_ref()))
+            .encrypt(XNonce::from_slice(&nonce), plaintext)
+            .map_err(|_| io::Error::other(t("error.encryption_failed")))?;
+
+        Ok(Envelope {
+            format: FORMAT.to_string(),
+            version: VERSION,
+            kdf: "argon2id".to_string(),
+            cost,
+            salt: to_hex(&salt),
+            cipher: "xchacha20poly1305".to_string(),
+            nonce: to_hex(&nonce),
+            data: to_hex(&data),
+        })
+    }
+
+    /// Abre um envelope; senha errada dá `PermissionDenied`
+    pub fn decrypt(&self, envelope: &[u8], name: &str) -> io::Result<Vec<u8>> {
+        let unsupported = || {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                tf("error.unsupported_encryption", &[&name]),
+            )
+        };
+        let envelope: Envelope = serde_json::from_slice(envelope).map_err(|_| unsupported())?;
+        if envelope.format != FORMAT
+            || envelope.version != VERSION
+            || !envelope.cost.within(&MAX_COST)
+        {
+            return Err(unsupported());
+        }
+        let salt: [u8; SALT_LEN] = from_hex(&envelope.salt).ok_or_else(unsupported)?;
+        let nonce: [u8; NONCE_LEN] = from_hex(&envelope.nonce).ok_or_else(unsupported)?;
+        let data: Vec<u8> = from_hex(&envelope.data).ok_or_else(unsupported)?;
+
+        let key = self.read_key(salt, envelope.cost)?;
+        XChaCha20Poly1305::new(Key::from_slice(key.as_ref()))
+            .decrypt(XNonce::from_slice(&nonce), data.as_slice())
+            .map_err(|_| {
+                io::Error::new(
+                    io::ErrorKind::PermissionDenied,
+                    tf("error.wrong_passphrase", &[&name]),
+                )
+            })
+    }
+
+    /// Chave para gravar: a primeira conhecida ou uma nova, com sal novo
+    fn write_key(&self) -> io::Result<([u8; SALT_LEN], Cost, Zeroizing<[u8; KEY_LEN]>)> {
+        if let Some((salt, cost, key)) = self.lock().first() {
+            return Ok((*salt, *cost, Zeroizing::new(*key)));
+        }
+        let salt = random()?;
+        let key = derive(&self.passphrase, &salt, self.cost)?;
+        self.lock().push((salt, self.cost, *key));
+        Ok((salt, self.cost, key))
+    }
+
+    /// Chave para ler um arquivo com este sal e custo
+    fn read_key(&self, salt: [u8; SALT_LEN], cost: Cost) -> io::Result<Zeroizing<[u8; KEY_LEN]>> {
+        let known = self
+            .lock()
+            .iter()
+            .find(|(s, c, _)| *s == salt && *c == cost)
+            .map(|(_, _, key)| Zeroizing::new(*key));
+        if let Some(key) = known {
+            return Ok(key);
+        }
+        let key = derive(&self.passphrase, &salt, cost)?;
+        self.lock().push((salt, cost, *key));
+        Ok(key)
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Vec<DerivedKey>> {
+        self.keys
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+/// Apaga da memória as chaves derivadas, como a senha
+impl Drop for Cipher {
+    fn drop(&mut self) {
+        for (_, _, key) in self.lock().iter_mut() {
+            key.zeroize();
+        }
+    }
+}
+
+/// Deriva a chave de uma senha com Argon2id
+fn derive(passphrase: &str, salt: &[u8], cost: Cost) -> io::Result<Zeroizing<[u8; KEY_LEN]>> {
+    let params = Params::new(
+        cost.memory_kib,
+        cost.iterations,
+        cost.parallelism,
+        Some(KEY_LEN),
+    )
+    .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.to_string()))?;
+    let mut key = Zeroizing::new([0u8; KEY_LEN]);
+    Argon2::new(Algorithm::Argon2id, Version::V0x13, params)
+        .hash_password_into(passphrase.as_bytes(), salt, key.as_mut())
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.to_string()))?;
+    Ok(key)
+}
+
+/// Verifica se o conteúdo é um envelope criptografado
+pub fn is_encrypted(content: &[u8]) -> bool {
+    serde_json::from_slice::<Header>(content).is_ok_and(|header| header.format == FORMAT)
+}
+
+fn random<const N: usize>() -> io::Result<[u8; N]> {
+    let mut bytes = [0u8; N];
+    getrandom::getrandom(&mut bytes).map_err(|e| io::Error::other(e.to_string()))?;
+    Ok(bytes)
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+fn from_hex<T: TryFrom<Vec<u8>>>(text: &str) -> Option<T> {
+    if !text.len().is_multiple_of(2) {
+        return None;
+    }
+    let bytes: Option<Vec<u8>> = (0..text.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(text.get(i..i + 2)?, 16).ok())
+        .collect();
+    T::try_from(bytes?).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Derivação barata, para os testes não demorarem
+    fn cheap(passphrase: &str) -> Cipher {
+        let cost = Cost {
+            memory_kib: 64,
+            iterations: 1,
+            parallelism: 1,
+        };
+        Cipher::with_cost(passphrase, cost)
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let cipher = cheap("segredo");
+        let envelope = cipher.encrypt(b"[{\"title\":\"Cliente ACME\"}]").unwrap();
+
+        assert!(is_encrypted(&envelope));
+        assert!(!String::from_utf8_lossy(&envelope).contains("ACME"));
+        assert_eq!(
+            cheap("segredo").decrypt(&envelope, "tasks.json").unwrap(),
+            b"[{\"title\":\"Cliente ACME\"}]"
+        );
+        assert!(!is_encrypted(b"[]"));
+    }
+
+    #[test]
+    fn test_wrong_passphrase_and_tampering() {
+        let envelope = cheap("certa").encrypt(b"dados").unwrap();
+
+        let error = cheap("errada")
+            .decrypt(&envelope, "tasks.json")
+            .unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::PermissionDenied);
+
+        let mut value: serde_json::Value = serde_json::from_slice(&envelope).unwrap();
+        let data = value["data"].as_str().unwrap().to_string();
+        let flipped = if data.starts_with('0') { "1" } else { "0" };
+        value["data"] = format!("{}{}", flipped, &data[1..]).into();
+        let tampered = serde_json::to_vec(&value).unwrap();
+        assert!(cheap("certa").decrypt(&tampered, "tasks.json").is_err());
+    }
+
+    #[test]
+    fn test_rejects_cost_above_maximum() {
+        let envelope = cheap("certa").encrypt(b"dados").unwrap();
+        let mut value: serde_json::Value = serde_json::from_slice(&envelope).unwrap();
+        value["memory_kib"] = u32::MAX.into();
+        let costly = serde_json::to_vec(&value).unwrap();
+
+        // Falha antes de derivar, sem tentar alocar a memória pedida
+        let error = cheap("certa").decrypt(&costly, "tasks.json").unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn test_matches_compares_derived_keys() {
+        let cipher = cheap("certa");
+        assert!(cipher.matches("certa"));
+        assert!(!cipher.matches("errada"));
+        assert!(!cipher.matches(""));
+
+        // Linhas de log: um envelope por linha, que abre como os arquivos
+        let line = cipher.encrypt_line(b"{\"event\":\"created\"}").unwrap();
+        assert!(!line.contains('\n'));
+        assert!(is_encrypted(line.as_bytes()));
+        assert_eq!(
+            cipher.decrypt(line.as_bytes(), "events.jsonl").unwrap(),
+            b"{\"event\":\"created\"}"
+        );
+    }
+}
@@ -1,17 +1,36 @@
+use crate::i18n::{t, tf};
+use crate::storage::crypto::{self, Cipher};
 use serde::{Deserialize, Serialize};
 use std::fs;
 use std::io;
 use std::path::Path;
+use std::sync::Arc;
 
 /// Gerenciador de armazenamento em JSON
 pub struct Storage {
     file_path: String,
+    /// Com cifra, o arquivo é gravado criptografado
+    cipher: Option<Arc<Cipher>>,
 }
 
 impl Storage {
     /// Cria uma nova instância de Storage
     pub fn new(file_path: String) -> Self {
-        Storage { file_path }
+        Storage {
+            file_path,
+            cipher: None,
+        }
+    }
+
+    /// Cria um Storage que grava o arquivo criptografado
+    ///
+    /// Um arquivo ainda em texto puro é lido normalmente e passa a ser
+    /// criptografado no próximo salvamento.
+    pub fn encrypted(file_path: String, cipher: Arc<Cipher>) -> Self {
+        Storage {
+            file_path,
+            cipher: Some(cipher),
+        }
     }
 
     /// Salva dados em arquivo JSON
@@ -23,13 +42,25 @@ impl Storage {
         let json = serde_json::to_string_pretty(data)
             .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
 
+        // Sem a senha, não sobrescreve um arquivo criptografado
+        let content = match &self.cipher {
+            Some(cipher) => cipher.encrypt(json.as_bytes())?,
+            None if self.is_encrypted() => {
+                return Err(io::Error::new(
+                    io::ErrorKind::PermissionDenied,
+                    tf("error.storage_encrypted", &[&self.file_path]),
+                ));
+            }
+            None => json.into_bytes(),
+        };
+
         // Cria o diretório se não existir
         if let Some(parent) = Path::new(&self.file_path).parent() {
             fs::create_dir_all(parent)?;
         }
 
         // Escreve no arquivo
-        fs::write(&self.file_path, json)?;
+        fs::write(&self.file_path, content)?;
 
         Ok(())
     }
@@ -43,15 +74,24 @@ impl Storage {
         if !Path::new(&self.file_path).exists() {
             return Err(io::Error::new(
                 io::ErrorKind::NotFound,
-                "Arquivo não encontrado",
+                t("error.file_not_found"),
             ));
         }
 
-        // Lê o arquivo
-        let json = fs::read_to_string(&self.file_path)?;
+        // Lê o arquivo, descriptografando se preciso
+        let mut json = fs::read(&self.file_path)?;
+        if crypto::is_encrypted(&json) {
+            let cipher = self.cipher.as_ref().ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::PermissionDenied,
+                    tf("error.passphrase_required", &[&self.file_path]),
+                )
+            })?;
+            json = cipher.decrypt(&json, &self.file_path)?;
+        }
 
         // Deserializa o JSON
-        let data = serde_json::from_str(&json)
+        let data = serde_json::from_slice(&json)
             .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
 
         Ok(data)
@@ -62,6 +102,11 @@ impl Storage {
         Path::new(&self.file_path).exists()
     }
 
+    /// Verifica se o arquivo salvo está criptografado
+    pub fn is_encrypted(&self) -> bool {
+        fs::read(&self.file_path).is_ok_and(|content| crypto::is_encrypted(&content))
+    }
+
     /// Deleta o arquivo de armazenamento
     pub fn delete(&self) -> io::Result<()> {
         if self.exists() {
@@ -112,6 +157,34 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_encrypted_storage() {
+        let path = "data/test_storage_encrypted.json".to_string();
+        let data = TestData {
+            id: 7,
+            name: "Cliente ACME".to_string(),
+        };
+        let storage = Storage::encrypted(path.clone(), Arc::new(Cipher::new("segredo")));
+        storage.save(&data).unwrap();
+
+        assert!(storage.is_encrypted());
+        assert!(!fs::read_to_string(&path).unwrap().contains("ACME"));
+        assert_eq!(storage.load::<TestData>().unwrap(), data);
+
+        // Senha errada ou ausente é erro, nunca dados vazios
+        let wrong = Storage::encrypted(path.clone(), Arc::new(Cipher::new("errada")));
+        let error = wrong.load::<TestData>().unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::PermissionDenied);
+        let plain = Storage::new(path);
+        assert_eq!(
+            plain.load::<TestData>().unwrap_err().kind(),
+            io::ErrorKind::PermissionDenied
+        );
+        assert!(plain.save(&data).is_err());
+
+        storage.delete().unwrap();
+    }
+
     #[test]
     fn test_exists() {
         let storage = Storage::new("data/test_exists.json".to_string());
@@ -0,0 +1,294 @@
+use crate::i18n::{t, tf};
+use crate::models::Task;
+use crate::storage::TaskStorage;
+use std::collections::HashSet;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Delimitador do cabeçalho TOML (front-matter)
+const FRONT_MATTER: &str = "+++";
+
+/// Armazenamento com um arquivo por tarefa, pensado para repositórios git
+///
+/// Cada tarefa vira `<pasta>/<id>.md`: os campos num cabeçalho TOML entre
+/// linhas `+++` e a descrição em Markdown logo abaixo. Editar uma tarefa
+/// mexe só no arquivo dela, e só quando algo mudou.
+pub struct MarkdownStorage {
+    dir: PathBuf,
+}
+
+impl MarkdownStorage {
+    /// Cria uma nova instância de MarkdownStorage
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        MarkdownStorage { dir: dir.into() }
+    }
+
+    /// Caminho do arquivo de uma tarefa
+    pub fn task_path(&self, id: u32) -> PathBuf {
+        self.dir.join(format!("{}.md", id))
+    }
+
+    /// Arquivos `<id>.md` da pasta, com o ID
+    fn task_files(&self) -> io::Result<Vec<(u32, PathBuf)>> {
+        let mut files = Vec::new();
+        for entry in fs::read_dir(&self.dir)? {
+            let path = entry?.path();
+            let id = path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .and_then(|name| name.strip_suffix(".md"))
+                .and_then(|id| id.parse().ok());
+            if let Some(id) = id {
+                files.push((id, path));
+            }
+        }
+        files.sort_by_key(|(id, _)| *id);
+        Ok(files)
+    }
+}
+
+impl TaskStorage for MarkdownStorage {
+    /// Arquivos que não dão para ler (ex.: com marcas de conflito do git)
+    /// são pulados, e ficam no disco até alguém resolvê-los; a lista sai
+    /// em [`TaskStorage::skipped_files`]
+    fn load_tasks(&self) -> io::Result<Vec<Task>> {
+        if !self.dir.exists() {
+            return Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                t("error.file_not_found"),
+            ));
+        }
+
+        let mut tasks = Vec::new();
+        for (_, path) in self.task_files()? {
+            if let Ok(mut task) = read_task(&path) {
+                task.fill_uuid();
+                tasks.push(task);
+            }
+        }
+        Ok(tasks)
+    }
+
+    fn save_tasks(&self, tasks: &[Task]) -> io::Result<()> {
+        fs::create_dir_all(&self.dir)?;
+
+        for task in tasks {
+            let path = self.task_path(task.id);
+            let content = to_markdown(task)?;
+            if fs::read_to_string(&path).ok().as_deref() != Some(content.as_str()) {
+                fs::write(&path, content)?;
+            }
+        }
+
+        // Remove as tarefas que saíram do conjunto, menos os arquivos
+        // ilegíveis, que podem ser um conflito ainda não resolvido
+        let ids: HashSet<u32> = tasks.iter().map(|t| t.id).collect();
+        for (id, path) in self.task_files()? {
+            if !ids.contains(&id) && read_task(&path).is_ok() {
+                fs::remove_file(path)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn exists(&self) -> bool {
+        self.dir.exists()
+    }
+
+    fn delete(&self) -> io::Result<()> {
+        if self.dir.exists() {
+            fs::remove_dir_all(&self.dir)?;
+        }
+        Ok(())
+    }
+
+    fn skipped_files(&self) -> Vec<PathBuf> {
+        self.task_files()
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|(_, path)| read_task(path).is_err())
+            .map(|(_, path)| path)
+            .collect()
+    }
+}
+
+fn read_task(path: &Path) -> io::Result<Task> {
+    from_markdown(&fs::read_to_string(path)?)
+}
+
+/// Converte uma tarefa em front-matter TOML mais a descrição em Markdown
+pub fn to_markdown(task: &Task) -> io::Result<String> {
+    let mut fields = task.clone();
+    let description = std::mem::take(&mut fields.description);
+    let header =
+        toml::to_string(&fields).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    let header = escape_delimiters(&header, task.id)?;
+
+    let mut text = format!("{}\n{}{}\n", FRONT_MATTER, header, FRONT_MATTER);
+    if !description.is_empty() {
+        text.push('\n');
+        text.push_str(&description);
+        text.push('\n');
+    }
+    Ok(text)
+}
+
+/// Troca as linhas `+++` de textos com várias linhas por `\u002B++`, para
+/// não fecharem o cabeçalho antes da hora
+///
+/// O escape só vale em strings TOML com aspas duplas; se o texto tiver
+/// saído em outro formato, a tarefa é recusada em vez de gravada quebrada.
+fn escape_delimiters(header: &str, id: u32) -> io::Result<String> {
+    if !header.lines().any(|line| line == FRONT_MATTER) {
+        return Ok(header.to_string());
+    }
+
+    let escaped: String = header
+        .split_inclusive('\n')
+        .map(|line| match line.strip_prefix(FRONT_MATTER) {
+            Some(end @ ("" | "\n")) => format!("\\u002B++{}", end),
+            _ => line.to_string(),
+        })
+        .collect();
+    let parse = |text: &str| toml::from_str::<toml::Value>(text).ok();
+    let parsed = parse(&escaped);
+    if parsed.is_none() || parsed != parse(header) {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            tf("error.front_matter_line", &[&FRONT_MATTER, &id]),
+        ));
+    }
+    Ok(escaped)
+}
+
+/// Lê uma tarefa escrita por [`to_markdown`]
+pub fn from_markdown(text: &str) -> io::Result<Task> {
+    let invalid = || {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            tf("error.invalid_task_file", &[&FRONT_MATTER]),
+        )
+    };
+
+    let text = text.replace("\r\n", "\n");
+    let rest = text
+        .strip_prefix(FRONT_MATTER)
+        .and_then(|rest| rest.strip_prefix('\n'))
+        .ok_or_else(invalid)?;
+    let end = rest
+        .find(&format!("\n{}\n", FRONT_MATTER))
+        .map(|end| (end + 1, end + FRONT_MATTER.len() + 2))
+        .or_else(|| {
+            rest.strip_suffix(&format!("\n{}", FRONT_MATTER))
+                .map(|header| (header.len() + 1, rest.len()))
+        })
+        .ok_or_else(invalid)?;
+
+    let mut task: Task = toml::from_str(&rest[..end.0])
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+    let body = &rest[end.1..];
+    let body = body.strip_prefix('\n').unwrap_or(body);
+    task.description = body.strip_suffix('\n').unwrap_or(body).to_string();
+    Ok(task)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Category, Note, Priority, TimeEntry};
+    use chrono::{Local, NaiveDate};
+
+    fn sample_task() -> Task {
+        let mut task = Task::new(
+            7,
+            "Revisar \"PR\" #42".to_string(),
+            "Checar:\n\n- **testes**\n- docs\n".to_string(),
+            Category::Work,
+            Priority::High,
+            NaiveDate::from_ymd_opt(2024, 6, 30),
+        );
+        task.time_entries.push(TimeEntry::manual(30, Local::now()));
+        task.notes
+            .push(Note::new("primeira\nsegunda linha".to_string(), None, Local::now()));
+        task.blocked_by = vec![3, 5];
+        task
+    }
+
+    #[test]
+    fn test_markdown_round_trip() {
+        let task = sample_task();
+
+        let text = to_markdown(&task).unwrap();
+        assert!(text.starts_with("+++\nid = 7\nuuid = "));
+        assert!(text.ends_with("+++\n\nChecar:\n\n- **testes**\n- docs\n\n"));
+
+        let loaded = from_markdown(&text).unwrap();
+        assert_eq!(loaded.title, task.title);
+        assert_eq!(loaded.description, task.description);
+        assert_eq!(loaded.due_date, task.due_date);
+        assert_eq!(loaded.time_entries, task.time_entries);
+        assert_eq!(loaded.notes, task.notes);
+        assert_eq!(loaded.blocked_by, task.blocked_by);
+        assert_eq!(to_markdown(&loaded).unwrap(), text);
+    }
+
+    #[test]
+    fn test_delimiter_inside_text_fields() {
+        let mut task = sample_task();
+        task.title = "+++".to_string();
+        task.description = "antes\n+++\ndepois".to_string();
+        task.notes
+            .push(Note::new("antes\n+++\ndepois".to_string(), None, Local::now()));
+
+        let text = to_markdown(&task).unwrap();
+        let loaded = from_markdown(&text).unwrap();
+        assert_eq!(loaded.title, "+++");
+        assert_eq!(loaded.description, task.description);
+        assert_eq!(loaded.notes, task.notes);
+        assert_eq!(to_markdown(&loaded).unwrap(), text);
+    }
+
+    #[test]
+    fn test_invalid_files() {
+        assert!(from_markdown("sem cabeçalho").is_err());
+        assert!(from_markdown("+++\nid = 1\n").is_err());
+        assert!(from_markdown("<<<<<<< HEAD\n+++\nid = 1\n+++\n").is_err());
+    }
+
+    #[test]
+    fn test_save_writes_one_file_per_task() {
+        let storage = MarkdownStorage::new("data/test_markdown_storage");
+        storage.delete().unwrap();
+
+        let mut task = sample_task();
+        let other = Task::new(
+            8,
+            "Outra".to_string(),
+            String::new(),
+            Category::Other,
+            Priority::Low,
+            None,
+        );
+        storage.save_tasks(&[task.clone(), other]).unwrap();
+        assert!(storage.task_path(7).exists());
+        assert!(storage.task_path(8).exists());
+
+        // Um arquivo em conflito não é carregado nem apagado
+        fs::write(storage.task_path(9), "<<<<<<< HEAD\n").unwrap();
+
+        task.title = "Revisado".to_string();
+        storage.save_tasks(&[task]).unwrap();
+
+        let loaded = storage.load_tasks().unwrap();
+        assert_eq!(loaded.len(), 1);
+        assert_eq!(loaded[0].title, "Revisado");
+        assert!(!storage.task_path(8).exists());
+        assert!(storage.task_path(9).exists());
+        assert_eq!(storage.skipped_files(), vec![storage.task_path(9)]);
+
+        storage.delete().unwrap();
+        assert!(storage.load_tasks().is_err());
+    }
+}
@@ -0,0 +1,465 @@
+use crate::i18n::tf;
+use crate::models::Task;
+use crate::storage::markdown_storage::{from_markdown, to_markdown};
+use chrono::{DateTime, Local};
+use serde_json::{Map, Value};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// Edição que a mesclagem não conseguiu conciliar sozinha
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Conflict {
+    /// O campo mudou dos dois lados, com valores diferentes
+    Field { id: u32, field: String },
+    /// A tarefa foi excluída de um lado e alterada do outro
+    DeletedAndModified { id: u32 },
+}
+
+impl fmt::Display for Conflict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Conflict::Field { id, field } => {
+                write!(f, "{}", tf("merge.conflict_field", &[id, field]))
+            }
+            Conflict::DeletedAndModified { id } => {
+                write!(f, "{}", tf("merge.conflict_deleted", &[id]))
+            }
+        }
+    }
+}
+
+/// Resultado de uma mesclagem de três vias
+#[derive(Debug)]
+pub struct MergeOutcome<T> {
+    pub merged: T,
+    /// Conflitos encontrados; nesses pontos fica a versão local
+    pub conflicts: Vec<Conflict>,
+    /// Tarefas novas dos dois lados com o mesmo ID: (ID, novo ID da remota)
+    pub renumbered: Vec<(u32, u32)>,
+}
+
+impl<T> MergeOutcome<T> {
+    pub fn is_clean(&self) -> bool {
+        self.conflicts.is_empty()
+    }
+}
+
+/// Mescla duas edições de uma tarefa a partir da versão comum
+///
+/// Campo a campo: o que mudou de um lado só é aceito; o que mudou dos
+/// dois lados vira conflito e fica com `ours`. Listas (notas, tempo,
+/// anexos, dependências) são mescladas item a item, mantendo o que foi
+/// acrescentado e tirando o que foi removido em qualquer dos lados.
+/// Os registros de mudança (`changed_at`) ficam com o mais recente.
+pub fn merge_task(
+    base_task: &Task,
+    ours_task: &Task,
+    theirs_task: &Task,
+) -> io::Result<MergeOutcome<Task>> {
+    let (base, ours, theirs) = (to_map(base_task)?, to_map(ours_task)?, to_map(theirs_task)?);
+    let id = ours.get("id").and_then(Value::as_u64).unwrap_or(0) as u32;
+
+    let keys: BTreeSet<&String> = base
+        .keys()
+        .chain(ours.keys())
+        .chain(theirs.keys())
+        .filter(|key| *key != "changed_at")
+        .collect();
+    let mut merged = Map::new();
+    let mut conflicts = Vec::new();
+
+    for key in keys {
+        let (b, o, t) = (base.get(key), ours.get(key), theirs.get(key));
+        let value = if o == t || t == b {
+            o.cloned()
+        } else if o == b {
+            t.cloned()
+        } else if let (Some(Value::Array(b)), Some(Value::Array(o)), Some(Value::Array(t))) =
+            (b.or(Some(&Value::Array(Vec::new()))), o, t)
+        {
+            Some(Value::Array(merge_lists(b, o, t)))
+        } else {
+            conflicts.push(Conflict::Field {
+                id,
+                field: key.clone(),
+            });
+            o.cloned()
+        };
+
+        if let Some(value) = value {
+            merged.insert(key.clone(), value);
+        }
+    }
+
+    let mut merged: Task = serde_json::from_value(Value::Object(merged))
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    merged.changed_at = latest_changes(ours_task, theirs_task);
+    Ok(MergeOutcome {
+        merged,
+        conflicts,
+        renumbered: Vec::new(),
+    })
+}
+
+/// Junta os registros de mudança dos dois lados, ficando com o mais recente
+pub(crate) fn latest_changes(a: &Task, b: &Task) -> BTreeMap<String, DateTime<Local>> {
+    let mut changes = a.changed_at.clone();
+    for (field, at) in &b.changed_at {
+        let entry = changes.entry(field.clone()).or_insert(*at);
+        *entry = (*entry).max(*at);
+    }
+    changes
+}
+
+/// Mescla dois conjuntos de tarefas a partir do conjunto comum, por ID
+///
+/// Tarefas novas de qualquer lado entram; excluídas de um lado e intactas
+/// do outro saem. Se os dois lados criaram tarefas diferentes com o mesmo
+/// ID, a remota ganha um ID novo.
+pub fn merge_tasks(
+    base: &[Task],
+    ours: &[Task],
+    theirs: &[Task],
+) -> io::Result<MergeOutcome<Vec<Task>>> {
+    let find = |tasks: &[Task], id: u32| tasks.iter().find(|t| t.id == id).cloned();
+    let ids: BTreeSet<u32> = base
+        .iter()
+        .chain(ours)
+        .chain(theirs)
+        .map(|t| t.id)
+        .collect();
+    let mut next_id = ids.last().copied().unwrap_or(0) + 1;
+
+    let mut merged = Vec::new();
+    let mut conflicts = Vec::new();
+    let mut renumbered = Vec::new();
+
+    for id in ids {
+        match (find(base, id), find(ours, id), find(theirs, id)) {
+            (Some(b), Some(o), Some(t)) => {
+                let outcome = merge_task(&b, &o, &t)?;
+                conflicts.extend(outcome.conflicts);
+                merged.push(outcome.merged);
+            }
+            (None, Some(o), Some(mut t)) => {
+                if !same(&o, &t)? {
+                    renumbered.push((id, next_id));
+                    t.id = next_id;
+                    next_id += 1;
+                    merged.push(t);
+                }
+                merged.push(o);
+            }
+            // Excluída de um lado e alterada do outro: fica a alterada
+            (Some(b), Some(kept), None) | (Some(b), None, Some(kept)) if !same(&b, &kept)? => {
+                conflicts.push(Conflict::DeletedAndModified { id });
+                merged.push(kept);
+            }
+            (None, Some(new), None) | (None, None, Some(new)) => merged.push(new),
+            // Excluída de um lado (ou dos dois) sem mudanças do outro: sai
+            _ => {}
+        }
+    }
+
+    merged.sort_by_key(|t| t.id);
+    Ok(MergeOutcome {
+        merged,
+        conflicts,
+        renumbered,
+    })
+}
+
+/// Mescla arquivos de tarefas para o git (`merge-file %O %A %B`)
+///
+/// Aceita tanto um arquivo Markdown de uma tarefa quanto o JSON com a
+/// lista de tarefas. O resultado é gravado em `ours`; a versão comum
+/// pode estar vazia (arquivo criado dos dois lados).
+pub fn merge_files(base: &Path, ours: &Path, theirs: &Path) -> io::Result<MergeOutcome<()>> {
+    let read = |path: &Path| fs::read_to_string(path);
+    let (base_text, ours_text, theirs_text) = (read(base)?, read(ours)?, read(theirs)?);
+
+    if ours_text.trim_start().starts_with('[') {
+        let parse = |text: &str, path: &Path| -> io::Result<Vec<Task>> {
+            if text.trim().is_empty() {
+                return Ok(Vec::new());
+            }
+            serde_json::from_str(text).map_err(|_| not_a_task_file(path))
+        };
+        let outcome = merge_tasks(
+            &parse(&base_text, base)?,
+            &parse(&ours_text, ours)?,
+            &parse(&theirs_text, theirs)?,
+        )?;
+        let json = serde_json::to_string_pretty(&outcome.merged)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        fs::write(ours, json)?;
+        return Ok(MergeOutcome {
+            merged: (),
+            conflicts: outcome.conflicts,
+            renumbered: outcome.renumbered,
+        });
+    }
+
+    let parse = |text: &str, path: &Path| from_markdown(text).map_err(|_| not_a_task_file(path));
+    let ours_task = parse(&ours_text, ours)?;
+    let theirs_task = parse(&theirs_text, theirs)?;
+    let outcome = if base_text.trim().is_empty() {
+        // Criada dos dois lados: sem versão comum, nada se mescla sozinho
+        conflicting_fields(&ours_task, &theirs_task)?
+    } else {
+        merge_task(&parse(&base_text, base)?, &ours_task, &theirs_task)?
+    };
+
+    fs::write(ours, to_markdown(&outcome.merged)?)?;
+    Ok(MergeOutcome {
+        merged: (),
+        conflicts: outcome.conflicts,
+        renumbered: Vec::new(),
+    })
+}
+
+/// Compara duas tarefas sem versão comum (ex.: o mesmo arquivo criado dos
+/// dois lados): cada campo diferente, listas inclusive, vira conflito e
+/// fica com `ours`
+fn conflicting_fields(ours_task: &Task, theirs_task: &Task) -> io::Result<MergeOutcome<Task>> {
+    let (ours, theirs) = (to_map(ours_task)?, to_map(theirs_task)?);
+    let keys: BTreeSet<&String> = ours
+        .keys()
+        .chain(theirs.keys())
+        .filter(|key| *key != "changed_at")
+        .collect();
+    let conflicts = keys
+        .into_iter()
+        .filter(|key| ours.get(*key) != theirs.get(*key))
+        .map(|key| Conflict::Field {
+            id: ours_task.id,
+            field: key.clone(),
+        })
+        .collect();
+
+    Ok(MergeOutcome {
+        merged: ours_task.clone(),
+        conflicts,
+        renumbered: Vec::new(),
+    })
+}
+
+/// Mescla de três vias de uma lista, item a item
+fn merge_lists(base: &[Value], ours: &[Value], theirs: &[Value]) -> Vec<Value> {
+    let removed = |side: &[Value]| -> Vec<Value> {
+        base.iter().filter(|v| !side.contains(v)).cloned().collect()
+    };
+    let removed: Vec<Value> = [removed(ours), removed(theirs)].concat();
+
+    let mut merged: Vec<Value> = Vec::new();
+    for value in ours.iter().chain(theirs) {
+        if !removed.contains(value) && !merged.contains(value) {
+            merged.push(value.clone());
+        }
+    }
+    merged
+}
+
+pub(crate) fn to_map(task: &Task) -> io::Result<Map<String, Value>> {
+    match serde_json::to_value(task) {
+        Ok(Value::Object(map)) => Ok(map),
+        Ok(_) => Err(io::Error::new(io::ErrorKind::InvalidData, "task")),
+        Err(e) => Err(io::Error::new(io::ErrorKind::InvalidData, e)),
+    }
+}
+
+fn same(a: &Task, b: &Task) -> io::Result<bool> {
+    Ok(to_map(a)? == to_map(b)?)
+}
+
+fn not_a_task_file(path: &Path) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        tf("error.not_a_task_file", &[&path.display()]),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Category, Note, Priority, Status};
+
+    fn task(id: u32, title: &str) -> Task {
+        Task::new(
+            id,
+            title.to_string(),
+            String::new(),
+            Category::Work,
+            Priority::Medium,
+            None,
+        )
+    }
+
+    #[test]
+    fn test_merge_task_takes_each_side_changes() {
+        let base = task(1, "Relatório");
+        let mut ours = base.clone();
+        ours.priority = Priority::High;
+        ours.notes.push(Note::new("daqui".to_string(), None, Local::now()));
+        let mut theirs = base.clone();
+        theirs.status = Status::InProgress;
+        theirs.notes.push(Note::new("de lá".to_string(), None, Local::now()));
+
+        let outcome = merge_task(&base, &ours, &theirs).unwrap();
+
+        assert!(outcome.is_clean());
+        assert_eq!(outcome.merged.priority, Priority::High);
+        assert_eq!(outcome.merged.status, Status::InProgress);
+        assert_eq!(outcome.merged.notes.len(), 2);
+    }
+
+    #[test]
+    fn test_merge_task_conflict_keeps_ours() {
+        let base = task(1, "Relatório");
+        let mut ours = base.clone();
+        ours.title = "Relatório mensal".to_string();
+        let mut theirs = base.clone();
+        theirs.title = "Relatório anual".to_string();
+        theirs.blocked_by = vec![4];
+
+        let outcome = merge_task(&base, &ours, &theirs).unwrap();
+
+        assert_eq!(
+            outcome.conflicts,
+            vec![Conflict::Field {
+                id: 1,
+                field: "title".to_string()
+            }]
+        );
+        assert_eq!(outcome.merged.title, "Relatório mensal");
+        assert_eq!(outcome.merged.blocked_by, vec![4]);
+    }
+
+    #[test]
+    fn test_merge_tasks_by_id() {
+        let base = vec![
+            task(1, "Fica"),
+            task(2, "Sai"),
+            task(3, "Editada e excluída"),
+        ];
+        let mut edited = base[2].clone();
+        edited.priority = Priority::Low;
+
+        let ours = vec![base[0].clone(), edited, task(4, "Nova daqui")];
+        let theirs = vec![base[0].clone(), base[1].clone(), task(4, "Nova de lá")];
+
+        let outcome = merge_tasks(&base, &ours, &theirs).unwrap();
+        let titles: Vec<(u32, &str)> = outcome
+            .merged
+            .iter()
+            .map(|t| (t.id, t.title.as_str()))
+            .collect();
+
+        assert_eq!(
+            titles,
+            vec![
+                (1, "Fica"),
+                (3, "Editada e excluída"),
+                (4, "Nova daqui"),
+                (5, "Nova de lá")
+            ]
+        );
+        assert_eq!(
+            outcome.conflicts,
+            vec![Conflict::DeletedAndModified { id: 3 }]
+        );
+        assert_eq!(outcome.renumbered, vec![(4, 5)]);
+    }
+
+    #[test]
+    fn test_merge_markdown_files() {
+        fs::create_dir_all("data").unwrap();
+        let paths = [
+            "data/test_merge_base.md",
+            "data/test_merge_ours.md",
+            "data/test_merge_theirs.md",
+        ];
+        let base = task(9, "Pintar");
+        let mut ours = base.clone();
+        ours.description = "Duas demãos".to_string();
+        let mut theirs = base.clone();
+        theirs.priority = Priority::High;
+
+        for (path, task) in paths.iter().zip([&base, &ours, &theirs]) {
+            fs::write(path, to_markdown(task).unwrap()).unwrap();
+        }
+        let outcome = merge_files(
+            Path::new(paths[0]),
+            Path::new(paths[1]),
+            Path::new(paths[2]),
+        )
+        .unwrap();
+
+        assert!(outcome.is_clean());
+        let merged = from_markdown(&fs::read_to_string(paths[1]).unwrap()).unwrap();
+        assert_eq!(merged.description, "Duas demãos");
+        assert_eq!(merged.priority, Priority::High);
+
+        fs::write(paths[2], "não é tarefa").unwrap();
+        assert!(
+            merge_files(
+                Path::new(paths[0]),
+                Path::new(paths[1]),
+                Path::new(paths[2])
+            )
+            .is_err()
+        );
+
+        for path in paths {
+            fs::remove_file(path).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_merge_markdown_files_without_base() {
+        fs::create_dir_all("data").unwrap();
+        let paths = [
+            "data/test_merge_nobase_base.md",
+            "data/test_merge_nobase_ours.md",
+            "data/test_merge_nobase_theirs.md",
+        ];
+        let ours = task(9, "Pintar a sala");
+        let mut theirs = task(9, "Trocar o chuveiro");
+        theirs.priority = Priority::High;
+        theirs.notes.push(Note::new("de lá".to_string(), None, Local::now()));
+
+        fs::write(paths[0], "").unwrap();
+        fs::write(paths[1], to_markdown(&ours).unwrap()).unwrap();
+        fs::write(paths[2], to_markdown(&theirs).unwrap()).unwrap();
+        let outcome = merge_files(
+            Path::new(paths[0]),
+            Path::new(paths[1]),
+            Path::new(paths[2]),
+        )
+        .unwrap();
+
+        // Tarefas diferentes não se fundem: tudo que difere é conflito
+        let fields: Vec<String> = outcome
+            .conflicts
+            .iter()
+            .map(|c| match c {
+                Conflict::Field { field, .. } => field.clone(),
+                Conflict::DeletedAndModified { .. } => String::new(),
+            })
+            .collect();
+        for field in ["title", "priority", "notes", "uuid"] {
+            assert!(fields.contains(&field.to_string()), "{}", field);
+        }
+        let merged = from_markdown(&fs::read_to_string(paths[1]).unwrap()).unwrap();
+        assert_eq!(merged.title, "Pintar a sala");
+        assert_eq!(merged.priority, Priority::Medium);
+        assert!(merged.notes.is_empty());
+
+        for path in paths {
+            fs::remove_file(path).unwrap();
+        }
+    }
+}
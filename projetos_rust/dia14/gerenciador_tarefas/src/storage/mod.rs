@@ -1,4 +1,13 @@
 // src/storage/mod.rs
 pub mod json_storage;
+pub mod crypto;
+pub mod attachment_store;
+pub mod task_storage;
+pub mod markdown_storage;
+pub mod merge;
 
-pub use json_storage::Storage;
\ No newline at end of file
+pub use json_storage::Storage;
+pub use crypto::Cipher;
+pub use attachment_store::AttachmentStore;
+pub use task_storage::{StorageFormat, TaskStorage};
+pub use markdown_storage::MarkdownStorage;
@@ -0,0 +1,99 @@
+use crate::models::Task;
+use crate::storage::{MarkdownStorage, Storage};
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Formato em que as tarefas ficam no disco
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StorageFormat {
+    /// Um arquivo JSON com todas as tarefas
+    Json,
+    /// Uma pasta com um arquivo Markdown por tarefa
+    Markdown,
+}
+
+impl StorageFormat {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            StorageFormat::Json => "json",
+            StorageFormat::Markdown => "markdown",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<StorageFormat> {
+        match value.trim().to_lowercase().as_str() {
+            "json" => Some(StorageFormat::Json),
+            "markdown" | "md" => Some(StorageFormat::Markdown),
+            _ => None,
+        }
+    }
+
+    /// Formato de um armazenamento que já existe: pasta é Markdown,
+    /// arquivo é JSON
+    pub fn detect(path: &Path) -> Option<StorageFormat> {
+        if path.is_dir() {
+            Some(StorageFormat::Markdown)
+        } else if path.is_file() {
+            Some(StorageFormat::Json)
+        } else {
+            None
+        }
+    }
+
+    /// Abre o armazenamento de tarefas em `path` (arquivo ou pasta)
+    pub fn open(&self, path: String) -> Box<dyn TaskStorage> {
+        match self {
+            StorageFormat::Json => Box::new(Storage::new(path)),
+            StorageFormat::Markdown => Box::new(MarkdownStorage::new(path)),
+        }
+    }
+}
+
+/// Onde um conjunto de tarefas é guardado
+pub trait TaskStorage {
+    /// Carrega as tarefas (erro se ainda não houver nada salvo)
+    fn load_tasks(&self) -> io::Result<Vec<Task>>;
+
+    /// Salva as tarefas, substituindo o que havia
+    fn save_tasks(&self, tasks: &[Task]) -> io::Result<()>;
+
+    /// Verifica se há algo salvo
+    fn exists(&self) -> bool;
+
+    /// Apaga o que estiver salvo
+    fn delete(&self) -> io::Result<()>;
+
+    /// Verifica se o que está salvo está criptografado
+    fn is_encrypted(&self) -> bool {
+        false
+    }
+
+    /// Arquivos que [`TaskStorage::load_tasks`] pulou por não dar para ler
+    fn skipped_files(&self) -> Vec<PathBuf> {
+        Vec::new()
+    }
+}
+
+impl TaskStorage for Storage {
+    fn load_tasks(&self) -> io::Result<Vec<Task>> {
+        let mut tasks: Vec<Task> = self.load()?;
+        tasks.iter_mut().for_each(Task::fill_uuid);
+        Ok(tasks)
+    }
+
+    fn save_tasks(&self, tasks: &[Task]) -> io::Result<()> {
+        self.save(&tasks)
+    }
+
+    fn exists(&self) -> bool {
+        Storage::exists(self)
+    }
+
+    fn delete(&self) -> io::Result<()> {
+        Storage::delete(self)
+    }
+
+    fn is_encrypted(&self) -> bool {
+        Storage::is_encrypted(self)
+    }
+}
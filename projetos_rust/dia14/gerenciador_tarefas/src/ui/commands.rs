@@ -0,0 +1,548 @@
+use crate::i18n::{self, tf};
+use crate::models::{Category, Priority, Status, Task};
+use crate::services::{SyncReport, SyncStore, TaskService};
+use crate::storage::merge::{self, Conflict};
+use crate::storage::StorageFormat;
+use crate::ui::json::{
+    self, AnalyticsRecord, GroupRecord, NextUpRecord, OutputFormat, ProjectRecord,
+    StatisticsRecord, TaskRecord,
+};
+use crate::ui::output;
+use chrono::{DateTime, Duration, Local, NaiveDate};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use uuid::Uuid;
+
+/// Quantas tarefas `next` mostra quando não se informa o número
+const DEFAULT_NEXT_UP: usize = 5;
+/// Dias do período padrão de `analytics` (as últimas duas semanas)
+const DEFAULT_ANALYTICS_DAYS: i64 = 14;
+
+/// De onde `list` tira as tarefas
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Source {
+    #[default]
+    Active,
+    Trash,
+    Archive,
+}
+
+/// Filtros de `list`; todos precisam bater
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct TaskQuery {
+    pub source: Source,
+    pub status: Option<Status>,
+    pub category: Option<Category>,
+    pub priority: Option<Priority>,
+    /// `Some(None)` = só tarefas sem projeto
+    pub project: Option<Option<String>>,
+    pub assignee: Option<String>,
+    pub overdue: bool,
+}
+
+impl TaskQuery {
+    fn matches(&self, task: &Task, now: &DateTime<Local>) -> bool {
+        self.status.is_none_or(|s| task.status == s)
+            && self.category.is_none_or(|c| task.category == c)
+            && self.priority.is_none_or(|p| task.priority == p)
+            && self
+                .project
+                .as_ref()
+                .is_none_or(|p| task.project.as_deref() == p.as_deref())
+            && self
+                .assignee
+                .as_ref()
+                .is_none_or(|a| task.assignee.as_deref() == Some(a.as_str()))
+            && (!self.overdue || task.is_overdue_at(now))
+    }
+}
+
+/// Tarefa indicada na linha de comando: o número curto ou o UUID
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TaskRef {
+    Id(u32),
+    Uuid(Uuid),
+}
+
+impl TaskRef {
+    /// Aceita `12`, `#12` ou um UUID
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim_start_matches('#').parse() {
+            Ok(id) => Some(TaskRef::Id(id)),
+            Err(_) => Uuid::parse_str(value).ok().map(TaskRef::Uuid),
+        }
+    }
+
+    /// Busca a tarefa entre as ativas
+    pub fn find<'a>(&self, service: &'a TaskService) -> Option<&'a Task> {
+        match self {
+            TaskRef::Id(id) => service.get_by_id(*id),
+            TaskRef::Uuid(uuid) => service.get_by_uuid(*uuid),
+        }
+    }
+}
+
+/// Comando executado sem abrir o menu
+///
+/// Quase todos só leem e respondem em texto, JSON ou JSON Lines;
+/// `merge-file`, `sync` e `decrypt-export` gravam arquivos e só têm texto.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+    /// `list [--status S] [--category C] [--priority P] [--project NOME |
+    /// --no-project] [--assignee U] [--overdue] [--trash | --archived]`
+    List(TaskQuery),
+    /// `show <id | uuid>`
+    Show(TaskRef),
+    /// `stats [--archived]`
+    Stats { archived: bool },
+    /// `next [N]`
+    Next(usize),
+    /// `projects`
+    Projects,
+    /// `analytics [--from AAAA-MM-DD] [--to AAAA-MM-DD]`; sem datas, as
+    /// últimas duas semanas no relógio do serviço
+    Analytics {
+        from: Option<NaiveDate>,
+        to: Option<NaiveDate>,
+    },
+    /// `merge-file <base> <ours> <theirs>`: driver de merge do git
+    /// (`%O %A %B`), grava o resultado em `ours`
+    MergeFile {
+        base: PathBuf,
+        ours: PathBuf,
+        theirs: PathBuf,
+    },
+    /// `sync <caminho>`: sincroniza com outro arquivo ou pasta de tarefas
+    Sync(PathBuf),
+    /// `decrypt-export <arquivo>`: cópia de todos os dados em JSON puro
+    DecryptExport(PathBuf),
+}
+
+impl Command {
+    /// Verifica se o comando grava arquivos (e por isso só responde em texto)
+    pub fn writes(&self) -> bool {
+        matches!(
+            self,
+            Command::MergeFile { .. } | Command::Sync(_) | Command::DecryptExport(_)
+        )
+    }
+
+    /// Interpreta o comando e seus argumentos (ex.: `["list", "--overdue"]`)
+    pub fn parse(args: &[String]) -> io::Result<Command> {
+        let Some((name, rest)) = args.split_first() else {
+            return Err(invalid(tf("command.unknown", &[&""])));
+        };
+        let mut rest = rest.iter();
+        let bad = |arg: &str| invalid(tf("command.invalid_argument", &[&name, &arg]));
+
+        match name.as_str() {
+            "list" => {
+                let mut query = TaskQuery::default();
+                while let Some(arg) = rest.next() {
+                    let mut value = || {
+                        rest.next()
+                            .ok_or_else(|| invalid(tf("config.missing_value", &[arg])))
+                    };
+                    match arg.as_str() {
+                        "--status" => {
+                            let v = value()?;
+                            query.status = Some(Status::parse(v).ok_or_else(|| bad(v))?);
+                        }
+                        "--category" => {
+                            let v = value()?;
+                            query.category = Some(Category::parse(v).ok_or_else(|| bad(v))?);
+                        }
+                        "--priority" => {
+                            let v = value()?;
+                            query.priority = Some(Priority::parse(v).ok_or_else(|| bad(v))?);
+                        }
+                        "--project" => query.project = Some(Some(value()?.clone())),
+                        "--no-project" => query.project = Some(None),
+                        "--assignee" => query.assignee = Some(value()?.clone()),
+                        "--overdue" => query.overdue = true,
+                        "--trash" => query.source = Source::Trash,
+                        "--archived" => query.source = Source::Archive,
+                        _ => return Err(bad(arg)),
+                    }
+                }
+                Ok(Command::List(query))
+            }
+            "show" => {
+                let id = rest.next().ok_or_else(|| bad(""))?;
+                let task = TaskRef::parse(id).ok_or_else(|| bad(id))?;
+                match rest.next() {
+                    Some(extra) => Err(bad(extra)),
+                    None => Ok(Command::Show(task)),
+                }
+            }
+            "stats" => match rest.next().map(String::as_str) {
+                None => Ok(Command::Stats { archived: false }),
+                Some("--archived") if rest.next().is_none() => {
+                    Ok(Command::Stats { archived: true })
+                }
+                Some(arg) => Err(bad(arg)),
+            },
+            "next" => {
+                let count = match rest.next() {
+                    Some(n) => n.parse().map_err(|_| bad(n))?,
+                    None => DEFAULT_NEXT_UP,
+                };
+                match rest.next() {
+                    Some(extra) => Err(bad(extra)),
+                    None => Ok(Command::Next(count)),
+                }
+            }
+            "projects" => match rest.next() {
+                Some(extra) => Err(bad(extra)),
+                None => Ok(Command::Projects),
+            },
+            "analytics" => {
+                let (mut from, mut to) = (None, None);
+                while let Some(arg) = rest.next() {
+                    let date = rest
+                        .next()
+                        .ok_or_else(|| invalid(tf("config.missing_value", &[arg])))?;
+                    let date = parse_date(date).ok_or_else(|| bad(date))?;
+                    match arg.as_str() {
+                        "--from" => from = Some(date),
+                        "--to" => to = Some(date),
+                        _ => return Err(bad(arg)),
+                    }
+                }
+                if let (Some(from), Some(to)) = (from, to)
+                    && from > to
+                {
+                    return Err(bad(&format!("{} > {}", from, to)));
+                }
+                Ok(Command::Analytics { from, to })
+            }
+            "merge-file" => {
+                let mut path = || rest.next().map(PathBuf::from).ok_or_else(|| bad(""));
+                let (base, ours, theirs) = (path()?, path()?, path()?);
+                match rest.next() {
+                    Some(extra) => Err(bad(extra)),
+                    None => Ok(Command::MergeFile { base, ours, theirs }),
+                }
+            }
+            "sync" => {
+                let path = rest.next().ok_or_else(|| bad(""))?;
+                match rest.next() {
+                    Some(extra) => Err(bad(extra)),
+                    None => Ok(Command::Sync(PathBuf::from(path))),
+                }
+            }
+            "decrypt-export" => {
+                let path = rest.next().ok_or_else(|| bad(""))?;
+                match rest.next() {
+                    Some(extra) => Err(bad(extra)),
+                    None => Ok(Command::DecryptExport(PathBuf::from(path))),
+                }
+            }
+            _ => Err(invalid(tf("command.unknown", &[name]))),
+        }
+    }
+}
+
+/// Aceita datas ISO (2024-06-30) ou no formato do idioma
+fn parse_date(value: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .or_else(|_| NaiveDate::parse_from_str(value, &i18n::date_format()))
+        .ok()
+}
+
+/// Executa um comando, escrevendo no formato pedido
+///
+/// Os comandos que gravam arquivos recusam JSON em vez de ignorá-lo.
+pub fn run<W: Write>(
+    service: &mut TaskService,
+    command: &Command,
+    format: OutputFormat,
+    out: &mut W,
+) -> io::Result<()> {
+    if command.writes() && format != OutputFormat::Text {
+        return Err(invalid(i18n::t("command.text_only").to_string()));
+    }
+
+    match command {
+        Command::List(query) => {
+            let source: Vec<&Task> = match query.source {
+                Source::Active => service.list_all().iter().collect(),
+                Source::Trash => service.list_trash().iter().collect(),
+                Source::Archive => service.list_archived().iter().collect(),
+            };
+            let now = service.clock().now();
+            let tasks: Vec<&Task> = source
+                .into_iter()
+                .filter(|t| query.matches(t, &now))
+                .collect();
+
+            if format == OutputFormat::Text {
+                return output::print_task_list(out, &tasks, now);
+            }
+            let records: Vec<TaskRecord> =
+                tasks.iter().map(|t| TaskRecord::new(t, service)).collect();
+            json::write_list(out, &records, format)
+        }
+        Command::Show(task) => {
+            let task = task.find(service).ok_or_else(|| {
+                io::Error::new(io::ErrorKind::NotFound, i18n::t("error.task_not_found"))
+            })?;
+
+            if format == OutputFormat::Text {
+                return output::print_task(out, task, service.clock().now());
+            }
+            json::write_one(out, &TaskRecord::new(task, service), format)
+        }
+        Command::Stats { archived } => {
+            let overall = if *archived {
+                service.get_statistics_with_archived()
+            } else {
+                service.get_statistics()
+            };
+            let by_user = service.get_statistics_by_user();
+            let by_project = service.get_statistics_by_project();
+
+            if format == OutputFormat::Text {
+                output::print_statistics(out, &overall)?;
+                output::print_user_statistics(out, &by_user)?;
+                return output::print_project_statistics(out, &by_project);
+            }
+            let record = StatisticsRecord {
+                overall: &overall,
+                by_user: GroupRecord::from_groups(&by_user),
+                by_project: GroupRecord::from_groups(&by_project),
+            };
+            json::write_one(out, &record, format)
+        }
+        Command::Next(count) => {
+            let ranked = service.next_up(*count);
+
+            if format == OutputFormat::Text {
+                return output::print_next_up(out, &ranked);
+            }
+            let records: Vec<NextUpRecord> = ranked
+                .iter()
+                .enumerate()
+                .map(|(i, (task, score))| NextUpRecord::new(i + 1, task, score, service))
+                .collect();
+            json::write_list(out, &records, format)
+        }
+        Command::Projects => {
+            let active = service.active_project().map(|p| p.name.as_str());
+            let projects: Vec<_> = service
+                .list_projects()
+                .iter()
+                .map(|p| (p, service.filter_by_project(Some(&p.name)).len()))
+                .collect();
+
+            if format == OutputFormat::Text {
+                return output::print_projects(out, &projects, active);
+            }
+            let records: Vec<ProjectRecord> = projects
+                .iter()
+                .map(|(p, count)| ProjectRecord::new(p, *count, active == Some(p.name.as_str())))
+                .collect();
+            json::write_list(out, &records, format)
+        }
+        Command::Analytics { from, to } => {
+            let to = to.unwrap_or_else(|| service.clock().now().date_naive());
+            let from = from.unwrap_or(to - Duration::days(DEFAULT_ANALYTICS_DAYS - 1));
+            if from > to {
+                let range = format!("{} > {}", from, to);
+                return Err(invalid(tf("command.invalid_argument", &[&"analytics", &range])));
+            }
+            let analytics = service.get_analytics(from, to);
+
+            if format == OutputFormat::Text {
+                return output::print_analytics(out, &analytics);
+            }
+            json::write_one(out, &AnalyticsRecord::from(&analytics), format)
+        }
+        Command::MergeFile { base, ours, theirs } => {
+            merge_file(base, ours, theirs, out).map(|_| ())
+        }
+        Command::Sync(path) => {
+            // O outro lado, se ainda não existe, é criado no mesmo formato
+            // e, se as tarefas daqui são criptografadas, com a mesma senha
+            let format = StorageFormat::detect(path).unwrap_or(service.storage_format());
+            if service.is_encrypted() && format != StorageFormat::Json {
+                return Err(invalid(i18n::t("error.encryption_needs_json").to_string()));
+            }
+            let remote =
+                SyncStore::with_cipher(&path.to_string_lossy(), format, service.cipher().cloned());
+            let report = service.sync_with(&remote)?;
+            print_sync_report(out, &report)
+        }
+        Command::DecryptExport(path) => {
+            let count = service.export_decrypted(path)?;
+            writeln!(out, "{}", tf("menu.exported", &[&count, &path.display()]))
+        }
+    }
+}
+
+fn print_sync_report<W: Write>(out: &mut W, report: &SyncReport) -> io::Result<()> {
+    writeln!(
+        out,
+        "{}",
+        tf(
+            "sync.summary",
+            &[
+                &report.received.len(),
+                &report.sent.len(),
+                &report.updated.len(),
+                &report.deleted.len()
+            ]
+        )
+    )?;
+    for (id, new_id) in &report.renumbered {
+        writeln!(out, "{}", tf("merge.renumbered", &[id, new_id]))?;
+    }
+    for conflict in &report.conflicts {
+        print_conflict(out, conflict, "sync.latest_wins")?;
+    }
+    Ok(())
+}
+
+/// Conflito de campo vem com o critério usado para resolvê-lo
+fn print_conflict<W: Write>(out: &mut W, conflict: &Conflict, resolution: &'static str) -> io::Result<()> {
+    match conflict {
+        Conflict::Field { .. } => writeln!(out, "{} {}", conflict, i18n::t(resolution)),
+        Conflict::DeletedAndModified { .. } => writeln!(out, "{}", conflict),
+    }
+}
+
+/// Mescla três versões de um arquivo de tarefas e relata o que não deu
+/// para conciliar; devolve `false` se sobrou conflito
+pub fn merge_file<W: Write>(
+    base: &Path,
+    ours: &Path,
+    theirs: &Path,
+    out: &mut W,
+) -> io::Result<bool> {
+    let outcome = merge::merge_files(base, ours, theirs)?;
+    for (id, new_id) in &outcome.renumbered {
+        writeln!(out, "{}", tf("merge.renumbered", &[id, new_id]))?;
+    }
+    for conflict in &outcome.conflicts {
+        print_conflict(out, conflict, "merge.kept_local")?;
+    }
+    Ok(outcome.is_clean())
+}
+
+fn invalid(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(items: &[&str]) -> Vec<String> {
+        items.iter().map(|a| a.to_string()).collect()
+    }
+
+    #[test]
+    fn test_parse_commands() {
+        let query = TaskQuery {
+            status: Some(Status::InProgress),
+            priority: Some(Priority::High),
+            project: Some(None),
+            overdue: true,
+            ..TaskQuery::default()
+        };
+        assert_eq!(
+            Command::parse(&args(&[
+                "list",
+                "--status",
+                "em andamento",
+                "--priority",
+                "alta",
+                "--no-project",
+                "--overdue"
+            ]))
+            .unwrap(),
+            Command::List(query)
+        );
+        assert_eq!(
+            Command::parse(&args(&["show", "#12"])).unwrap(),
+            Command::Show(TaskRef::Id(12))
+        );
+        let uuid = "5f0c6d4e-8b1a-4c2f-9e3d-7a6b5c4d3e2f";
+        assert_eq!(
+            Command::parse(&args(&["show", uuid])).unwrap(),
+            Command::Show(TaskRef::Uuid(Uuid::parse_str(uuid).unwrap()))
+        );
+        assert_eq!(
+            Command::parse(&args(&["next"])).unwrap(),
+            Command::Next(DEFAULT_NEXT_UP)
+        );
+        assert_eq!(
+            Command::parse(&args(&[
+                "analytics",
+                "--from",
+                "2024-06-01",
+                "--to",
+                "2024-06-30"
+            ]))
+            .unwrap(),
+            Command::Analytics {
+                from: NaiveDate::from_ymd_opt(2024, 6, 1),
+                to: NaiveDate::from_ymd_opt(2024, 6, 30),
+            }
+        );
+        assert_eq!(
+            Command::parse(&args(&["analytics"])).unwrap(),
+            Command::Analytics {
+                from: None,
+                to: None
+            }
+        );
+        assert_eq!(
+            Command::parse(&args(&["merge-file", "%O", "%A", "%B"])).unwrap(),
+            Command::MergeFile {
+                base: PathBuf::from("%O"),
+                ours: PathBuf::from("%A"),
+                theirs: PathBuf::from("%B"),
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(Command::parse(&args(&["listar"])).is_err());
+        assert!(Command::parse(&args(&["list", "--status", "feito"])).is_err());
+        assert!(Command::parse(&args(&["list", "--priority"])).is_err());
+        assert!(Command::parse(&args(&["show", "abc"])).is_err());
+        assert!(Command::parse(&args(&["stats", "--x"])).is_err());
+        assert!(Command::parse(&args(&["merge-file", "a", "b"])).is_err());
+        assert!(Command::parse(&args(&["sync"])).is_err());
+        assert!(Command::parse(&args(&["decrypt-export"])).is_err());
+    }
+
+    #[test]
+    fn test_writing_commands_reject_json() {
+        let path = "data/test_commands_text_only.json".to_string();
+        let mut service = TaskService::new(path.clone());
+        let target = "data/test_commands_text_only_export.json";
+        let export = Command::parse(&args(&["decrypt-export", target])).unwrap();
+        assert!(export.writes());
+        assert!(!Command::parse(&args(&["list"])).unwrap().writes());
+
+        for format in [OutputFormat::Json, OutputFormat::JsonLines] {
+            let error = run(&mut service, &export, format, &mut Vec::new()).unwrap_err();
+            assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+        }
+        assert!(!Path::new(target).exists());
+        service.delete_storage().ok();
+        assert!(
+            Command::parse(&args(&[
+                "analytics",
+                "--from",
+                "2024-07-01",
+                "--to",
+                "2024-06-01"
+            ]))
+            .is_err()
+        );
+    }
+}
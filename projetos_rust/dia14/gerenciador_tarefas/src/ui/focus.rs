@@ -0,0 +1,224 @@
+use crate::i18n::{t, tf};
+use crate::services::{FocusEvent, FocusSession, Phase, PomodoroSettings, TaskService};
+use chrono::Duration;
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEventKind, KeyModifiers};
+use ratatui::crossterm::terminal;
+use std::io::{self, Write};
+use std::time;
+
+/// De quanto em quanto tempo a contagem é redesenhada
+const REFRESH: time::Duration = time::Duration::from_millis(500);
+
+/// Tecla da sessão de foco
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FocusKey {
+    /// `p` ou espaço: pausa ou retoma
+    TogglePause,
+    /// `q`, Esc ou Ctrl+C: encerra a sessão
+    Quit,
+}
+
+/// Origem das teclas, para os testes não dependerem de um terminal
+pub trait FocusInput {
+    /// Espera uma tecla por até `timeout`
+    fn wait(&mut self, timeout: time::Duration) -> io::Result<Option<FocusKey>>;
+}
+
+/// Teclas do terminal, em modo cru
+pub struct TerminalInput;
+
+impl FocusInput for TerminalInput {
+    fn wait(&mut self, timeout: time::Duration) -> io::Result<Option<FocusKey>> {
+        if !event::poll(timeout)? {
+            return Ok(None);
+        }
+        let key = match event::read()? {
+            Event::Key(key) if key.kind == KeyEventKind::Press => key,
+            _ => return Ok(None),
+        };
+        Ok(match key.code {
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                Some(FocusKey::Quit)
+            }
+            KeyCode::Char('p') | KeyCode::Char(' ') => Some(FocusKey::TogglePause),
+            KeyCode::Char('q') | KeyCode::Esc => Some(FocusKey::Quit),
+            _ => None,
+        })
+    }
+}
+
+/// Roda uma sessão de foco no terminal, até o usuário encerrar
+///
+/// Retorna quantos pomodoros foram concluídos.
+pub fn run_in_terminal<W: Write>(
+    service: &mut TaskService,
+    id: u32,
+    settings: PomodoroSettings,
+    out: &mut W,
+) -> io::Result<u32> {
+    let mut session = FocusSession::start(id, settings, service.clock().clone());
+
+    let raw_mode = RawMode::enable()?;
+    let result = run(service, &mut session, &mut TerminalInput, out);
+    raw_mode.disable()?;
+    result
+}
+
+/// Modo cru do terminal, desligado ao sair do escopo (inclusive em pânico)
+struct RawMode;
+
+impl RawMode {
+    fn enable() -> io::Result<Self> {
+        terminal::enable_raw_mode()?;
+        Ok(RawMode)
+    }
+
+    /// Desliga já, informando o erro (o `Drop` só pode ignorá-lo)
+    fn disable(self) -> io::Result<()> {
+        std::mem::forget(self);
+        terminal::disable_raw_mode()
+    }
+}
+
+impl Drop for RawMode {
+    fn drop(&mut self) {
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+/// Laço da sessão: mostra a contagem, trata as teclas e registra os
+/// pomodoros concluídos na tarefa
+pub fn run<W: Write, I: FocusInput>(
+    service: &mut TaskService,
+    session: &mut FocusSession,
+    input: &mut I,
+    out: &mut W,
+) -> io::Result<u32> {
+    let id = session.task_id();
+    let title = service
+        .get_by_id(id)
+        .map(|task| task.title.clone())
+        .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, t("error.task_not_found")))?;
+
+    // Em modo cru, quebras de linha precisam do `\r`
+    write!(out, "{}\r\n\r\n", t("focus.hint"))?;
+    loop {
+        for event in session.tick() {
+            match event {
+                FocusEvent::Completed(pomodoro) => {
+                    service.log_pomodoro(id, pomodoro)?;
+                    let message = tf("focus.completed", &[&session.completed()]);
+                    write!(out, "\r{}\x07\r\n", message)?;
+                }
+                FocusEvent::BreakOver => write!(out, "\r{}\x07\r\n", t("focus.break_over"))?,
+            }
+        }
+
+        write!(out, "\r{}  ", status_line(session, &title))?;
+        out.flush()?;
+
+        match input.wait(REFRESH)? {
+            Some(FocusKey::TogglePause) => session.toggle_pause(),
+            Some(FocusKey::Quit) => break,
+            None => {}
+        }
+    }
+
+    write!(
+        out,
+        "\r\n{}\r\n",
+        tf("focus.summary", &[&session.completed(), &id])
+    )?;
+    Ok(session.completed())
+}
+
+/// Ex.: "🍅 Foco 24:13 · Escrever relatório"
+fn status_line(session: &FocusSession, title: &str) -> String {
+    let phase = match (session.phase(), session.is_paused()) {
+        (_, true) => t("focus.paused"),
+        (Phase::Work, false) => t("focus.work"),
+        (Phase::Break, false) => t("focus.break"),
+    };
+    format!("{} {} · {}", phase, countdown(session.remaining()), title)
+}
+
+/// Tempo restante em `MM:SS`, arredondado para cima
+fn countdown(remaining: Duration) -> String {
+    let seconds = (remaining.num_milliseconds() + 999) / 1000;
+    format!("{:02}:{:02}", seconds / 60, seconds % 60)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Category, Priority};
+    use crate::utils::ManualClock;
+    use chrono::Local;
+    use std::sync::Arc;
+
+    /// Teclas roteirizadas; cada espera sem tecla faz o relógio andar
+    struct Script {
+        clock: Arc<ManualClock>,
+        steps: Vec<(Duration, Option<FocusKey>)>,
+    }
+
+    impl FocusInput for Script {
+        fn wait(&mut self, _timeout: time::Duration) -> io::Result<Option<FocusKey>> {
+            let (advance, key) = self.steps.remove(0);
+            self.clock.advance(advance);
+            Ok(key)
+        }
+    }
+
+    #[test]
+    fn test_session_logs_pomodoros() {
+        let path = "data/test_focus.json".to_string();
+        TaskService::new(path.clone()).delete_storage().ok();
+        let mut service = TaskService::new(path);
+        let id = service
+            .add_task("Relatório".to_string(), "".to_string(), Category::Work, Priority::High, None)
+            .unwrap()
+            .id;
+
+        let clock = Arc::new(ManualClock::new(Local::now()));
+        let settings = PomodoroSettings {
+            work_minutes: 25,
+            break_minutes: 5,
+        };
+        let mut session = FocusSession::start(id, settings, clock.clone());
+        let mut input = Script {
+            clock,
+            steps: vec![
+                (Duration::minutes(10), Some(FocusKey::TogglePause)),
+                (Duration::minutes(30), Some(FocusKey::TogglePause)),
+                (Duration::minutes(15), None),
+                (Duration::minutes(1), None),
+                (Duration::zero(), Some(FocusKey::Quit)),
+            ],
+        };
+        let mut out = Vec::new();
+
+        let completed = run(&mut service, &mut session, &mut input, &mut out).unwrap();
+
+        assert_eq!(completed, 1);
+        let task = service.get_by_id(id).unwrap();
+        assert_eq!(task.pomodoros.len(), 1);
+        assert_eq!(task.pomodoros[0].minutes, 25);
+        assert_eq!(service.get_statistics().pomodoros, 1);
+        assert_eq!(service.get_statistics().focus_minutes, 25);
+
+        let output = String::from_utf8(out).unwrap();
+        assert!(output.contains("15:00 · Relatório"));
+        assert!(output.contains("04:00 · Relatório"));
+        assert!(output.contains("1 pomodoro(s) concluído(s) em #1"));
+
+        service.delete_storage().ok();
+    }
+
+    #[test]
+    fn test_countdown() {
+        assert_eq!(countdown(Duration::minutes(25)), "25:00");
+        assert_eq!(countdown(Duration::milliseconds(59_001)), "01:00");
+        assert_eq!(countdown(Duration::zero()), "00:00");
+    }
+}
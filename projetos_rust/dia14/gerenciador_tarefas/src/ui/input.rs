@@ -1,41 +1,109 @@
-use std::io::{self, Write};
+use crate::i18n::t;
+use std::io::{self, BufRead, Write};
 
 /// Lê uma linha de entrada do usuário
-pub fn read_line(prompt: &str) -> String {
-    print!("{}", prompt);
-    io::stdout().flush().unwrap();
+///
+/// Retorna `UnexpectedEof` quando a entrada termina.
+pub fn read_line<R: BufRead, W: Write>(
+    reader: &mut R,
+    writer: &mut W,
+    prompt: &str,
+) -> io::Result<String> {
+    write!(writer, "{}", prompt)?;
+    writer.flush()?;
 
     let mut input = String::new();
-    io::stdin()
-        .read_line(&mut input)
-        .expect("Falha ao ler entrada");
+    if reader.read_line(&mut input)? == 0 {
+        return Err(io::Error::new(
+            io::ErrorKind::UnexpectedEof,
+            t("error.end_of_input"),
+        ));
+    }
 
-    input.trim().to_string()
+    Ok(input.trim().to_string())
 }
 
 /// Lê um número do usuário
-pub fn read_number(prompt: &str) -> Option<u32> {
-    let input = read_line(prompt);
-    input.parse::<u32>().ok()
+pub fn read_number<R: BufRead, W: Write>(
+    reader: &mut R,
+    writer: &mut W,
+    prompt: &str,
+) -> io::Result<Option<u32>> {
+    let input = read_line(reader, writer, prompt)?;
+    Ok(input.parse::<u32>().ok())
 }
 
 /// Lê uma opção do menu
-pub fn read_option(prompt: &str, max: u32) -> Option<u32> {
-    let option = read_number(prompt)?;
-    if option <= max {
-        Some(option)
-    } else {
-        None
-    }
+pub fn read_option<R: BufRead, W: Write>(
+    reader: &mut R,
+    writer: &mut W,
+    prompt: &str,
+    max: u32,
+) -> io::Result<Option<u32>> {
+    let option = read_number(reader, writer, prompt)?;
+    Ok(option.filter(|o| *o <= max))
 }
 
 /// Confirma uma ação (s/n)
-pub fn confirm(prompt: &str) -> bool {
-    let input = read_line(&format!("{} (s/n): ", prompt));
-    matches!(input.to_lowercase().as_str(), "s" | "sim" | "y" | "yes")
+pub fn confirm<R: BufRead, W: Write>(
+    reader: &mut R,
+    writer: &mut W,
+    prompt: &str,
+) -> io::Result<bool> {
+    let input = read_line(
+        reader,
+        writer,
+        &format!("{} {}: ", prompt, t("input.yes_no")),
+    )?;
+    Ok(matches!(
+        input.to_lowercase().as_str(),
+        "s" | "sim" | "y" | "yes"
+    ))
 }
 
 /// Pausa até o usuário pressionar Enter
-pub fn pause() {
-    read_line("\nPressione Enter para continuar...");
-}
\ No newline at end of file
+pub fn pause<R: BufRead, W: Write>(reader: &mut R, writer: &mut W) -> io::Result<()> {
+    read_line(reader, writer, t("input.press_enter"))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_line_trims_and_prints_prompt() {
+        let mut reader = "  olá  \n".as_bytes();
+        let mut writer = Vec::new();
+
+        let line = read_line(&mut reader, &mut writer, "Nome: ").unwrap();
+
+        assert_eq!(line, "olá");
+        assert_eq!(String::from_utf8(writer).unwrap(), "Nome: ");
+    }
+
+    #[test]
+    fn test_read_line_eof() {
+        let mut reader = "".as_bytes();
+        let mut writer = Vec::new();
+
+        let err = read_line(&mut reader, &mut writer, "> ").unwrap_err();
+
+        assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+    }
+
+    #[test]
+    fn test_read_option_and_confirm() {
+        let mut reader = "3\n9\nabc\nSim\nn\n".as_bytes();
+        let mut writer = Vec::new();
+
+        assert_eq!(
+            read_option(&mut reader, &mut writer, "", 5).unwrap(),
+            Some(3)
+        );
+        assert_eq!(read_option(&mut reader, &mut writer, "", 5).unwrap(), None);
+        assert_eq!(read_option(&mut reader, &mut writer, "", 5).unwrap(), None);
+        assert!(confirm(&mut reader, &mut writer, "Ok?").unwrap());
+        assert!(!confirm(&mut reader, &mut writer, "Ok?").unwrap());
+    }
+}
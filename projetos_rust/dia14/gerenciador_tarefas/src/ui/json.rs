@@ -0,0 +1,390 @@
+use crate::models::{
+    Attachment, Category, Note, Pomodoro, Priority, Project, ProjectColor, Status, Task,
+};
+use crate::services::task_service::Statistics;
+use crate::services::{Analytics, Score, TaskService};
+use chrono::{DateTime, Local, NaiveDate, NaiveTime};
+use serde::Serialize;
+use std::io::{self, Write};
+use uuid::Uuid;
+
+/// Formato de saída dos comandos de leitura
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OutputFormat {
+    /// Texto formatado, como no menu
+    #[default]
+    Text,
+    /// Um documento JSON
+    Json,
+    /// Um objeto JSON compacto por linha
+    JsonLines,
+}
+
+impl OutputFormat {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OutputFormat::Text => "text",
+            OutputFormat::Json => "json",
+            OutputFormat::JsonLines => "jsonl",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<OutputFormat> {
+        match value.trim().to_lowercase().as_str() {
+            "text" | "texto" => Some(OutputFormat::Text),
+            "json" => Some(OutputFormat::Json),
+            "jsonl" | "ndjson" | "json-lines" => Some(OutputFormat::JsonLines),
+            _ => None,
+        }
+    }
+}
+
+/// Tarefa no formato JSON, com os campos calculados
+#[derive(Debug, Serialize)]
+pub struct TaskRecord<'a> {
+    pub id: u32,
+    pub uuid: Uuid,
+    pub title: &'a str,
+    pub description: &'a str,
+    pub category: Category,
+    pub priority: Priority,
+    pub status: Status,
+    pub due_date: Option<NaiveDate>,
+    pub due_time: Option<NaiveTime>,
+    pub created_at: DateTime<Local>,
+    pub completed_at: Option<DateTime<Local>>,
+    pub project: Option<&'a str>,
+    pub assignee: Option<&'a str>,
+    pub created_by: Option<&'a str>,
+    pub blocked_by: &'a [u32],
+    pub estimate_minutes: Option<u32>,
+    pub time_spent_minutes: i64,
+    pub is_overdue: bool,
+    pub is_blocked: bool,
+    pub is_over_estimate: bool,
+    pub timer_running: bool,
+    pub notes: &'a [Note],
+    pub attachments: &'a [Attachment],
+    pub pomodoros: &'a [Pomodoro],
+}
+
+impl<'a> TaskRecord<'a> {
+    /// Monta o registro; o serviço diz se a tarefa está bloqueada
+    pub fn new(task: &'a Task, service: &TaskService) -> Self {
+        let now = service.clock().now();
+        TaskRecord {
+            id: task.id,
+            uuid: task.uuid,
+            title: &task.title,
+            description: &task.description,
+            category: task.category,
+            priority: task.priority,
+            status: task.status,
+            due_date: task.due_date,
+            due_time: task.due_time,
+            created_at: task.created_at,
+            completed_at: task.completed_at,
+            project: task.project.as_deref(),
+            assignee: task.assignee.as_deref(),
+            created_by: task.created_by.as_deref(),
+            blocked_by: &task.blocked_by,
+            estimate_minutes: task.estimate_minutes,
+            time_spent_minutes: task.time_spent(now).num_minutes(),
+            is_overdue: task.is_overdue_at(&now),
+            is_blocked: service.is_blocked(task),
+            is_over_estimate: task.is_over_estimate(now),
+            timer_running: task.is_timer_running(),
+            notes: &task.notes,
+            attachments: &task.attachments,
+            pomodoros: &task.pomodoros,
+        }
+    }
+}
+
+/// Estatísticas gerais e por grupo
+#[derive(Debug, Serialize)]
+pub struct StatisticsRecord<'a> {
+    pub overall: &'a Statistics,
+    pub by_user: Vec<GroupRecord<'a>>,
+    pub by_project: Vec<GroupRecord<'a>>,
+}
+
+/// Estatísticas de um grupo (`name` nulo = sem responsável ou sem projeto)
+#[derive(Debug, Serialize)]
+pub struct GroupRecord<'a> {
+    pub name: Option<&'a str>,
+    #[serde(flatten)]
+    pub statistics: &'a Statistics,
+}
+
+impl<'a> GroupRecord<'a> {
+    pub fn from_groups(groups: &'a [(Option<String>, Statistics)]) -> Vec<Self> {
+        groups
+            .iter()
+            .map(|(name, statistics)| GroupRecord {
+                name: name.as_deref(),
+                statistics,
+            })
+            .collect()
+    }
+}
+
+/// Tarefa recomendada, com a pontuação e os motivos
+#[derive(Debug, Serialize)]
+pub struct NextUpRecord<'a> {
+    pub rank: usize,
+    pub score: f64,
+    pub factors: Vec<FactorRecord<'a>>,
+    pub task: TaskRecord<'a>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct FactorRecord<'a> {
+    pub reason: &'a str,
+    pub points: f64,
+}
+
+impl<'a> NextUpRecord<'a> {
+    pub fn new(rank: usize, task: &'a Task, score: &'a Score, service: &TaskService) -> Self {
+        NextUpRecord {
+            rank,
+            score: score.total,
+            factors: score
+                .factors
+                .iter()
+                .map(|f| FactorRecord {
+                    reason: &f.reason,
+                    points: f.points,
+                })
+                .collect(),
+            task: TaskRecord::new(task, service),
+        }
+    }
+}
+
+/// Projeto com a quantidade de tarefas
+#[derive(Debug, Serialize)]
+pub struct ProjectRecord<'a> {
+    pub name: &'a str,
+    pub description: &'a str,
+    pub color: ProjectColor,
+    pub task_count: usize,
+    pub active: bool,
+}
+
+impl<'a> ProjectRecord<'a> {
+    pub fn new(project: &'a Project, task_count: usize, active: bool) -> Self {
+        ProjectRecord {
+            name: &project.name,
+            description: &project.description,
+            color: project.color,
+            task_count,
+            active,
+        }
+    }
+}
+
+/// Análise de produtividade, com durações em minutos
+#[derive(Debug, Serialize)]
+pub struct AnalyticsRecord {
+    pub from: NaiveDate,
+    pub to: NaiveDate,
+    pub completed_per_day: Vec<DayCount>,
+    pub completed_per_week: Vec<DayCount>,
+    pub lead_time_minutes_by_category: Vec<CategoryLeadTime>,
+    pub overdue_rate_by_week: Vec<WeekRate>,
+    pub burndown: Vec<DayCount>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DayCount {
+    pub date: NaiveDate,
+    pub count: usize,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CategoryLeadTime {
+    pub category: Category,
+    pub minutes: Option<i64>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct WeekRate {
+    pub week: NaiveDate,
+    pub rate: Option<f64>,
+}
+
+impl From<&Analytics> for AnalyticsRecord {
+    fn from(analytics: &Analytics) -> Self {
+        let counts = |days: &[(NaiveDate, usize)]| {
+            days.iter()
+                .map(|(date, count)| DayCount {
+                    date: *date,
+                    count: *count,
+                })
+                .collect()
+        };
+
+        AnalyticsRecord {
+            from: analytics.from,
+            to: analytics.to,
+            completed_per_day: counts(&analytics.completed_per_day),
+            completed_per_week: counts(&analytics.completed_per_week),
+            lead_time_minutes_by_category: analytics
+                .lead_time_by_category
+                .iter()
+                .map(|(category, lead)| CategoryLeadTime {
+                    category: *category,
+                    minutes: lead.map(|d| d.num_minutes()),
+                })
+                .collect(),
+            overdue_rate_by_week: analytics
+                .overdue_rate_by_week
+                .iter()
+                .map(|(week, rate)| WeekRate {
+                    week: *week,
+                    rate: *rate,
+                })
+                .collect(),
+            burndown: counts(&analytics.burndown),
+        }
+    }
+}
+
+/// Escreve uma lista de registros
+///
+/// Em JSON sai um array; em JSON Lines, um objeto por linha (nada se a
+/// lista estiver vazia).
+pub fn write_list<W: Write, T: Serialize>(
+    out: &mut W,
+    records: &[T],
+    format: OutputFormat,
+) -> io::Result<()> {
+    match format {
+        OutputFormat::JsonLines => {
+            for record in records {
+                serde_json::to_writer(&mut *out, record)?;
+                writeln!(out)?;
+            }
+            Ok(())
+        }
+        _ => {
+            serde_json::to_writer_pretty(&mut *out, records)?;
+            writeln!(out)
+        }
+    }
+}
+
+/// Escreve um único registro (em JSON Lines, numa linha só)
+pub fn write_one<W: Write, T: Serialize>(
+    out: &mut W,
+    record: &T,
+    format: OutputFormat,
+) -> io::Result<()> {
+    match format {
+        OutputFormat::JsonLines => serde_json::to_writer(&mut *out, record)?,
+        _ => serde_json::to_writer_pretty(&mut *out, record)?,
+    }
+    writeln!(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+    use serde_json::Value;
+
+    fn create_test_service(name: &str) -> TaskService {
+        let path = format!("data/test_json_{}.json", name);
+        TaskService::new(path.clone()).delete_storage().ok();
+        TaskService::new(path)
+    }
+
+    #[test]
+    fn test_parse_format() {
+        assert_eq!(OutputFormat::parse("JSON"), Some(OutputFormat::Json));
+        assert_eq!(OutputFormat::parse("jsonl"), Some(OutputFormat::JsonLines));
+        assert_eq!(OutputFormat::parse("xml"), None);
+    }
+
+    #[test]
+    fn test_task_record_fields() {
+        let mut service = create_test_service("task_record");
+        let yesterday = Local::now().date_naive() - Duration::days(1);
+        let id = service
+            .add_task(
+                "Pagar conta".to_string(),
+                "".to_string(),
+                Category::Personal,
+                Priority::High,
+                Some(yesterday),
+            )
+            .unwrap()
+            .id;
+
+        let task = service.get_by_id(id).unwrap();
+        let value = serde_json::to_value(TaskRecord::new(task, &service)).unwrap();
+
+        assert_eq!(value["id"], id);
+        assert_eq!(value["uuid"], task.uuid.to_string());
+        assert_eq!(value["category"], "Personal");
+        assert_eq!(value["priority"], "High");
+        assert_eq!(value["status"], "Pending");
+        assert_eq!(value["due_date"], Value::String(yesterday.to_string()));
+        assert_eq!(value["is_overdue"], true);
+        assert_eq!(value["is_blocked"], false);
+        assert_eq!(value["project"], Value::Null);
+        assert_eq!(value["notes"], Value::Array(Vec::new()));
+
+        service.delete_storage().ok();
+    }
+
+    #[test]
+    fn test_statistics_counts_are_objects() {
+        let mut service = create_test_service("statistics");
+        service
+            .add_task(
+                "A".to_string(),
+                "".to_string(),
+                Category::Work,
+                Priority::Low,
+                None,
+            )
+            .unwrap();
+
+        let stats = service.get_statistics();
+        let value = serde_json::to_value(&stats).unwrap();
+
+        assert_eq!(value["total"], 1);
+        assert_eq!(value["by_category"]["Work"], 1);
+        assert_eq!(value["by_category"]["Health"], 0);
+        assert_eq!(value["by_priority"]["Low"], 1);
+
+        service.delete_storage().ok();
+    }
+
+    #[test]
+    fn test_json_lines_one_object_per_line() {
+        let mut out = Vec::new();
+        let empty: Vec<DayCount> = Vec::new();
+        write_list(&mut out, &empty, OutputFormat::JsonLines).unwrap();
+        assert!(out.is_empty());
+
+        let house = Project::new("Casa".to_string(), String::new(), ProjectColor::Blue);
+        let work = Project::new("Obra".to_string(), String::new(), ProjectColor::Red);
+        let records = vec![
+            ProjectRecord::new(&house, 2, true),
+            ProjectRecord::new(&work, 0, false),
+        ];
+        let mut out = Vec::new();
+        write_list(&mut out, &records, OutputFormat::JsonLines).unwrap();
+
+        let output = String::from_utf8(out).unwrap();
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(
+            lines[0],
+            r#"{"name":"Casa","description":"","color":"Blue","task_count":2,"active":true}"#
+        );
+    }
+}
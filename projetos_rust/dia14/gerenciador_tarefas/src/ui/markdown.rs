@@ -0,0 +1,227 @@
+use crate::ui::text::{self, display_width, truncate, wrap_styled};
+
+/// Estilo de um trecho de texto
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Style {
+    Plain,
+    Bold,
+    Code,
+    Link,
+}
+
+impl Style {
+    /// Códigos ANSI de início e fim do estilo
+    fn codes(&self) -> (&'static str, &'static str) {
+        match self {
+            Style::Plain => ("", ""),
+            Style::Bold => ("\x1B[1m", "\x1B[22m"),
+            Style::Code => ("\x1B[36m", "\x1B[39m"),
+            Style::Link => ("\x1B[4m", "\x1B[24m"),
+        }
+    }
+}
+
+/// Renderiza um subconjunto de Markdown em linhas de até `width` colunas
+///
+/// Suporta `**negrito**`, `` `código` ``, `[texto](url)`, títulos (`#`),
+/// listas (`-`, `*`, `1.`) com recuo pendurado e blocos de código entre
+/// ```` ``` ````, que são cortados em vez de quebrados. Os estilos viram
+/// códigos ANSI quando [`text::styles_enabled`] está ligado.
+pub fn render(markdown: &str, width: usize) -> Vec<String> {
+    let width = width.max(1);
+    let mut lines = Vec::new();
+    let mut in_code_block = false;
+
+    for line in markdown.lines() {
+        if line.trim_start().starts_with("```") {
+            in_code_block = !in_code_block;
+            continue;
+        }
+
+        if in_code_block {
+            let chars: Vec<(char, Style)> = truncate(line, width)
+                .chars()
+                .map(|c| (c, Style::Code))
+                .collect();
+            lines.push(paint(&chars));
+            continue;
+        }
+
+        if line.trim().is_empty() {
+            lines.push(String::new());
+            continue;
+        }
+
+        let (prefix, content, base) = block(line);
+        let indent = " ".repeat(display_width(&prefix));
+        let chars = inline(content, base);
+        let wrapped = wrap_styled(&chars, width.saturating_sub(indent.len()));
+
+        for (i, wrapped_line) in wrapped.iter().enumerate() {
+            let lead = if i == 0 { &prefix } else { &indent };
+            lines.push(format!("{}{}", lead, paint(wrapped_line)));
+        }
+    }
+
+    lines
+}
+
+/// Separa o marcador de bloco (lista ou título) do conteúdo da linha
+///
+/// Retorna o prefixo a exibir, o conteúdo e o estilo base do conteúdo.
+fn block(line: &str) -> (String, &str, Style) {
+    let content = line.trim_start();
+    let indent = " ".repeat((line.len() - content.len()) / 2 * 2);
+
+    if let Some(rest) = content
+        .strip_prefix("- ")
+        .or_else(|| content.strip_prefix("* "))
+        .or_else(|| content.strip_prefix("+ "))
+    {
+        return (format!("{}• ", indent), rest.trim_start(), Style::Plain);
+    }
+
+    let digits = content.chars().take_while(|c| c.is_ascii_digit()).count();
+    if digits > 0
+        && let Some(rest) = content[digits..].strip_prefix(". ")
+    {
+        return (
+            format!("{}{}. ", indent, &content[..digits]),
+            rest.trim_start(),
+            Style::Plain,
+        );
+    }
+
+    let hashes = content.chars().take_while(|c| *c == '#').count();
+    if hashes > 0
+        && let Some(rest) = content[hashes..].strip_prefix(' ')
+    {
+        return (indent, rest.trim_start(), Style::Bold);
+    }
+
+    (indent, content, Style::Plain)
+}
+
+/// Interpreta negrito, código e links de uma linha
+fn inline(text: &str, base: Style) -> Vec<(char, Style)> {
+    let mut chars = Vec::new();
+    let mut rest = text;
+
+    while let Some(c) = rest.chars().next() {
+        if let Some(after) = rest.strip_prefix("**")
+            && let Some(end) = after.find("**")
+        {
+            chars.extend(after[..end].chars().map(|c| (c, Style::Bold)));
+            rest = &after[end + 2..];
+        } else if let Some(after) = rest.strip_prefix('`')
+            && let Some(end) = after.find('`')
+        {
+            chars.extend(after[..end].chars().map(|c| (c, Style::Code)));
+            rest = &after[end + 1..];
+        } else if let Some((label, url, after)) = link(rest) {
+            chars.extend(label.chars().map(|c| (c, Style::Link)));
+            if url != label {
+                chars.extend(format!(" ({})", url).chars().map(|c| (c, base)));
+            }
+            rest = after;
+        } else {
+            chars.push((c, base));
+            rest = &rest[c.len_utf8()..];
+        }
+    }
+
+    chars
+}
+
+/// Reconhece `[texto](url)` no início do texto
+fn link(text: &str) -> Option<(&str, &str, &str)> {
+    let after = text.strip_prefix('[')?;
+    let (label, after) = after.split_once("](")?;
+    let (url, after) = after.split_once(')')?;
+    if label.contains(']') {
+        return None;
+    }
+    Some((label, url, after))
+}
+
+/// Monta a linha com os códigos ANSI de cada trecho (ou sem, se desligados)
+fn paint(chars: &[(char, Style)]) -> String {
+    let styled = text::styles_enabled();
+    let mut line = String::new();
+    let mut current = Style::Plain;
+
+    for &(c, style) in chars {
+        if styled && style != current {
+            line.push_str(current.codes().1);
+            line.push_str(style.codes().0);
+            current = style;
+        }
+        line.push(c);
+    }
+
+    if styled {
+        line.push_str(current.codes().1);
+    }
+    line
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn plain(markdown: &str, width: usize) -> Vec<String> {
+        text::set_styles(false);
+        render(markdown, width)
+    }
+
+    #[test]
+    fn test_inline_styles() {
+        text::set_styles(true);
+        let lines = render("Use **sempre** o `cargo` e leia [o guia](https://x.io)", 80);
+
+        assert_eq!(
+            lines,
+            vec![
+                "Use \x1B[1msempre\x1B[22m o \x1B[36mcargo\x1B[39m e leia \
+                 \x1B[4mo guia\x1B[24m (https://x.io)"
+            ]
+        );
+        assert_eq!(
+            plain("Use **sempre** o `cargo`", 80),
+            vec!["Use sempre o cargo"]
+        );
+    }
+
+    #[test]
+    fn test_lists_wrap_with_hanging_indent() {
+        let lines = plain(
+            "Passos:\n- comprar tinta branca\n  - lixar parede\n2. pintar",
+            14,
+        );
+
+        assert_eq!(
+            lines,
+            vec![
+                "Passos:",
+                "• comprar",
+                "  tinta branca",
+                "  • lixar",
+                "    parede",
+                "2. pintar"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_code_block_is_cut_not_wrapped() {
+        let lines = plain("```\nlet x = uma_funcao_longa();\n```\n# Título", 12);
+
+        assert_eq!(lines, vec!["let x = uma…", "Título"]);
+    }
+
+    #[test]
+    fn test_unclosed_markers_stay_literal() {
+        assert_eq!(plain("2 ** 3 e `x", 80), vec!["2 ** 3 e `x"]);
+        assert_eq!(plain("[sem link]", 80), vec!["[sem link]"]);
+    }
+}
@@ -1,216 +1,582 @@
-use crate::models::{Category, Priority};
-use crate::services::TaskService;
-use crate::ui::{input, output};
-use chrono::NaiveDate;
+use crate::config::Config;
+use crate::i18n::{self, t, tf};
+use crate::models::{Category, Priority, ProjectColor, Status, Task};
+use crate::services::{PomodoroSettings, TaskService};
+use crate::services::bulk::{self, BulkAction};
+use crate::ui::theme::{self, AsciiWriter};
+use crate::ui::{focus, input, output};
+use chrono::{Duration, NaiveDate};
+use std::collections::HashMap;
+use std::io::{self, BufRead, IsTerminal, StdinLock, Stdout, Write};
+use std::path::Path;
 
-pub struct Menu {
+/// Quantas tarefas a recomendação "o que fazer agora" mostra
+const NEXT_UP_COUNT: usize = 5;
+
+/// Menu interativo, genérico sobre a entrada e a saída
+pub struct Menu<R = StdinLock<'static>, W = AsciiWriter<Stdout>> {
     service: TaskService,
+    reader: R,
+    writer: W,
+    config: Config,
+    /// Entrada vem de um terminal: senhas sem eco e sessão de foco
+    terminal: bool,
 }
 
 impl Menu {
+    /// Cria um menu que usa o terminal (stdin/stdout)
+    ///
+    /// A saída vira ASCII puro quando [`theme::ascii_enabled`] está ligado.
     pub fn new(service: TaskService) -> Self {
-        Menu { service }
+        let writer = AsciiWriter::new(io::stdout(), theme::ascii_enabled());
+        let mut menu = Menu::with_io(service, io::stdin().lock(), writer);
+        menu.terminal = io::stdin().is_terminal();
+        menu
+    }
+}
+
+impl<R: BufRead, W: Write> Menu<R, W> {
+    /// Cria um menu com entrada e saída customizadas
+    pub fn with_io(service: TaskService, reader: R, writer: W) -> Self {
+        Menu {
+            service,
+            reader,
+            writer,
+            config: Config::default(),
+            terminal: false,
+        }
+    }
+
+    /// Aplica a configuração de interface (ex.: `clear_screen`)
+    pub fn with_config(mut self, config: Config) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Serviço usado pelo menu
+    pub fn service(&self) -> &TaskService {
+        &self.service
+    }
+
+    /// Saída usada pelo menu
+    pub fn writer(&self) -> &W {
+        &self.writer
+    }
+
+    /// Executa o menu até o usuário sair
+    ///
+    /// O fim da entrada também encerra o menu.
+    pub fn run(&mut self) -> io::Result<()> {
+        match self.main_loop() {
+            Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => Ok(()),
+            result => result,
+        }
     }
 
-    pub fn run(&mut self) {
+    fn main_loop(&mut self) -> io::Result<()> {
         loop {
-            output::clear_screen();
-            self.display_main_menu();
+            self.clear_screen()?;
+            self.display_main_menu()?;
 
-            let option = input::read_option("Escolha uma opção: ", 7);
+            let option = self.read_option(t("menu.choose_option"), 16)?;
 
             match option {
-                Some(1) => self.create_task(),
-                Some(2) => self.list_tasks(),
-                Some(3) => self.view_task(),
-                Some(4) => self.update_task(),
-                Some(5) => self.delete_task(),
-                Some(6) => self.filter_tasks(),
-                Some(7) => self.show_statistics(),
+                Some(1) => self.create_task()?,
+                Some(2) => self.list_tasks()?,
+                Some(3) => self.view_task()?,
+                Some(4) => self.update_task()?,
+                Some(5) => self.delete_task()?,
+                Some(6) => self.filter_tasks()?,
+                Some(7) => self.show_statistics()?,
+                Some(8) => self.show_analytics()?,
+                Some(9) => self.show_board()?,
+                Some(10) => self.trash_and_archive()?,
+                Some(11) => self.bulk_operations()?,
+                Some(12) => self.quick_add()?,
+                Some(13) => self.show_next_up()?,
+                Some(14) => self.projects()?,
+                Some(15) => self.encryption()?,
+                Some(16) => self.templates()?,
                 Some(0) => {
-                    output::print_success("Até logo! 👋");
-                    break;
+                    output::print_success(&mut self.writer, t("menu.goodbye"))?;
+                    return Ok(());
                 }
                 _ => {
-                    output::print_error("Opção inválida!");
-                    input::pause();
+                    output::print_error(&mut self.writer, t("menu.invalid_option"))?;
+                    self.pause()?;
                 }
             }
         }
     }
 
-    fn display_main_menu(&self) {
-        output::print_header("GERENCIADOR DE TAREFAS");
-        println!("1. ➕ Criar nova tarefa");
-        println!("2. 📋 Listar todas as tarefas");
-        println!("3. 🔍 Ver detalhes de uma tarefa");
-        println!("4. ✏️  Atualizar tarefa");
-        println!("5. 🗑️  Deletar tarefa");
-        println!("6. 🔎 Filtrar tarefas");
-        println!("7. 📊 Estatísticas");
-        println!("0. 🚪 Sair");
-        println!();
+    /// Limpa a tela, se a configuração permitir
+    fn clear_screen(&mut self) -> io::Result<()> {
+        if self.config.clear_screen {
+            output::clear_screen(&mut self.writer)?;
+        }
+        Ok(())
     }
 
-    fn create_task(&mut self) {
-        output::clear_screen();
-        output::print_header("CRIAR NOVA TAREFA");
+    fn read_line(&mut self, prompt: &str) -> io::Result<String> {
+        input::read_line(&mut self.reader, &mut self.writer, prompt)
+    }
 
-        let title = input::read_line("Título: ");
-        if title.is_empty() {
-            output::print_error("Título não pode ser vazio!");
-            input::pause();
-            return;
+    /// Lê uma senha; sem terminal (ex.: testes), lê a linha normalmente
+    fn read_secret(&mut self, prompt: &str) -> io::Result<String> {
+        if !self.terminal {
+            return self.read_line(prompt);
         }
+        write!(self.writer, "{}", prompt)?;
+        self.writer.flush()?;
+        rpassword::read_password()
+    }
+
+    fn read_number(&mut self, prompt: &str) -> io::Result<Option<u32>> {
+        input::read_number(&mut self.reader, &mut self.writer, prompt)
+    }
+
+    fn read_option(&mut self, prompt: &str, max: u32) -> io::Result<Option<u32>> {
+        input::read_option(&mut self.reader, &mut self.writer, prompt, max)
+    }
+
+    fn confirm(&mut self, prompt: &str) -> io::Result<bool> {
+        input::confirm(&mut self.reader, &mut self.writer, prompt)
+    }
 
-        let description = input::read_line("Descrição: ");
+    fn pause(&mut self) -> io::Result<()> {
+        input::pause(&mut self.reader, &mut self.writer)
+    }
+
+    /// Mostra uma mensagem de erro e pausa
+    fn fail(&mut self, message: &str) -> io::Result<()> {
+        output::print_error(&mut self.writer, message)?;
+        self.pause()
+    }
 
-        // Categoria
-        println!("\nCategorias:");
-        for (i, cat) in Category::all().iter().enumerate() {
-            println!("{}. {}", i + 1, cat.as_str());
+    fn display_main_menu(&mut self) -> io::Result<()> {
+        let out = &mut self.writer;
+        output::print_header(out, t("menu.title"))?;
+        if let Some(project) = self.service.active_project() {
+            writeln!(out, "{}\n", tf("menu.active_project", &[&project.name]))?;
         }
-        let cat_option = input::read_option("Escolha a categoria: ", Category::all().len() as u32);
-        let category = match cat_option {
-            Some(n) => Category::all()[(n - 1) as usize],
-            None => {
-                output::print_error("Categoria inválida!");
-                input::pause();
-                return;
-            }
-        };
+        writeln!(out, "1. ➕ {}", t("menu.create"))?;
+        writeln!(out, "2. 📋 {}", t("menu.list"))?;
+        writeln!(out, "3. 🔍 {}", t("menu.view"))?;
+        writeln!(out, "4. ✏️  {}", t("menu.update"))?;
+        writeln!(out, "5. 🗑️  {}", t("menu.delete"))?;
+        writeln!(out, "6. 🔎 {}", t("menu.filter"))?;
+        writeln!(out, "7. 📊 {}", t("menu.statistics"))?;
+        writeln!(out, "8. 📈 {}", t("menu.analytics"))?;
+        writeln!(out, "9. 🗂️  {}", t("menu.board"))?;
+        writeln!(out, "10. ♻️  {}", t("menu.trash_archive"))?;
+        writeln!(out, "11. 📦 {}", t("menu.bulk"))?;
+        writeln!(out, "12. ⚡ {}", t("menu.quick_add"))?;
+        writeln!(out, "13. 🧭 {}", t("menu.next_up"))?;
+        writeln!(out, "14. 📁 {}", t("menu.projects"))?;
+        writeln!(out, "15. 🔐 {}", t("menu.encryption"))?;
+        writeln!(out, "16. 🧩 {}", t("menu.templates"))?;
+        writeln!(out, "0. 🚪 {}", t("menu.exit"))?;
+        writeln!(out)
+    }
+
+    fn create_task(&mut self) -> io::Result<()> {
+        self.clear_screen()?;
+        output::print_header(&mut self.writer, t("menu.create_title"))?;
 
-        // Prioridade
-        println!("\nPrioridades:");
-        for (i, pri) in Priority::all().iter().enumerate() {
-            println!("{}. {}", i + 1, pri.as_str());
+        let title = self.read_line(t("menu.prompt_title"))?;
+        if title.is_empty() {
+            return self.fail(t("menu.empty_title"));
         }
-        let pri_option = input::read_option("Escolha a prioridade: ", Priority::all().len() as u32);
-        let priority = match pri_option {
-            Some(n) => Priority::all()[(n - 1) as usize],
-            None => {
-                output::print_error("Prioridade inválida!");
-                input::pause();
-                return;
-            }
-        };
+
+        let description = self.read_line(t("menu.prompt_description"))?;
+
+        // Categoria (Enter usa a padrão da configuração)
+        let default_category = self.service.default_category();
+        let prompt = tf("menu.choose_with_default", &[&default_category.as_str()]);
+        let category =
+            match self.choose_category(t("menu.categories"), &prompt, Some(default_category))? {
+                Some(category) => category,
+                None => return self.fail(t("menu.invalid_category")),
+            };
+
+        // Prioridade (Enter usa a padrão da configuração)
+        let default_priority = self.service.default_priority();
+        let prompt = tf("menu.choose_with_default", &[&default_priority.as_str()]);
+        let priority =
+            match self.choose_priority(t("menu.priorities"), &prompt, Some(default_priority))? {
+                Some(priority) => priority,
+                None => return self.fail(t("menu.invalid_priority")),
+            };
 
         // Data de vencimento
-        let due_date_str = input::read_line("Data de vencimento (DD/MM/AAAA) ou Enter para pular: ");
+        let due_date_str = self.read_line(&tf("menu.prompt_due_date", &[&i18n::date_hint()]))?;
         let due_date = if due_date_str.is_empty() {
             None
         } else {
-            match NaiveDate::parse_from_str(&due_date_str, "%d/%m/%Y") {
+            match NaiveDate::parse_from_str(&due_date_str, &i18n::date_format()) {
                 Ok(date) => Some(date),
                 Err(_) => {
-                    output::print_error("Data inválida! Use o formato DD/MM/AAAA");
-                    input::pause();
-                    return;
+                    return self.fail(&tf("menu.invalid_date", &[&i18n::date_hint()]));
                 }
             }
         };
 
         // Criar tarefa
-        match self.service.add_task(title, description, category, priority, due_date) {
+        match self
+            .service
+            .add_task(title, description, category, priority, due_date)
+        {
             Ok(task) => {
-                output::print_success(&format!("Tarefa criada com ID: {}", task.id));
+                let message = tf("menu.task_created", &[&task.id]);
+                output::print_success(&mut self.writer, &message)?;
             }
             Err(e) => {
-                output::print_error(&format!("Erro ao criar tarefa: {}", e));
+                output::print_error(&mut self.writer, &tf("menu.create_error", &[&e]))?;
             }
         }
 
-        input::pause();
+        self.pause()
     }
 
-    fn list_tasks(&self) {
-        output::clear_screen();
-        output::print_header("TODAS AS TAREFAS");
+    /// Lista as opções numeradas e lê a escolha do usuário
+    ///
+    /// Com um valor padrão, Enter sem nada escolhe esse valor.
+    fn choose<T: Copy>(
+        &mut self,
+        title: &str,
+        prompt: &str,
+        options: &[T],
+        label: fn(&T) -> &'static str,
+        default: Option<T>,
+    ) -> io::Result<Option<T>> {
+        writeln!(self.writer, "{}", title)?;
+        for (i, option) in options.iter().enumerate() {
+            writeln!(self.writer, "{}. {}", i + 1, label(option))?;
+        }
+
+        let input = self.read_line(prompt)?;
+        if input.is_empty() {
+            return Ok(default);
+        }
+
+        Ok(input
+            .parse::<usize>()
+            .ok()
+            .filter(|n| *n > 0)
+            .and_then(|n| options.get(n - 1).copied()))
+    }
 
-        let tasks: Vec<&crate::models::Task> = self.service.list_all().iter().collect();
-        output::print_task_list(&tasks);
+    /// Lista as categorias e lê a escolha do usuário
+    fn choose_category(
+        &mut self,
+        title: &str,
+        prompt: &str,
+        default: Option<Category>,
+    ) -> io::Result<Option<Category>> {
+        self.choose(title, prompt, &Category::all(), Category::as_str, default)
+    }
 
-        input::pause();
+    /// Lista as prioridades e lê a escolha do usuário
+    fn choose_priority(
+        &mut self,
+        title: &str,
+        prompt: &str,
+        default: Option<Priority>,
+    ) -> io::Result<Option<Priority>> {
+        self.choose(title, prompt, &Priority::all(), Priority::as_str, default)
     }
 
-    fn view_task(&self) {
-        output::clear_screen();
-        output::print_header("VER DETALHES DA TAREFA");
+    fn quick_add(&mut self) -> io::Result<()> {
+        self.clear_screen()?;
+        output::print_header(&mut self.writer, t("menu.quick_add_title"))?;
 
-        let id = match input::read_number("ID da tarefa: ") {
-            Some(id) => id,
-            None => {
-                output::print_error("ID inválido!");
-                input::pause();
-                return;
+        let text = self.read_line(t("menu.prompt_quick_add"))?;
+        let now = self.service.clock().now();
+        match self.service.quick_add(&text) {
+            Ok(task) => {
+                let message = tf("menu.task_created", &[&task.id]);
+                writeln!(self.writer)?;
+                output::print_task(&mut self.writer, task, now)?;
+                output::print_success(&mut self.writer, &message)?;
             }
+            Err(e) => output::print_error(&mut self.writer, &e.to_string())?,
+        }
+
+        self.pause()
+    }
+
+    fn show_next_up(&mut self) -> io::Result<()> {
+        self.clear_screen()?;
+        output::print_header(&mut self.writer, t("menu.next_up_title"))?;
+
+        let ranked = self.service.next_up(NEXT_UP_COUNT);
+        output::print_next_up(&mut self.writer, &ranked)?;
+
+        self.pause()
+    }
+
+    fn list_tasks(&mut self) -> io::Result<()> {
+        self.clear_screen()?;
+        output::print_header(&mut self.writer, t("menu.list_title"))?;
+
+        let tasks = self.service.active_tasks();
+        output::print_task_list(&mut self.writer, &tasks, self.service.clock().now())?;
+
+        self.pause()
+    }
+
+    fn view_task(&mut self) -> io::Result<()> {
+        self.clear_screen()?;
+        output::print_header(&mut self.writer, t("menu.view_title"))?;
+
+        let id = match self.read_number(t("menu.prompt_id"))? {
+            Some(id) => id,
+            None => return self.fail(t("menu.invalid_id")),
         };
 
         match self.service.get_by_id(id) {
             Some(task) => {
-                println!();
-                output::print_task(task);
+                writeln!(self.writer)?;
+                output::print_task(&mut self.writer, task, self.service.clock().now())?;
 
                 // Submenu de ações
-                println!("\nAções:");
-                println!("1. ▶️  Iniciar tarefa");
-                println!("2. ✅ Marcar como concluída");
-                println!("0. Voltar");
+                writeln!(self.writer, "\n{}", t("menu.actions"))?;
+                writeln!(self.writer, "1. ▶️  {}", t("menu.action_start"))?;
+                writeln!(self.writer, "2. ✅ {}", t("menu.action_complete"))?;
+                let timer_running = task.is_timer_running();
+                if timer_running {
+                    writeln!(self.writer, "3. ⏹️  {}", t("menu.action_stop_timer"))?;
+                } else {
+                    writeln!(self.writer, "3. ⏱️  {}", t("menu.action_start_timer"))?;
+                }
+                writeln!(self.writer, "4. 📝 {}", t("menu.action_log_time"))?;
+                writeln!(self.writer, "5. 🎯 {}", t("menu.action_set_estimate"))?;
+                writeln!(self.writer, "6. 🔗 {}", t("menu.action_dependencies"))?;
+                writeln!(self.writer, "7. 👤 {}", t("menu.action_assign"))?;
+                writeln!(self.writer, "8. 📝 {}", t("menu.action_add_note"))?;
+                writeln!(self.writer, "9. 📎 {}", t("menu.action_attach"))?;
+                writeln!(self.writer, "10. 🗃️  {}", t("menu.action_attachments"))?;
+                writeln!(self.writer, "11. 🍅 {}", t("menu.action_focus"))?;
+                writeln!(self.writer, "0. {}", t("menu.back"))?;
 
-                let option = input::read_option("\nEscolha uma ação: ", 2);
+                let option = self.read_option(t("menu.choose_action"), 11)?;
 
                 match option {
                     Some(1) => {
                         if let Err(e) = self.service.start_task(id) {
-                            output::print_error(&format!("Erro: {}", e));
+                            output::print_error(&mut self.writer, &e.to_string())?;
                         } else {
-                            output::print_success("Tarefa iniciada!");
+                            output::print_success(&mut self.writer, t("menu.task_started"))?;
                         }
                     }
                     Some(2) => {
                         if let Err(e) = self.service.complete_task(id) {
-                            output::print_error(&format!("Erro: {}", e));
+                            output::print_error(&mut self.writer, &e.to_string())?;
+                        } else {
+                            output::print_success(&mut self.writer, t("menu.task_completed"))?;
+                        }
+                    }
+                    Some(3) if timer_running => match self.service.stop_timer(id) {
+                        Ok(elapsed) => {
+                            let elapsed = output::format_minutes(elapsed.num_minutes());
+                            output::print_success(
+                                &mut self.writer,
+                                &tf("menu.timer_stopped", &[&elapsed]),
+                            )?;
+                        }
+                        Err(e) => output::print_error(&mut self.writer, &e.to_string())?,
+                    },
+                    Some(3) => match self.service.start_timer(id) {
+                        Ok(()) => output::print_success(&mut self.writer, t("menu.timer_started"))?,
+                        Err(e) => output::print_error(&mut self.writer, &e.to_string())?,
+                    },
+                    Some(4) => {
+                        let minutes = match self.read_number(t("menu.prompt_minutes"))? {
+                            Some(minutes) if minutes > 0 => minutes,
+                            _ => return self.fail(t("menu.invalid_minutes")),
+                        };
+                        match self.service.log_time(id, minutes) {
+                            Ok(()) => {
+                                output::print_success(&mut self.writer, t("menu.time_logged"))?
+                            }
+                            Err(e) => output::print_error(&mut self.writer, &e.to_string())?,
+                        }
+                    }
+                    Some(5) => {
+                        let input = self.read_line(t("menu.prompt_estimate"))?;
+                        let estimate = if input.is_empty() {
+                            None
+                        } else {
+                            match input.parse::<u32>() {
+                                Ok(minutes) if minutes > 0 => Some(minutes),
+                                _ => return self.fail(t("menu.invalid_minutes")),
+                            }
+                        };
+                        match self.service.set_estimate(id, estimate) {
+                            Ok(()) => {
+                                output::print_success(&mut self.writer, t("menu.estimate_set"))?
+                            }
+                            Err(e) => output::print_error(&mut self.writer, &e.to_string())?,
+                        }
+                    }
+                    Some(6) => {
+                        let input = self.read_line(t("menu.prompt_dependencies"))?;
+                        let blocked_by = if input.is_empty() {
+                            Vec::new()
                         } else {
-                            output::print_success("Tarefa concluída!");
+                            match bulk::parse_ids(&input, self.service.highest_id()) {
+                                Some(ids) => ids,
+                                None => return self.fail(t("menu.invalid_ids")),
+                            }
+                        };
+                        match self.service.set_dependencies(id, blocked_by) {
+                            Ok(()) => {
+                                output::print_success(&mut self.writer, t("menu.dependencies_set"))?
+                            }
+                            Err(e) => output::print_error(&mut self.writer, &e.to_string())?,
+                        }
+                    }
+                    Some(7) => {
+                        let input = self.read_line(t("menu.prompt_assignee"))?;
+                        let assignee = Some(input).filter(|a| !a.is_empty());
+                        match self.service.assign_task(id, assignee) {
+                            Ok(()) => {
+                                output::print_success(&mut self.writer, t("menu.task_assigned"))?
+                            }
+                            Err(e) => output::print_error(&mut self.writer, &e.to_string())?,
+                        }
+                    }
+                    Some(8) => {
+                        let text = self.read_line(t("menu.prompt_note"))?;
+                        match self.service.add_note(id, &text) {
+                            Ok(()) => {
+                                output::print_success(&mut self.writer, t("menu.note_added"))?
+                            }
+                            Err(e) => output::print_error(&mut self.writer, &e.to_string())?,
+                        }
+                    }
+                    Some(9) => {
+                        let path = self.read_line(t("menu.prompt_attachment_path"))?;
+                        match self.service.attach_file(id, Path::new(&path)) {
+                            Ok(attachment) => {
+                                let name = theme::user_text(&attachment.name);
+                                let message = tf("menu.file_attached", &[&name]);
+                                output::print_success(&mut self.writer, &message)?;
+                            }
+                            Err(e) => output::print_error(&mut self.writer, &e.to_string())?,
+                        }
+                    }
+                    Some(10) => return self.manage_attachments(id),
+                    Some(11) if !self.terminal => {
+                        output::print_error(&mut self.writer, t("focus.needs_terminal"))?
+                    }
+                    Some(11) => {
+                        let settings = PomodoroSettings::from_config(&self.config);
+                        let result = focus::run_in_terminal(
+                            &mut self.service,
+                            id,
+                            settings,
+                            &mut self.writer,
+                        );
+                        if let Err(e) = result {
+                            output::print_error(&mut self.writer, &e.to_string())?;
                         }
                     }
                     _ => {}
                 }
             }
             None => {
-                output::print_error("Tarefa não encontrada!");
+                output::print_error(&mut self.writer, t("menu.task_not_found"))?;
             }
         }
 
-        input::pause();
+        self.pause()
     }
 
-    fn update_task(&mut self) {
-        output::clear_screen();
-        output::print_header("ATUALIZAR TAREFA");
+    /// Lista os anexos com o caminho para abrir e permite remover um
+    ///
+    /// Com as tarefas criptografadas, os anexos também são: em vez do
+    /// caminho, oferece salvar uma cópia aberta.
+    fn manage_attachments(&mut self, id: u32) -> io::Result<()> {
+        writeln!(self.writer)?;
+        let attachments = self.service.list_attachments(id)?.to_vec();
+        if attachments.is_empty() {
+            writeln!(self.writer, "{}", t("menu.no_attachments"))?;
+            return self.pause();
+        }
 
-        let id = match input::read_number("ID da tarefa: ") {
-            Some(id) => id,
-            None => {
-                output::print_error("ID inválido!");
-                input::pause();
-                return;
+        let encrypted = self.service.is_encrypted();
+        for (i, attachment) in attachments.iter().enumerate() {
+            let size = output::format_size(attachment.size);
+            let name = theme::user_text(&attachment.name);
+            writeln!(self.writer, "{}. {} ({})", i + 1, name, size)?;
+            match self.service.attachment_path(attachment) {
+                Ok(_) if encrypted => {
+                    writeln!(self.writer, "   🔒 {}", t("menu.attachment_encrypted"))?
+                }
+                Ok(path) => writeln!(self.writer, "   {}", path.display())?,
+                Err(e) => writeln!(self.writer, "   ⚠️  {}", e)?,
+            }
+        }
+
+        if encrypted {
+            let input = self.read_line(t("menu.prompt_save_attachment"))?;
+            if !input.is_empty() {
+                let attachment = match input.parse::<usize>() {
+                    Ok(n) if n > 0 && n <= attachments.len() => &attachments[n - 1],
+                    _ => return self.fail(t("menu.invalid_option")),
+                };
+                let target = self.read_line(t("menu.prompt_save_attachment_path"))?;
+                match self.service.save_attachment(attachment, Path::new(&target)) {
+                    Ok(()) => {
+                        let name = theme::user_text(&attachment.name);
+                        let message = tf("menu.attachment_saved", &[&name, &target]);
+                        output::print_success(&mut self.writer, &message)?;
+                    }
+                    Err(e) => output::print_error(&mut self.writer, &e.to_string())?,
+                }
             }
+        }
+
+        let input = self.read_line(t("menu.prompt_remove_attachment"))?;
+        if input.is_empty() {
+            return self.pause();
+        }
+        let index = match input.parse::<usize>() {
+            Ok(n) if n > 0 => n - 1,
+            _ => return self.fail(t("menu.invalid_option")),
+        };
+        match self.service.remove_attachment(id, index) {
+            Ok(removed) => {
+                let name = theme::user_text(&removed.name);
+                let message = tf("menu.attachment_removed", &[&name]);
+                output::print_success(&mut self.writer, &message)?;
+            }
+            Err(e) => output::print_error(&mut self.writer, &e.to_string())?,
+        }
+        self.pause()
+    }
+
+    fn update_task(&mut self) -> io::Result<()> {
+        self.clear_screen()?;
+        output::print_header(&mut self.writer, t("menu.update_title"))?;
+
+        let id = match self.read_number(t("menu.prompt_id"))? {
+            Some(id) => id,
+            None => return self.fail(t("menu.invalid_id")),
         };
 
         // Verifica se existe
         if self.service.get_by_id(id).is_none() {
-            output::print_error("Tarefa não encontrada!");
-            input::pause();
-            return;
+            return self.fail(t("menu.task_not_found"));
         }
 
-        println!("\nDeixe em branco para manter o valor atual.\n");
+        writeln!(self.writer, "\n{}\n", t("menu.keep_blank"))?;
 
-        let title = input::read_line("Novo título: ");
+        let title = self.read_line(t("menu.prompt_new_title"))?;
         let title = if title.is_empty() { None } else { Some(title) };
 
-        let description = input::read_line("Nova descrição: ");
+        let description = self.read_line(t("menu.prompt_new_description"))?;
         let description = if description.is_empty() {
             None
         } else {
@@ -219,158 +585,603 @@ impl Menu {
 
         // Aqui você pode adicionar lógica para atualizar categoria, prioridade, etc.
 
-        match self.service.update_task(id, title, description, None, None, None) {
+        match self
+            .service
+            .update_task(id, title, description, None, None, None)
+        {
             Ok(_) => {
-                output::print_success("Tarefa atualizada com sucesso!");
+                output::print_success(&mut self.writer, t("menu.task_updated"))?;
             }
             Err(e) => {
-                output::print_error(&format!("Erro ao atualizar: {}", e));
+                output::print_error(&mut self.writer, &tf("menu.update_error", &[&e]))?;
             }
         }
 
-        input::pause();
+        self.pause()
     }
 
-    fn delete_task(&mut self) {
-        output::clear_screen();
-        output::print_header("DELETAR TAREFA");
+    fn delete_task(&mut self) -> io::Result<()> {
+        self.clear_screen()?;
+        output::print_header(&mut self.writer, t("menu.delete_title"))?;
 
-        let id = match input::read_number("ID da tarefa: ") {
+        let id = match self.read_number(t("menu.prompt_id"))? {
             Some(id) => id,
-            None => {
-                output::print_error("ID inválido!");
-                input::pause();
-                return;
-            }
+            None => return self.fail(t("menu.invalid_id")),
         };
 
         // Mostra a tarefa
         match self.service.get_by_id(id) {
             Some(task) => {
-                println!();
-                output::print_task(task);
+                writeln!(self.writer)?;
+                output::print_task(&mut self.writer, task, self.service.clock().now())?;
 
-                if input::confirm("\nTem certeza que deseja deletar esta tarefa?") {
+                if self.confirm(t("menu.confirm_delete"))? {
                     match self.service.delete_task(id) {
                         Ok(_) => {
-                            output::print_success("Tarefa deletada com sucesso!");
+                            output::print_success(&mut self.writer, t("menu.task_deleted"))?;
                         }
                         Err(e) => {
-                            output::print_error(&format!("Erro ao deletar: {}", e));
+                            output::print_error(&mut self.writer, &tf("menu.delete_error", &[&e]))?;
                         }
                     }
                 } else {
-                    output::print_warning("Operação cancelada.");
+                    output::print_warning(&mut self.writer, t("menu.cancelled"))?;
                 }
             }
             None => {
-                output::print_error("Tarefa não encontrada!");
+                output::print_error(&mut self.writer, t("menu.task_not_found"))?;
             }
         }
 
-        input::pause();
+        self.pause()
     }
 
-    fn filter_tasks(&self) {
-        output::clear_screen();
-        output::print_header("FILTRAR TAREFAS");
+    fn bulk_operations(&mut self) -> io::Result<()> {
+        self.clear_screen()?;
+        output::print_header(&mut self.writer, t("menu.bulk_title"))?;
 
-        println!("1. Por Status");
-        println!("2. Por Categoria");
-        println!("3. Por Prioridade");
-        println!("4. Tarefas Atrasadas");
-        println!("0. Voltar");
+        let input = self.read_line(t("menu.prompt_ids"))?;
+        let ids = match bulk::parse_ids(&input, self.service.highest_id()) {
+            Some(ids) => ids,
+            None => return self.fail(t("menu.invalid_ids")),
+        };
 
-        let option = input::read_option("\nEscolha o filtro: ", 4);
+        writeln!(self.writer, "\n1. ▶️  {}", t("menu.action_start"))?;
+        writeln!(self.writer, "2. ✅ {}", t("menu.action_complete"))?;
+        writeln!(self.writer, "3. 🗑️  {}", t("menu.delete"))?;
+        writeln!(self.writer, "4. ⭐ {}", t("menu.bulk_set_priority"))?;
+        writeln!(self.writer, "5. 🏷️  {}", t("menu.bulk_set_category"))?;
+        writeln!(self.writer, "6. 📅 {}", t("menu.bulk_set_due_date"))?;
+        writeln!(self.writer, "0. {}", t("menu.back"))?;
+
+        let action = match self.read_option(t("menu.choose_action"), 6)? {
+            Some(1) => BulkAction::Start,
+            Some(2) => BulkAction::Complete,
+            Some(3) => {
+                let prompt = tf("menu.confirm_bulk_delete", &[&ids.len()]);
+                if !self.confirm(&prompt)? {
+                    output::print_warning(&mut self.writer, t("menu.cancelled"))?;
+                    return self.pause();
+                }
+                BulkAction::Delete
+            }
+            Some(4) => {
+                match self.choose_priority(t("menu.priorities"), t("menu.choose_priority"), None)? {
+                    Some(priority) => BulkAction::SetPriority(priority),
+                    None => return self.fail(t("menu.invalid_priority")),
+                }
+            }
+            Some(5) => {
+                match self.choose_category(t("menu.categories"), t("menu.choose_category"), None)? {
+                    Some(category) => BulkAction::SetCategory(category),
+                    None => return self.fail(t("menu.invalid_category")),
+                }
+            }
+            Some(6) => {
+                let prompt = tf("menu.prompt_bulk_due_date", &[&i18n::date_hint()]);
+                let input = self.read_line(&prompt)?;
+                if input.is_empty() {
+                    BulkAction::SetDueDate(None)
+                } else {
+                    match NaiveDate::parse_from_str(&input, &i18n::date_format()) {
+                        Ok(date) => BulkAction::SetDueDate(Some(date)),
+                        Err(_) => {
+                            return self.fail(&tf("menu.invalid_date", &[&i18n::date_hint()]));
+                        }
+                    }
+                }
+            }
+            _ => return Ok(()),
+        };
+
+        match self.service.bulk(&ids, action) {
+            Ok(report) => output::print_bulk_report(&mut self.writer, &report)?,
+            Err(e) => output::print_error(&mut self.writer, &e.to_string())?,
+        }
+        self.pause()
+    }
+
+    fn projects(&mut self) -> io::Result<()> {
+        self.clear_screen()?;
+        output::print_header(&mut self.writer, t("menu.projects_title"))?;
+
+        writeln!(self.writer, "1. {}", t("menu.project_list"))?;
+        writeln!(self.writer, "2. {}", t("menu.project_create"))?;
+        writeln!(self.writer, "3. {}", t("menu.project_switch"))?;
+        writeln!(self.writer, "4. {}", t("menu.project_move"))?;
+        writeln!(self.writer, "5. {}", t("menu.project_delete"))?;
+        writeln!(self.writer, "6. {}", t("menu.project_statistics"))?;
+        writeln!(self.writer, "0. {}", t("menu.back"))?;
+
+        let option = self.read_option(t("menu.choose_action"), 6)?;
+
+        match option {
+            Some(1) => {
+                let projects: Vec<_> = self
+                    .service
+                    .list_projects()
+                    .iter()
+                    .map(|p| (p, self.service.filter_by_project(Some(&p.name)).len()))
+                    .collect();
+                let active = self.service.active_project().map(|p| p.name.as_str());
+                output::print_projects(&mut self.writer, &projects, active)?;
+            }
+            Some(2) => {
+                let name = self.read_line(t("menu.prompt_project_name"))?;
+                let description = self.read_line(t("menu.prompt_project_description"))?;
+                let default = ProjectColor::White;
+                let prompt = tf("menu.choose_color", &[&default.as_str()]);
+                let color = match self.choose(
+                    "",
+                    &prompt,
+                    &ProjectColor::all(),
+                    ProjectColor::as_str,
+                    Some(default),
+                )? {
+                    Some(color) => color,
+                    None => return self.fail(t("menu.invalid_option")),
+                };
+                match self.service.create_project(name, description, color) {
+                    Ok(project) => {
+                        let message = tf("menu.project_created", &[&project.name]);
+                        output::print_success(&mut self.writer, &message)?;
+                    }
+                    Err(e) => output::print_error(&mut self.writer, &e.to_string())?,
+                }
+            }
+            Some(3) => {
+                let name = self.read_line(t("menu.prompt_project_switch"))?;
+                let project = Some(name.as_str()).filter(|n| !n.is_empty());
+                match self.service.set_active_project(project) {
+                    Ok(()) => {
+                        let message = match self.service.active_project() {
+                            Some(project) => tf("menu.project_switched", &[&project.name]),
+                            None => t("menu.project_cleared").to_string(),
+                        };
+                        output::print_success(&mut self.writer, &message)?;
+                    }
+                    Err(e) => output::print_error(&mut self.writer, &e.to_string())?,
+                }
+            }
+            Some(4) => {
+                let id = match self.read_number(t("menu.prompt_id"))? {
+                    Some(id) => id,
+                    None => return self.fail(t("menu.invalid_id")),
+                };
+                let name = self.read_line(t("menu.prompt_project_move"))?;
+                let project = Some(name.as_str()).filter(|n| !n.is_empty());
+                match self.service.move_task(id, project) {
+                    Ok(()) => output::print_success(&mut self.writer, t("menu.task_moved"))?,
+                    Err(e) => output::print_error(&mut self.writer, &e.to_string())?,
+                }
+            }
+            Some(5) => {
+                let name = self.read_line(t("menu.prompt_project_name"))?;
+                if !self.confirm(t("menu.confirm_delete_project"))? {
+                    output::print_warning(&mut self.writer, t("menu.cancelled"))?;
+                } else {
+                    match self.service.delete_project(&name) {
+                        Ok(()) => {
+                            output::print_success(&mut self.writer, t("menu.project_deleted"))?
+                        }
+                        Err(e) => output::print_error(&mut self.writer, &e.to_string())?,
+                    }
+                }
+            }
+            Some(6) => {
+                let by_project = self.service.get_statistics_by_project();
+                output::print_project_statistics(&mut self.writer, &by_project)?;
+            }
+            _ => return Ok(()),
+        }
+
+        self.pause()
+    }
+
+    fn templates(&mut self) -> io::Result<()> {
+        self.clear_screen()?;
+        output::print_header(&mut self.writer, t("menu.templates_title"))?;
+
+        writeln!(self.writer, "1. {}", t("menu.template_list"))?;
+        writeln!(self.writer, "2. {}", t("menu.template_use"))?;
+        writeln!(self.writer, "3. {}", t("menu.template_save"))?;
+        writeln!(self.writer, "4. {}", t("menu.template_delete"))?;
+        writeln!(self.writer, "0. {}", t("menu.back"))?;
+
+        let option = self.read_option(t("menu.choose_action"), 4)?;
+
+        match option {
+            Some(1) => output::print_templates(&mut self.writer, self.service.list_templates())?,
+            Some(2) => return self.use_template(),
+            Some(3) => {
+                let input = self.read_line(t("menu.prompt_ids"))?;
+                let ids = match bulk::parse_ids(&input, self.service.highest_id()) {
+                    Some(ids) => ids,
+                    None => return self.fail(t("menu.invalid_ids")),
+                };
+                let name = self.read_line(t("menu.prompt_template_name"))?;
+                let description = self.read_line(t("menu.prompt_description"))?;
+                match self.service.template_from_tasks(name, description, &ids) {
+                    Ok(template) => {
+                        let message = tf("menu.template_saved", &[&template.name]);
+                        output::print_success(&mut self.writer, &message)?;
+                    }
+                    Err(e) => output::print_error(&mut self.writer, &e.to_string())?,
+                }
+            }
+            Some(4) => {
+                let name = self.read_line(t("menu.prompt_template_name"))?;
+                if !self.confirm(t("menu.confirm_delete_template"))? {
+                    output::print_warning(&mut self.writer, t("menu.cancelled"))?;
+                } else {
+                    match self.service.delete_template(&name) {
+                        Ok(()) => {
+                            output::print_success(&mut self.writer, t("menu.template_deleted"))?
+                        }
+                        Err(e) => output::print_error(&mut self.writer, &e.to_string())?,
+                    }
+                }
+            }
+            _ => return Ok(()),
+        }
+
+        self.pause()
+    }
+
+    /// Escolhe um modelo, pede o valor de cada variável e cria as tarefas
+    fn use_template(&mut self) -> io::Result<()> {
+        let templates = self.service.list_templates();
+        output::print_templates(&mut self.writer, templates)?;
+        if templates.is_empty() {
+            return self.pause();
+        }
+
+        let count = templates.len() as u32;
+        let template = match self.read_option(t("menu.choose_template"), count)? {
+            Some(n) if n > 0 => self.service.list_templates()[n as usize - 1].clone(),
+            _ => return self.fail(t("menu.invalid_option")),
+        };
+
+        let mut values = HashMap::new();
+        for variable in template.variables() {
+            let value = self.read_line(&tf("menu.prompt_variable", &[&variable]))?;
+            values.insert(variable, value);
+        }
+
+        // A data base só importa se alguma tarefa tiver prazo
+        let today = self.service.clock().now().date_naive();
+        let start = if template.tasks.iter().any(|task| task.due_in_days.is_some()) {
+            match self.read_date_or("menu.prompt_template_start", today)? {
+                Some(date) => date,
+                None => return self.fail(&tf("menu.invalid_date", &[&i18n::date_hint()])),
+            }
+        } else {
+            today
+        };
+
+        match self
+            .service
+            .instantiate_template(&template.name, &values, start)
+        {
+            Ok(ids) => {
+                let list: Vec<String> = ids.iter().map(|id| format!("#{}", id)).collect();
+                let message = tf("menu.template_used", &[&ids.len(), &list.join(", ")]);
+                output::print_success(&mut self.writer, &message)?;
+            }
+            Err(e) => output::print_error(&mut self.writer, &e.to_string())?,
+        }
+
+        self.pause()
+    }
+
+    fn encryption(&mut self) -> io::Result<()> {
+        self.clear_screen()?;
+        output::print_header(&mut self.writer, t("menu.encryption_title"))?;
+
+        let encrypted = self.service.is_encrypted();
+        let status = if encrypted {
+            t("menu.encryption_on")
+        } else {
+            t("menu.encryption_off")
+        };
+        writeln!(self.writer, "{}\n", status)?;
+        let change = if encrypted {
+            t("menu.passphrase_change")
+        } else {
+            t("menu.passphrase_set")
+        };
+        writeln!(self.writer, "1. {}", change)?;
+        writeln!(self.writer, "2. {}", t("menu.decrypt_export"))?;
+        writeln!(self.writer, "0. {}", t("menu.back"))?;
+
+        let option = self.read_option(t("menu.choose_action"), 2)?;
+
+        match option {
+            Some(1) => {
+                let current = if encrypted {
+                    self.read_secret(t("menu.prompt_current_passphrase"))?
+                } else {
+                    String::new()
+                };
+                let new = self.read_secret(t("menu.prompt_new_passphrase"))?;
+                let confirmation = self.read_secret(t("menu.prompt_confirm_passphrase"))?;
+                if new != confirmation {
+                    return self.fail(t("error.passphrase_mismatch"));
+                }
+                match self.service.change_passphrase(&current, &new) {
+                    Ok(()) => {
+                        output::print_success(&mut self.writer, t("menu.passphrase_changed"))?
+                    }
+                    Err(e) => output::print_error(&mut self.writer, &e.to_string())?,
+                }
+            }
+            Some(2) => {
+                output::print_warning(&mut self.writer, t("menu.export_warning"))?;
+                let path = self.read_line(t("menu.prompt_export_path"))?;
+                if path.is_empty() {
+                    output::print_warning(&mut self.writer, t("menu.cancelled"))?;
+                } else {
+                    match self.service.export_decrypted(Path::new(&path)) {
+                        Ok(count) => {
+                            let message = tf("menu.exported", &[&count, &path]);
+                            output::print_success(&mut self.writer, &message)?;
+                        }
+                        Err(e) => output::print_error(&mut self.writer, &e.to_string())?,
+                    }
+                }
+            }
+            _ => return Ok(()),
+        }
+
+        self.pause()
+    }
+
+    fn trash_and_archive(&mut self) -> io::Result<()> {
+        self.clear_screen()?;
+        output::print_header(&mut self.writer, t("menu.trash_title"))?;
+
+        writeln!(self.writer, "1. {}", t("menu.trash_list"))?;
+        writeln!(self.writer, "2. {}", t("menu.trash_restore"))?;
+        writeln!(self.writer, "3. {}", t("menu.trash_purge"))?;
+        writeln!(self.writer, "4. {}", t("menu.trash_empty"))?;
+        writeln!(self.writer, "5. {}", t("menu.archive_completed"))?;
+        writeln!(self.writer, "6. {}", t("menu.archive_list"))?;
+        writeln!(self.writer, "7. {}", t("menu.archive_restore"))?;
+        writeln!(self.writer, "8. {}", t("menu.statistics_all"))?;
+        writeln!(self.writer, "0. {}", t("menu.back"))?;
+
+        let option = self.read_option(t("menu.choose_action"), 8)?;
+
+        match option {
+            Some(1) => {
+                output::print_header(&mut self.writer, t("menu.trash_header"))?;
+                let tasks: Vec<&Task> = self.service.list_trash().iter().collect();
+                output::print_task_list(&mut self.writer, &tasks, self.service.clock().now())?;
+            }
+            Some(2) => {
+                let id = match self.read_number(t("menu.prompt_id"))? {
+                    Some(id) => id,
+                    None => return self.fail(t("menu.invalid_id")),
+                };
+                match self.service.restore_task(id) {
+                    Ok(()) => output::print_success(&mut self.writer, t("menu.task_restored"))?,
+                    Err(e) => output::print_error(&mut self.writer, &e.to_string())?,
+                }
+            }
+            Some(3) => {
+                let id = match self.read_number(t("menu.prompt_id"))? {
+                    Some(id) => id,
+                    None => return self.fail(t("menu.invalid_id")),
+                };
+                if !self.confirm(t("menu.confirm_purge"))? {
+                    output::print_warning(&mut self.writer, t("menu.cancelled"))?;
+                } else {
+                    match self.service.purge_task(id) {
+                        Ok(()) => output::print_success(&mut self.writer, t("menu.task_purged"))?,
+                        Err(e) => output::print_error(&mut self.writer, &e.to_string())?,
+                    }
+                }
+            }
+            Some(4) => {
+                if !self.confirm(t("menu.confirm_purge"))? {
+                    output::print_warning(&mut self.writer, t("menu.cancelled"))?;
+                } else {
+                    match self.service.purge_trash_older_than(0) {
+                        Ok(n) => {
+                            let message = tf("menu.trash_emptied", &[&n]);
+                            output::print_success(&mut self.writer, &message)?;
+                        }
+                        Err(e) => output::print_error(&mut self.writer, &e.to_string())?,
+                    }
+                }
+            }
+            Some(5) => match self.service.archive_completed() {
+                Ok(n) => {
+                    output::print_success(&mut self.writer, &tf("menu.tasks_archived", &[&n]))?
+                }
+                Err(e) => output::print_error(&mut self.writer, &e.to_string())?,
+            },
+            Some(6) => {
+                output::print_header(&mut self.writer, t("menu.archive_header"))?;
+                let tasks: Vec<&Task> = self.service.list_archived().iter().collect();
+                output::print_task_list(&mut self.writer, &tasks, self.service.clock().now())?;
+            }
+            Some(7) => {
+                let id = match self.read_number(t("menu.prompt_id"))? {
+                    Some(id) => id,
+                    None => return self.fail(t("menu.invalid_id")),
+                };
+                match self.service.unarchive_task(id) {
+                    Ok(()) => output::print_success(&mut self.writer, t("menu.task_restored"))?,
+                    Err(e) => output::print_error(&mut self.writer, &e.to_string())?,
+                }
+            }
+            Some(8) => {
+                let stats = self.service.get_statistics_with_archived();
+                output::print_statistics(&mut self.writer, &stats)?;
+            }
+            _ => return Ok(()),
+        }
+
+        self.pause()
+    }
+
+    fn filter_tasks(&mut self) -> io::Result<()> {
+        self.clear_screen()?;
+        output::print_header(&mut self.writer, t("menu.filter_title"))?;
+
+        writeln!(self.writer, "1. {}", t("menu.filter_status"))?;
+        writeln!(self.writer, "2. {}", t("menu.filter_category"))?;
+        writeln!(self.writer, "3. {}", t("menu.filter_priority"))?;
+        writeln!(self.writer, "4. {}", t("menu.filter_overdue"))?;
+        writeln!(self.writer, "5. {}", t("menu.filter_mine"))?;
+        writeln!(self.writer, "6. {}", t("menu.filter_assigned_by_me"))?;
+        writeln!(self.writer, "0. {}", t("menu.back"))?;
+
+        let option = self.read_option(t("menu.choose_filter"), 6)?;
 
         match option {
             Some(1) => self.filter_by_status(),
             Some(2) => self.filter_by_category(),
             Some(3) => self.filter_by_priority(),
             Some(4) => self.show_overdue(),
-            _ => {}
+            Some(5) => self.filter_by_user(TaskService::my_tasks),
+            Some(6) => self.filter_by_user(TaskService::assigned_by_me),
+            _ => Ok(()),
         }
     }
 
-    fn filter_by_status(&self) {
-        use crate::models::Status;
+    /// Filtros que dependem do usuário atual
+    fn filter_by_user(&mut self, filter: fn(&TaskService) -> Vec<&Task>) -> io::Result<()> {
+        if self.service.current_user().is_none() {
+            return self.fail(t("menu.no_current_user"));
+        }
+
+        let tasks = filter(&self.service);
+        output::print_task_list(&mut self.writer, &tasks, self.service.clock().now())?;
+        self.pause()
+    }
 
-        println!("\n1. Pendentes");
-        println!("2. Em Andamento");
-        println!("3. Concluídas");
+    fn filter_by_status(&mut self) -> io::Result<()> {
+        writeln!(self.writer, "\n1. {}", t("menu.status_pending"))?;
+        writeln!(self.writer, "2. {}", t("menu.status_in_progress"))?;
+        writeln!(self.writer, "3. {}", t("menu.status_completed"))?;
 
-        let option = input::read_option("Escolha o status: ", 3);
+        let option = self.read_option(t("menu.choose_status"), 3)?;
 
         let status = match option {
             Some(1) => Status::Pending,
             Some(2) => Status::InProgress,
             Some(3) => Status::Completed,
-            _ => {
-                output::print_error("Opção inválida!");
-                input::pause();
-                return;
-            }
+            _ => return self.fail(t("menu.invalid_option")),
         };
 
         let tasks = self.service.filter_by_status(status);
-        output::print_task_list(&tasks);
-        input::pause();
+        output::print_task_list(&mut self.writer, &tasks, self.service.clock().now())?;
+        self.pause()
     }
 
-    fn filter_by_category(&self) {
-        println!();
-        for (i, cat) in Category::all().iter().enumerate() {
-            println!("{}. {}", i + 1, cat.as_str());
-        }
-
-        let option = input::read_option("Escolha a categoria: ", Category::all().len() as u32);
-
-        let category = match option {
-            Some(n) => Category::all()[(n - 1) as usize],
-            None => {
-                output::print_error("Opção inválida!");
-                input::pause();
-                return;
-            }
+    fn filter_by_category(&mut self) -> io::Result<()> {
+        let category = match self.choose_category("", t("menu.choose_category"), None)? {
+            Some(category) => category,
+            None => return self.fail(t("menu.invalid_option")),
         };
 
         let tasks = self.service.filter_by_category(category);
-        output::print_task_list(&tasks);
-        input::pause();
+        output::print_task_list(&mut self.writer, &tasks, self.service.clock().now())?;
+        self.pause()
     }
 
-    fn filter_by_priority(&self) {
-        println!();
-        for (i, pri) in Priority::all().iter().enumerate() {
-            println!("{}. {}", i + 1, pri.as_str());
-        }
-
-        let option = input::read_option("Escolha a prioridade: ", Priority::all().len() as u32);
-
-        let priority = match option {
-            Some(n) => Priority::all()[(n - 1) as usize],
-            None => {
-                output::print_error("Opção inválida!");
-                input::pause();
-                return;
-            }
+    fn filter_by_priority(&mut self) -> io::Result<()> {
+        let priority = match self.choose_priority("", t("menu.choose_priority"), None)? {
+            Some(priority) => priority,
+            None => return self.fail(t("menu.invalid_option")),
         };
 
         let tasks = self.service.filter_by_priority(priority);
-        output::print_task_list(&tasks);
-        input::pause();
+        output::print_task_list(&mut self.writer, &tasks, self.service.clock().now())?;
+        self.pause()
     }
 
-    fn show_overdue(&self) {
+    fn show_overdue(&mut self) -> io::Result<()> {
         let tasks = self.service.get_overdue();
-        output::print_task_list(&tasks);
-        input::pause();
+        output::print_task_list(&mut self.writer, &tasks, self.service.clock().now())?;
+        self.pause()
     }
 
-    fn show_statistics(&self) {
-        output::clear_screen();
+    fn show_statistics(&mut self) -> io::Result<()> {
+        self.clear_screen()?;
         let stats = self.service.get_statistics();
-        output::print_statistics(&stats);
-        input::pause();
+        output::print_statistics(&mut self.writer, &stats)?;
+        let by_user = self.service.get_statistics_by_user();
+        output::print_user_statistics(&mut self.writer, &by_user)?;
+        self.pause()
+    }
+
+    fn show_board(&mut self) -> io::Result<()> {
+        self.clear_screen()?;
+        output::print_header(&mut self.writer, t("board.title"))?;
+        let tasks = self.service.active_tasks();
+        let limits = self.service.wip_limits();
+        output::print_board(&mut self.writer, &tasks, &limits, self.service.clock().now())?;
+        self.pause()
     }
-}
\ No newline at end of file
+
+    /// Lê uma data; Enter vazio usa `default`
+    fn read_date_or(
+        &mut self,
+        key: &'static str,
+        default: NaiveDate,
+    ) -> io::Result<Option<NaiveDate>> {
+        let hint = i18n::date_hint();
+        let shown = default.format(&i18n::date_format()).to_string();
+        let input = self.read_line(&tf(key, &[&hint, &shown]))?;
+        if input.is_empty() {
+            return Ok(Some(default));
+        }
+        Ok(NaiveDate::parse_from_str(&input, &i18n::date_format()).ok())
+    }
+
+    fn show_analytics(&mut self) -> io::Result<()> {
+        self.clear_screen()?;
+        output::print_header(&mut self.writer, t("analytics.title"))?;
+
+        // Período padrão: as últimas duas semanas
+        let today = self.service.clock().now().date_naive();
+        let from = match self.read_date_or("menu.prompt_from", today - Duration::days(13))? {
+            Some(date) => date,
+            None => return self.fail(&tf("menu.invalid_date", &[&i18n::date_hint()])),
+        };
+        let to = match self.read_date_or("menu.prompt_to", today)? {
+            Some(date) => date,
+            None => return self.fail(&tf("menu.invalid_date", &[&i18n::date_hint()])),
+        };
+        if from > to {
+            return self.fail(t("menu.invalid_range"));
+        }
+
+        let analytics = self.service.get_analytics(from, to);
+        output::print_analytics(&mut self.writer, &analytics)?;
+        self.pause()
+    }
+}
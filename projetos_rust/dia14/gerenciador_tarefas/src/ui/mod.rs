@@ -2,5 +2,12 @@
 pub mod menu;
 pub mod input;
 pub mod output;
+pub mod tui;
+pub mod text;
+pub mod markdown;
+pub mod theme;
+pub mod json;
+pub mod commands;
+pub mod focus;
 
 pub use menu::Menu;
\ No newline at end of file
@@ -1,120 +1,975 @@
-use crate::models::Task;
+use crate::i18n::{self, t, tf};
+use crate::models::{Priority, Project, Status, Task, Template};
 use crate::services::task_service::Statistics;
+use crate::services::{Analytics, BulkReport, Score, WipLimits};
+use crate::ui::markdown;
+use crate::ui::text::{self, display_width};
+use crate::ui::theme::{Role, paint, user_text};
+use chrono::{DateTime, Local};
+use std::fmt::Display;
+use std::io::{self, Write};
+
+/// Largura interna da caixa de detalhes da tarefa
+const BOX_WIDTH: usize = 56;
 
 /// Limpa a tela
-pub fn clear_screen() {
-    print!("\x1B[2J\x1B[1;1H");
+pub fn clear_screen<W: Write>(out: &mut W) -> io::Result<()> {
+    write!(out, "\x1B[2J\x1B[1;1H")
 }
 
 /// Exibe o cabeçalho
-pub fn print_header(title: &str) {
-    println!("\n╔══════════════════════════════════════════════════════════╗");
-    println!("║  {:^54}  ║", title);
-    println!("╚══════════════════════════════════════════════════════════╝\n");
+pub fn print_header<W: Write>(out: &mut W, title: &str) -> io::Result<()> {
+    writeln!(
+        out,
+        "\n╔══════════════════════════════════════════════════════════╗"
+    )?;
+    writeln!(
+        out,
+        "║  {}  ║",
+        paint(Role::Heading, &format!("{:^54}", title))
+    )?;
+    writeln!(
+        out,
+        "╚══════════════════════════════════════════════════════════╝\n"
+    )
 }
 
 /// Exibe uma mensagem de sucesso
-pub fn print_success(message: &str) {
-    println!("\n✅ {}", message);
+pub fn print_success<W: Write>(out: &mut W, message: &str) -> io::Result<()> {
+    writeln!(
+        out,
+        "\n{}",
+        paint(Role::Success, &format!("✅ {}", message))
+    )
 }
 
 /// Exibe uma mensagem de erro
-pub fn print_error(message: &str) {
-    println!("\n❌ Erro: {}", message);
+pub fn print_error<W: Write>(out: &mut W, message: &str) -> io::Result<()> {
+    let line = format!("❌ {}", tf("output.error", &[&message]));
+    writeln!(out, "\n{}", paint(Role::Error, &line))
 }
 
 /// Exibe uma mensagem de aviso
-pub fn print_warning(message: &str) {
-    println!("\n⚠️  {}", message);
+pub fn print_warning<W: Write>(out: &mut W, message: &str) -> io::Result<()> {
+    writeln!(
+        out,
+        "\n{}",
+        paint(Role::Warning, &format!("⚠️  {}", message))
+    )
+}
+
+/// Formata uma duração em minutos como "1h 05min" ou "45min"
+pub fn format_minutes(minutes: i64) -> String {
+    let minutes = minutes.max(0);
+    if minutes >= 60 {
+        format!("{}h {:02}min", minutes / 60, minutes % 60)
+    } else {
+        format!("{}min", minutes)
+    }
+}
+
+/// Formata um tamanho em bytes como "512 B", "12.3 KB" ou "4.0 MB"
+pub fn format_size(bytes: u64) -> String {
+    const KB: f64 = 1024.0;
+    let size = bytes as f64;
+    if size >= KB * KB {
+        format!("{:.1} MB", size / (KB * KB))
+    } else if size >= KB {
+        format!("{:.1} KB", size / KB)
+    } else {
+        format!("{} B", bytes)
+    }
+}
+
+/// Exibe uma linha "rótulo: valor" dentro da caixa, cortando o valor
+fn print_field<W: Write>(
+    out: &mut W,
+    inner: usize,
+    label: &str,
+    value: &dyn Display,
+) -> io::Result<()> {
+    print_painted_field(out, inner, label, value, None)
+}
+
+/// Como [`print_field`], com o valor na cor do papel
+fn print_painted_field<W: Write>(
+    out: &mut W,
+    inner: usize,
+    label: &str,
+    value: &dyn Display,
+    role: Option<Role>,
+) -> io::Result<()> {
+    let width = inner.saturating_sub(display_width(label) + 2);
+    let value = text::fit(&value.to_string(), width);
+    let value = match role {
+        Some(role) => paint(role, &value),
+        None => value,
+    };
+    writeln!(out, "│ {}: {} │", label, value)
 }
 
-/// Exibe uma tarefa formatada
-pub fn print_task(task: &Task) {
-    println!("┌─────────────────────────────────────────────────────────┐");
-    println!("│ ID: {:<52} │", task.id);
-    println!("│ Título: {:<48} │", task.title);
-    println!("│ Descrição: {:<45} │", task.description);
-    println!("│ Categoria: {:<45} │", task.category.as_str());
-    println!("│ Prioridade: {:<44} │", task.priority.as_str());
-    println!("│ Status: {:<48} │", task.status.as_str());
+/// Exibe um campo em Markdown, quebrado em várias linhas alinhadas ao valor
+fn print_markdown_field<W: Write>(
+    out: &mut W,
+    inner: usize,
+    label: &str,
+    value: &str,
+) -> io::Result<()> {
+    let width = inner.saturating_sub(display_width(label) + 2);
+    let lines = markdown::render(value, width);
+    if lines.is_empty() {
+        return print_field(out, inner, label, &"");
+    }
+
+    let indent = " ".repeat(display_width(label) + 2);
+    for (i, line) in lines.iter().enumerate() {
+        let line = user_text(&text::pad(line, width));
+        if i == 0 {
+            writeln!(out, "│ {}: {} │", label, line)?;
+        } else {
+            writeln!(out, "│ {}{} │", indent, line)?;
+        }
+    }
+    Ok(())
+}
+
+/// Exibe uma tarefa formatada, na largura do terminal
+pub fn print_task<W: Write>(out: &mut W, task: &Task, now: DateTime<Local>) -> io::Result<()> {
+    print_task_with_width(out, task, now, text::terminal_width())
+}
+
+/// Exibe uma tarefa formatada cabendo em `width` colunas
+///
+/// A caixa tem no máximo `BOX_WIDTH` colunas por dentro; a descrição é
+/// renderizada como Markdown e quebrada dentro dela. Atraso e tempo gasto
+/// são calculados no instante `now`.
+pub fn print_task_with_width<W: Write>(
+    out: &mut W,
+    task: &Task,
+    now: DateTime<Local>,
+    width: usize,
+) -> io::Result<()> {
+    let inner = BOX_WIDTH.min(width.saturating_sub(4));
+    writeln!(out, "┌{}┐", "─".repeat(inner + 2))?;
+    print_field(out, inner, t("field.id"), &task.id)?;
+    print_field(out, inner, t("field.uuid"), &task.uuid)?;
+    print_field(out, inner, t("field.title"), &user_text(&task.title))?;
+    print_markdown_field(out, inner, t("field.description"), &task.description)?;
+    print_field(out, inner, t("field.category"), &task.category.as_str())?;
+    print_painted_field(
+        out,
+        inner,
+        t("field.priority"),
+        &task.priority.as_str(),
+        Some(Role::priority(task.priority)),
+    )?;
+    print_painted_field(
+        out,
+        inner,
+        t("field.status"),
+        &task.status.as_str(),
+        Some(Role::status(task.status)),
+    )?;
 
     if let Some(due_date) = task.due_date {
-        let overdue = if task.is_overdue() { " (ATRASADA!)" } else { "" };
-        println!("│ Vencimento: {:<40}{} │", due_date, overdue);
+        let (overdue, role) = if task.is_overdue_at(&now) {
+            (t("output.overdue_tag"), Some(Role::Overdue))
+        } else {
+            ("", None)
+        };
+        let time = task
+            .due_time
+            .map(|time| time.format(" %H:%M").to_string())
+            .unwrap_or_default();
+        let value = format!(
+            "{}{}{}",
+            due_date.format(&i18n::date_format()),
+            time,
+            overdue
+        );
+        print_painted_field(out, inner, t("field.due_date"), &value, role)?;
     }
 
-    println!(
-        "│ Criada em: {:<45} │",
-        task.created_at.format("%d/%m/%Y %H:%M")
-    );
+    if !task.time_entries.is_empty() || task.estimate_minutes.is_some() {
+        let running = if task.is_timer_running() {
+            t("output.timer_running_tag")
+        } else {
+            ""
+        };
+        let value = format!(
+            "{}{}",
+            format_minutes(task.time_spent(now).num_minutes()),
+            running
+        );
+        print_field(out, inner, t("field.time_spent"), &value)?;
+    }
+
+    if let Some(estimate) = task.estimate_minutes {
+        let over = if task.is_over_estimate(now) {
+            t("output.over_estimate_tag")
+        } else {
+            ""
+        };
+        let value = format!("{}{}", format_minutes(estimate as i64), over);
+        print_field(out, inner, t("field.estimate"), &value)?;
+    }
+
+    if !task.pomodoros.is_empty() {
+        print_field(out, inner, t("field.pomodoros"), &task.pomodoros.len())?;
+    }
+
+    if let Some(assignee) = &task.assignee {
+        print_field(out, inner, t("field.assignee"), &user_text(assignee))?;
+    }
+
+    if let Some(created_by) = &task.created_by {
+        print_field(out, inner, t("field.created_by"), &user_text(created_by))?;
+    }
+
+    if let Some(project) = &task.project {
+        print_field(out, inner, t("field.project"), &user_text(project))?;
+    }
+
+    if !task.blocked_by.is_empty() {
+        let ids: Vec<String> = task
+            .blocked_by
+            .iter()
+            .map(|id| format!("#{}", id))
+            .collect();
+        print_field(out, inner, t("field.blocked_by"), &ids.join(", "))?;
+    }
+
+    print_field(
+        out,
+        inner,
+        t("field.created_at"),
+        &task.created_at.format(&i18n::datetime_format()),
+    )?;
 
     if let Some(completed_at) = task.completed_at {
-        println!(
-            "│ Concluída em: {:<42} │",
-            completed_at.format("%d/%m/%Y %H:%M")
-        );
+        print_field(
+            out,
+            inner,
+            t("field.completed_at"),
+            &completed_at.format(&i18n::datetime_format()),
+        )?;
+    }
+
+    writeln!(out, "└{}┘", "─".repeat(inner + 2))?;
+
+    if !task.notes.is_empty() {
+        writeln!(out, "📝 {}", t("output.notes"))?;
+        for note in &task.notes {
+            let date = note.created_at.format(&i18n::datetime_format()).to_string();
+            let line = match &note.author {
+                Some(author) => format!("   [{}] {}: {}", date, author, note.text),
+                None => format!("   [{}] {}", date, note.text),
+            };
+            let line = user_text(&line);
+            writeln!(out, "{}", text::truncate(&line, width))?;
+        }
     }
 
-    println!("└─────────────────────────────────────────────────────────┘");
+    if !task.attachments.is_empty() {
+        writeln!(out, "📎 {}", t("output.attachments"))?;
+        for (i, attachment) in task.attachments.iter().enumerate() {
+            let line = format!(
+                "   {}. {} ({})",
+                i + 1,
+                user_text(&attachment.name),
+                format_size(attachment.size)
+            );
+            writeln!(out, "{}", text::truncate(&line, width))?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Larguras das colunas fixas da lista de tarefas
+const LIST_ID_WIDTH: usize = 4;
+const LIST_CATEGORY_WIDTH: usize = 12;
+const LIST_PRIORITY_WIDTH: usize = 10;
+const LIST_STATUS_WIDTH: usize = 14;
+
+/// Exibe uma lista de tarefas, na largura do terminal
+pub fn print_task_list<W: Write>(
+    out: &mut W,
+    tasks: &[&Task],
+    now: DateTime<Local>,
+) -> io::Result<()> {
+    print_task_list_with_width(out, tasks, now, text::terminal_width())
 }
 
-/// Exibe uma lista de tarefas
-pub fn print_task_list(tasks: &[&Task]) {
+/// Exibe uma lista de tarefas cabendo em `width` colunas
+///
+/// O título fica com o espaço que sobrar das outras colunas (entre 10 e
+/// 40 colunas) e cada linha é cortada na largura. As tarefas atrasadas no
+/// instante `now` ganham destaque.
+pub fn print_task_list_with_width<W: Write>(
+    out: &mut W,
+    tasks: &[&Task],
+    now: DateTime<Local>,
+    width: usize,
+) -> io::Result<()> {
     if tasks.is_empty() {
-        print_warning("Nenhuma tarefa encontrada.");
-        return;
+        return print_warning(out, t("output.no_tasks"));
     }
 
-    println!("\n{:<4} {:<25} {:<12} {:<10} {:<12}", "ID", "Título", "Categoria", "Prioridade", "Status");
-    println!("{}", "─".repeat(70));
+    let fixed = LIST_ID_WIDTH + LIST_CATEGORY_WIDTH + LIST_PRIORITY_WIDTH + LIST_STATUS_WIDTH + 4;
+    let title_width = width.saturating_sub(fixed).clamp(10, 40);
+    // As cores entram depois de completar cada coluna; o corte final
+    // preserva os códigos ANSI
+    let row = |id: &str, title: &str, category: &str, priority: String, status: String| {
+        let line = format!(
+            "{} {} {} {} {}",
+            text::fit(id, LIST_ID_WIDTH),
+            text::fit(title, title_width),
+            text::fit(category, LIST_CATEGORY_WIDTH),
+            priority,
+            status
+        );
+        text::truncate(line.trim_end(), width)
+    };
+
+    writeln!(out)?;
+    let header = row(
+        t("field.id"),
+        t("field.title"),
+        t("field.category"),
+        text::fit(t("field.priority"), LIST_PRIORITY_WIDTH),
+        t("field.status").to_string(),
+    );
+    writeln!(out, "{}", paint(Role::Heading, &header))?;
+    writeln!(out, "{}", "─".repeat(width.min(fixed + title_width)))?;
 
     for task in tasks {
-        let title = if task.title.len() > 25 {
-            format!("{}...", &task.title[..22])
+        let priority = text::fit(task.priority.as_str(), LIST_PRIORITY_WIDTH);
+        let status = if task.is_overdue_at(&now) {
+            paint(Role::Overdue, &format!("⚠️ {}", task.status.as_str()))
         } else {
-            task.title.clone()
+            paint(Role::status(task.status), task.status.as_str())
         };
-
-        let overdue = if task.is_overdue() { "⚠️ " } else { "" };
-
-        println!(
-            "{:<4} {:<25} {:<12} {:<10} {}{}",
-            task.id,
-            title,
-            task.category.as_str(),
-            task.priority.as_str(),
-            overdue,
-            task.status.as_str()
-        );
+        writeln!(
+            out,
+            "{}",
+            row(
+                &task.id.to_string(),
+                &user_text(&task.title),
+                task.category.as_str(),
+                paint(Role::priority(task.priority), &priority),
+                status
+            )
+        )?;
     }
 
-    println!("\nTotal: {} tarefa(s)", tasks.len());
+    writeln!(out, "\n{}", tf("output.total_tasks", &[&tasks.len()]))
 }
 
 /// Exibe estatísticas
-pub fn print_statistics(stats: &Statistics) {
-    println!("\n╔══════════════════════════════════════════════════════════╗");
-    println!("║                      ESTATÍSTICAS                        ║");
-    println!("╚══════════════════════════════════════════════════════════╝");
-
-    println!("\n📊 Resumo Geral:");
-    println!("   Total de tarefas: {}", stats.total);
-    println!("   ✅ Concluídas: {}", stats.completed);
-    println!("   🔄 Em andamento: {}", stats.in_progress);
-    println!("   ⏳ Pendentes: {}", stats.pending);
-    println!("   ⚠️  Atrasadas: {}", stats.overdue);
-
-    println!("\n📁 Por Categoria:");
+pub fn print_statistics<W: Write>(out: &mut W, stats: &Statistics) -> io::Result<()> {
+    writeln!(
+        out,
+        "\n╔══════════════════════════════════════════════════════════╗"
+    )?;
+    writeln!(
+        out,
+        "║  {}  ║",
+        paint(Role::Heading, &format!("{:^54}", t("stats.title")))
+    )?;
+    writeln!(
+        out,
+        "╚══════════════════════════════════════════════════════════╝"
+    )?;
+
+    writeln!(out, "\n📊 {}", t("stats.summary"))?;
+    writeln!(out, "   {}", tf("stats.total", &[&stats.total]))?;
+    let line = format!("✅ {}", tf("stats.completed", &[&stats.completed]));
+    writeln!(out, "   {}", paint(Role::Completed, &line))?;
+    let line = format!("🔄 {}", tf("stats.in_progress", &[&stats.in_progress]));
+    writeln!(out, "   {}", paint(Role::InProgress, &line))?;
+    let line = format!("⏳ {}", tf("stats.pending", &[&stats.pending]));
+    writeln!(out, "   {}", paint(Role::Pending, &line))?;
+    let line = format!("⚠️  {}", tf("stats.overdue", &[&stats.overdue]));
+    writeln!(out, "   {}", paint(Role::Overdue, &line))?;
+
+    writeln!(out, "\n📁 {}", t("stats.by_category"))?;
     for (category, count) in &stats.by_category {
         if *count > 0 {
-            println!("   {}: {}", category.as_str(), count);
+            writeln!(out, "   {}: {}", category.as_str(), count)?;
         }
     }
 
-    println!("\n⭐ Por Prioridade:");
+    writeln!(out, "\n⭐ {}", t("stats.by_priority"))?;
     for (priority, count) in &stats.by_priority {
         if *count > 0 {
-            println!("   {}: {}", priority.as_str(), count);
+            let label = paint(Role::priority(*priority), priority.as_str());
+            writeln!(out, "   {}: {}", label, count)?;
+        }
+    }
+
+    if stats.tracked_minutes > 0 || stats.estimate_minutes > 0 {
+        writeln!(out, "\n⏱  {}", t("stats.time"))?;
+        writeln!(
+            out,
+            "   {}",
+            tf("stats.tracked", &[&format_minutes(stats.tracked_minutes)])
+        )?;
+        if stats.estimate_minutes > 0 {
+            writeln!(
+                out,
+                "   {}",
+                tf(
+                    "stats.estimate_vs_actual",
+                    &[
+                        &format_minutes(stats.estimate_minutes),
+                        &format_minutes(stats.actual_minutes)
+                    ]
+                )
+            )?;
+            writeln!(
+                out,
+                "   {}",
+                tf("stats.over_estimate", &[&stats.over_estimate])
+            )?;
+        }
+    }
+
+    if stats.pomodoros > 0 {
+        writeln!(out, "\n🍅 {}", t("stats.focus"))?;
+        let focus = format_minutes(stats.focus_minutes);
+        writeln!(out, "   {}", tf("stats.pomodoros", &[&stats.pomodoros, &focus]))?;
+    }
+
+    Ok(())
+}
+
+/// Exibe o resumo por responsável (nada se ninguém tiver tarefas atribuídas)
+pub fn print_user_statistics<W: Write>(
+    out: &mut W,
+    by_user: &[(Option<String>, Statistics)],
+) -> io::Result<()> {
+    print_grouped_statistics(
+        out,
+        "👤",
+        t("stats.by_user"),
+        t("stats.unassigned"),
+        by_user,
+    )
+}
+
+/// Exibe o resumo por projeto (nada se não houver projetos)
+pub fn print_project_statistics<W: Write>(
+    out: &mut W,
+    by_project: &[(Option<String>, Statistics)],
+) -> io::Result<()> {
+    print_grouped_statistics(
+        out,
+        "📁",
+        t("stats.by_project"),
+        t("stats.no_project"),
+        by_project,
+    )
+}
+
+/// Uma linha de resumo por grupo; `None` é o grupo "sem" (sem responsável etc.)
+fn print_grouped_statistics<W: Write>(
+    out: &mut W,
+    icon: &str,
+    title: &str,
+    none_label: &str,
+    groups: &[(Option<String>, Statistics)],
+) -> io::Result<()> {
+    if groups.iter().all(|(name, _)| name.is_none()) {
+        return Ok(());
+    }
+
+    writeln!(out, "\n{} {}", icon, title)?;
+    for (name, stats) in groups {
+        let name = name.as_deref().unwrap_or(none_label);
+        writeln!(
+            out,
+            "   {}",
+            tf(
+                "stats.group_line",
+                &[&name, &stats.total, &stats.completed, &stats.overdue]
+            )
+        )?;
+    }
+    Ok(())
+}
+
+/// Exibe os projetos com a quantidade de tarefas; o ativo fica marcado com ▶
+pub fn print_projects<W: Write>(
+    out: &mut W,
+    projects: &[(&Project, usize)],
+    active: Option<&str>,
+) -> io::Result<()> {
+    if projects.is_empty() {
+        return writeln!(out, "{}", t("menu.no_projects"));
+    }
+
+    for (project, count) in projects {
+        let marker = if active == Some(project.name.as_str()) {
+            "▶"
+        } else {
+            " "
+        };
+        let line = tf(
+            "menu.project_line",
+            &[&user_text(&project.name), &project.color.as_str(), count],
+        );
+        writeln!(out, "{} {}", marker, line)?;
+        if !project.description.is_empty() {
+            writeln!(out, "    {}", user_text(&project.description))?;
+        }
+    }
+    Ok(())
+}
+
+/// Lista os modelos numerados, com as variáveis de cada um
+pub fn print_templates<W: Write>(out: &mut W, templates: &[Template]) -> io::Result<()> {
+    if templates.is_empty() {
+        return writeln!(out, "{}", t("menu.no_templates"));
+    }
+
+    for (i, template) in templates.iter().enumerate() {
+        let line = tf(
+            "menu.template_line",
+            &[&user_text(&template.name), &template.tasks.len()],
+        );
+        writeln!(out, "{}. {}", i + 1, line)?;
+        if !template.description.is_empty() {
+            writeln!(out, "    {}", user_text(&template.description))?;
+        }
+        let variables = template.variables();
+        if !variables.is_empty() {
+            let names: Vec<String> = variables.iter().map(|v| format!("{{{}}}", v)).collect();
+            writeln!(out, "    {}", names.join(", "))?;
+        }
+    }
+    Ok(())
+}
+
+/// Exibe o resultado de uma operação em lote, uma linha por ID
+pub fn print_bulk_report<W: Write>(out: &mut W, report: &BulkReport) -> io::Result<()> {
+    writeln!(out)?;
+    for (id, result) in &report.results {
+        match result {
+            Ok(()) => writeln!(out, "   ✅ #{}", id)?,
+            Err(e) => writeln!(out, "   ❌ #{}: {}", id, e)?,
         }
     }
-}
\ No newline at end of file
+
+    let summary = tf(
+        "menu.bulk_summary",
+        &[&report.succeeded().len(), &report.failed().len()],
+    );
+    writeln!(out, "\n{}", summary)
+}
+
+/// Exibe as tarefas recomendadas, explicando a pontuação de cada uma
+pub fn print_next_up<W: Write>(out: &mut W, ranked: &[(&Task, Score)]) -> io::Result<()> {
+    if ranked.is_empty() {
+        return writeln!(out, "{}", t("output.no_tasks"));
+    }
+
+    for (position, (task, score)) in ranked.iter().enumerate() {
+        let points = tf("score.points", &[&format!("{:.1}", score.total)]);
+        writeln!(
+            out,
+            "{}. {} #{} {} — {}",
+            position + 1,
+            paint(
+                Role::priority(task.priority),
+                &priority_marker(task.priority).to_string()
+            ),
+            task.id,
+            user_text(&task.title),
+            points
+        )?;
+        for factor in &score.factors {
+            writeln!(out, "      {:+6.1}  {}", factor.points, factor.reason)?;
+        }
+    }
+    Ok(())
+}
+
+/// Largura máxima das barras dos gráficos
+const BAR_WIDTH: usize = 30;
+
+/// Exibe um gráfico de barras horizontal em texto
+///
+/// As barras são proporcionais ao maior valor; `value_label` formata o
+/// número mostrado ao lado de cada barra.
+pub fn print_bar_chart<W: Write>(
+    out: &mut W,
+    title: &str,
+    rows: &[(String, f64)],
+    value_label: impl Fn(f64) -> String,
+) -> io::Result<()> {
+    writeln!(out, "\n{}", title)?;
+
+    let max = rows.iter().map(|(_, v)| *v).fold(0.0, f64::max);
+    let label_width = rows
+        .iter()
+        .map(|(label, _)| label.chars().count())
+        .max()
+        .unwrap_or(0);
+
+    for (label, value) in rows {
+        let len = if max > 0.0 {
+            (value / max * BAR_WIDTH as f64).round() as usize
+        } else {
+            0
+        };
+        let padding = label_width - label.chars().count();
+        writeln!(
+            out,
+            "   {}{} │{} {}",
+            label,
+            " ".repeat(padding),
+            "█".repeat(len),
+            value_label(*value)
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Exibe a análise de produtividade
+pub fn print_analytics<W: Write>(out: &mut W, analytics: &Analytics) -> io::Result<()> {
+    // Formato de data do idioma, sem o ano
+    let day_format = ["/%Y", "-%Y", ".%Y", "%Y-", "%Y/"]
+        .iter()
+        .fold(i18n::date_format(), |format, year| format.replace(year, ""));
+    let date = |d: &chrono::NaiveDate| d.format(&day_format).to_string();
+    let count = |v: f64| format!("{}", v as usize);
+
+    print_header(out, t("analytics.title"))?;
+    writeln!(
+        out,
+        "{}",
+        tf(
+            "analytics.period",
+            &[
+                &analytics.from.format(&i18n::date_format()),
+                &analytics.to.format(&i18n::date_format())
+            ]
+        )
+    )?;
+
+    let rows: Vec<(String, f64)> = analytics
+        .completed_per_day
+        .iter()
+        .map(|(day, n)| (date(day), *n as f64))
+        .collect();
+    print_bar_chart(out, &format!("✅ {}", t("analytics.per_day")), &rows, count)?;
+
+    let rows: Vec<(String, f64)> = analytics
+        .completed_per_week
+        .iter()
+        .map(|(week, n)| (date(week), *n as f64))
+        .collect();
+    print_bar_chart(
+        out,
+        &format!("📅 {}", t("analytics.per_week")),
+        &rows,
+        count,
+    )?;
+
+    let rows: Vec<(String, f64)> = analytics
+        .lead_time_by_category
+        .iter()
+        .filter_map(|(category, lead)| {
+            lead.map(|d| (category.as_str().to_string(), d.num_minutes() as f64))
+        })
+        .collect();
+    if rows.is_empty() {
+        writeln!(out, "\n⏳ {}", t("analytics.lead_time"))?;
+        writeln!(out, "   {}", t("analytics.no_data"))?;
+    } else {
+        print_bar_chart(
+            out,
+            &format!("⏳ {}", t("analytics.lead_time")),
+            &rows,
+            |v| format_minutes(v as i64),
+        )?;
+    }
+
+    let rows: Vec<(String, f64)> = analytics
+        .overdue_rate_by_week
+        .iter()
+        .filter_map(|(week, rate)| rate.map(|r| (date(week), r * 100.0)))
+        .collect();
+    if rows.is_empty() {
+        writeln!(out, "\n⚠️  {}", t("analytics.overdue_rate"))?;
+        writeln!(out, "   {}", t("analytics.no_data"))?;
+    } else {
+        print_bar_chart(
+            out,
+            &format!("⚠️  {}", t("analytics.overdue_rate")),
+            &rows,
+            |v| format!("{:.0}%", v),
+        )?;
+    }
+
+    let rows: Vec<(String, f64)> = analytics
+        .burndown
+        .iter()
+        .map(|(day, n)| (date(day), *n as f64))
+        .collect();
+    print_bar_chart(
+        out,
+        &format!("📉 {}", t("analytics.burndown")),
+        &rows,
+        count,
+    )
+}
+
+/// Largura interna de cada coluna do quadro Kanban
+const COLUMN_WIDTH: usize = 24;
+
+/// Marcador de prioridade dos cartões do quadro
+fn priority_marker(priority: Priority) -> char {
+    match priority {
+        Priority::High => '↑',
+        Priority::Medium => '→',
+        Priority::Low => '↓',
+    }
+}
+
+/// Exibe o quadro Kanban com uma coluna por status
+///
+/// Os cartões vêm ordenados por prioridade, com `↑ → ↓` indicando a
+/// prioridade e `!` as tarefas atrasadas no instante `now`. O cabeçalho
+/// mostra o limite de WIP da coluna e avisa quando ele foi ultrapassado.
+pub fn print_board<W: Write>(
+    out: &mut W,
+    tasks: &[&Task],
+    limits: &WipLimits,
+    now: DateTime<Local>,
+) -> io::Result<()> {
+    let statuses = Status::all();
+    let columns: Vec<Vec<&Task>> = statuses
+        .iter()
+        .map(|status| {
+            let mut cards: Vec<&Task> = tasks
+                .iter()
+                .filter(|t| t.status == *status)
+                .copied()
+                .collect();
+            cards.sort_by_key(|t| (t.priority, t.id));
+            cards
+        })
+        .collect();
+
+    let line = |left: &str, middle: &str, right: &str| {
+        let segments: Vec<String> = statuses
+            .iter()
+            .map(|_| "─".repeat(COLUMN_WIDTH + 2))
+            .collect();
+        format!("{}{}{}", left, segments.join(middle), right)
+    };
+
+    let headers: Vec<String> = statuses
+        .iter()
+        .zip(&columns)
+        .map(|(status, cards)| {
+            let (header, role) = match limits.limit(*status) {
+                Some(limit) if cards.len() > limit => (
+                    format!("{} ({}/{}) ⚠", status.as_str(), cards.len(), limit),
+                    Role::Warning,
+                ),
+                Some(limit) => (
+                    format!("{} ({}/{})", status.as_str(), cards.len(), limit),
+                    Role::status(*status),
+                ),
+                None => (
+                    format!("{} ({})", status.as_str(), cards.len()),
+                    Role::status(*status),
+                ),
+            };
+            paint(role, &text::fit(&header, COLUMN_WIDTH))
+        })
+        .collect();
+
+    writeln!(out, "{}", line("┌", "┬", "┐"))?;
+    writeln!(out, "│ {} │", headers.join(" │ "))?;
+    writeln!(out, "{}", line("├", "┼", "┤"))?;
+
+    let height = columns.iter().map(|c| c.len()).max().unwrap_or(0).max(1);
+    for i in 0..height {
+        let row: Vec<String> = columns
+            .iter()
+            .map(|cards| match cards.get(i) {
+                Some(task) => {
+                    let (overdue, role) = if task.is_overdue_at(&now) {
+                        ("!", Role::Overdue)
+                    } else {
+                        (" ", Role::priority(task.priority))
+                    };
+                    let card = format!(
+                        "{}{} #{} {}",
+                        overdue,
+                        priority_marker(task.priority),
+                        task.id,
+                        user_text(&task.title)
+                    );
+                    paint(role, &text::fit(&card, COLUMN_WIDTH))
+                }
+                None => text::fit("", COLUMN_WIDTH),
+            })
+            .collect();
+        writeln!(out, "│ {} │", row.join(" │ "))?;
+    }
+
+    writeln!(out, "{}", line("└", "┴", "┘"))?;
+    writeln!(out, "{}", t("board.legend"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Category;
+    use chrono::NaiveDate;
+
+    #[test]
+    fn test_format_size() {
+        assert_eq!(format_size(512), "512 B");
+        assert_eq!(format_size(2048), "2.0 KB");
+        assert_eq!(format_size(5 * 1024 * 1024 + 200 * 1024), "5.2 MB");
+    }
+
+    #[test]
+    fn test_task_box_wraps_markdown_description() {
+        let task = Task::new(
+            1,
+            "Título 🚀".to_string(),
+            "Fazer **já**:\n- revisar o relatório trimestral".to_string(),
+            Category::Work,
+            Priority::High,
+            None,
+        );
+        let mut out = Vec::new();
+        print_task_with_width(&mut out, &task, Local::now(), 40).unwrap();
+
+        let output = String::from_utf8(out).unwrap();
+        let lines: Vec<&str> = output.lines().collect();
+        // Todas as linhas da caixa têm a mesma largura na tela
+        assert!(lines.iter().all(|l| display_width(l) == 40));
+        assert!(output.contains("│ Descrição: Fazer já:"));
+        assert!(output.contains("│            • revisar o relatório"));
+        assert!(output.contains("│              trimestral"));
+    }
+
+    #[test]
+    fn test_task_list_fits_width() {
+        let task = Task::new(
+            7,
+            "Um título comprido demais para a coluna da lista".to_string(),
+            "".to_string(),
+            Category::Health,
+            Priority::Low,
+            None,
+        );
+        let mut out = Vec::new();
+        print_task_list_with_width(&mut out, &[&task], Local::now(), 60).unwrap();
+
+        let output = String::from_utf8(out).unwrap();
+        assert!(output.lines().all(|l| display_width(l) <= 60));
+        assert!(output.contains("7    Um título compr… Saúde        Baixa      Pendente"));
+    }
+
+    #[test]
+    fn test_task_list_colors_priority_and_overdue() {
+        let mut task = Task::new(
+            3,
+            "Pagar conta".to_string(),
+            "".to_string(),
+            Category::Personal,
+            Priority::High,
+            NaiveDate::from_ymd_opt(2024, 3, 9),
+        );
+        task.status = Status::Pending;
+        let now = NaiveDate::from_ymd_opt(2024, 3, 10)
+            .unwrap()
+            .and_hms_opt(9, 0, 0)
+            .unwrap()
+            .and_local_timezone(Local)
+            .earliest()
+            .unwrap();
+        let mut out = Vec::new();
+        text::set_styles(true);
+        print_task_list_with_width(&mut out, &[&task], now, 80).unwrap();
+
+        let output = String::from_utf8(out).unwrap();
+        assert!(output.contains("\x1B[1;31mAlta      \x1B[0m"));
+        assert!(output.contains("\x1B[1;31m⚠️ Pendente\x1B[0m"));
+        assert!(output.lines().all(|l| display_width(l) <= 80));
+    }
+
+    #[test]
+    fn test_format_minutes() {
+        assert_eq!(format_minutes(45), "45min");
+        assert_eq!(format_minutes(65), "1h 05min");
+        assert_eq!(format_minutes(-5), "0min");
+    }
+
+    #[test]
+    fn test_bar_chart_scales_to_largest_value() {
+        let rows = vec![("a".to_string(), 2.0), ("bb".to_string(), 1.0)];
+        let mut out = Vec::new();
+        print_bar_chart(&mut out, "Título", &rows, |v| v.to_string()).unwrap();
+
+        let text = String::from_utf8(out).unwrap();
+        assert!(text.contains(&format!("a  │{} 2", "█".repeat(BAR_WIDTH))));
+        assert!(text.contains(&format!("bb │{} 1", "█".repeat(BAR_WIDTH / 2))));
+    }
+
+    #[test]
+    fn test_board_columns_and_wip_warning() {
+        let mut high = Task::new(
+            1,
+            "Urgente".to_string(),
+            String::new(),
+            Category::Work,
+            Priority::High,
+            None,
+        );
+        high.start();
+        let mut low = Task::new(
+            2,
+            "Depois".to_string(),
+            String::new(),
+            Category::Work,
+            Priority::Low,
+            None,
+        );
+        low.start();
+        let pending = Task::new(
+            3,
+            "Um título bem comprido para caber".to_string(),
+            String::new(),
+            Category::Work,
+            Priority::Medium,
+            None,
+        );
+        let limits = WipLimits {
+            pending: None,
+            in_progress: Some(1),
+        };
+
+        let mut out = Vec::new();
+        print_board(&mut out, &[&low, &high, &pending], &limits, Local::now()).unwrap();
+        let text = String::from_utf8(out).unwrap();
+
+        assert!(text.contains("Em Andamento (2/1) ⚠"));
+        assert!(text.contains("Pendente (1)"));
+        assert!(text.contains("→ #3 Um título bem com…"));
+        assert!(text.find("↑ #1").unwrap() < text.find("↓ #2").unwrap());
+
+        let widths: Vec<usize> = text.lines().take(4).map(|l| l.chars().count()).collect();
+        assert!(widths.iter().all(|w| *w == widths[0]));
+    }
+}
@@ -0,0 +1,247 @@
+use std::cell::Cell;
+use std::env;
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+/// Largura usada quando não dá para descobrir a do terminal
+const DEFAULT_WIDTH: usize = 80;
+
+thread_local! {
+    static STYLES: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Liga ou desliga os estilos ANSI (negrito, sublinhado, cores) no texto
+///
+/// Começam desligados; o programa liga conforme a configuração e o terminal.
+pub fn set_styles(enabled: bool) {
+    STYLES.with(|styles| styles.set(enabled));
+}
+
+/// Verifica se os estilos ANSI estão ligados
+pub fn styles_enabled() -> bool {
+    STYLES.with(|styles| styles.get())
+}
+
+/// Largura do terminal em colunas
+///
+/// Tenta o próprio terminal, depois a variável `COLUMNS` e, por fim, 80.
+pub fn terminal_width() -> usize {
+    if let Ok((columns, _)) = ratatui::crossterm::terminal::size()
+        && columns > 0
+    {
+        return columns as usize;
+    }
+    env::var("COLUMNS")
+        .ok()
+        .and_then(|columns| columns.trim().parse().ok())
+        .filter(|columns| *columns > 0)
+        .unwrap_or(DEFAULT_WIDTH)
+}
+
+/// Colunas ocupadas no terminal, ignorando sequências ANSI
+///
+/// Emojis e ideogramas contam 2, acentos combinantes contam 0.
+pub fn display_width(text: &str) -> usize {
+    if !text.contains('\x1B') {
+        return text.width();
+    }
+
+    let mut width = 0;
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c == '\x1B' {
+            // Pula até a letra que encerra a sequência (ex.: "\x1B[1m")
+            for c in chars.by_ref() {
+                if c.is_ascii_alphabetic() {
+                    break;
+                }
+            }
+        } else {
+            width += c.width().unwrap_or(0);
+        }
+    }
+    width
+}
+
+/// Corta o texto em `width` colunas, terminando com "…" quando corta
+///
+/// Sequências ANSI são mantidas sem contar colunas; se o corte deixar um
+/// estilo aberto, ele é encerrado no fim.
+pub fn truncate(text: &str, width: usize) -> String {
+    if display_width(text) <= width {
+        return text.to_string();
+    }
+    if width == 0 {
+        return String::new();
+    }
+
+    let mut cut = String::new();
+    let mut used = 0;
+    let mut styled = false;
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c == '\x1B' {
+            cut.push(c);
+            for c in chars.by_ref() {
+                cut.push(c);
+                if c.is_ascii_alphabetic() {
+                    break;
+                }
+            }
+            styled = true;
+            continue;
+        }
+
+        let w = c.width().unwrap_or(0);
+        if used + w > width - 1 {
+            break;
+        }
+        cut.push(c);
+        used += w;
+    }
+    cut.push('…');
+    if styled {
+        cut.push_str("\x1B[0m");
+    }
+    cut
+}
+
+/// Completa o texto com espaços até `width` colunas
+pub fn pad(text: &str, width: usize) -> String {
+    let missing = width.saturating_sub(display_width(text));
+    format!("{}{}", text, " ".repeat(missing))
+}
+
+/// Ajusta o texto a exatamente `width` colunas (corta ou completa)
+pub fn fit(text: &str, width: usize) -> String {
+    pad(&truncate(text, width), width)
+}
+
+/// Quebra um texto simples em linhas de até `width` colunas
+///
+/// Quebras de linha do texto são mantidas; palavras maiores que a
+/// largura são divididas.
+pub fn wrap(text: &str, width: usize) -> Vec<String> {
+    text.lines()
+        .flat_map(|line| {
+            let chars: Vec<(char, ())> = line.chars().map(|c| (c, ())).collect();
+            let wrapped = wrap_styled(&chars, width);
+            if wrapped.is_empty() {
+                vec![String::new()]
+            } else {
+                wrapped
+                    .into_iter()
+                    .map(|line| line.into_iter().map(|(c, _)| c).collect())
+                    .collect()
+            }
+        })
+        .collect()
+}
+
+/// Palavra com o estilo do espaço que a precede (se houver)
+type Word<'a, S> = (Option<S>, &'a [(char, S)]);
+
+/// Quebra uma linha de caracteres com estilo em linhas de até `width` colunas
+///
+/// Espaços em sequência viram um só; o estilo de cada caractere é mantido,
+/// para quem chama reaplicar os códigos ANSI depois da quebra.
+pub fn wrap_styled<S: Copy>(chars: &[(char, S)], width: usize) -> Vec<Vec<(char, S)>> {
+    let width = width.max(1);
+    let char_width = |c: char| c.width().unwrap_or(0);
+
+    let mut words: Vec<Word<S>> = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let spaces = chars[i..]
+            .iter()
+            .take_while(|(c, _)| c.is_whitespace())
+            .count();
+        let gap = (spaces > 0).then(|| chars[i].1);
+        i += spaces;
+
+        let len = chars[i..]
+            .iter()
+            .take_while(|(c, _)| !c.is_whitespace())
+            .count();
+        if len > 0 {
+            words.push((gap, &chars[i..i + len]));
+        }
+        i += len;
+    }
+
+    let mut lines = Vec::new();
+    let mut line: Vec<(char, S)> = Vec::new();
+    let mut line_width = 0;
+
+    for (gap, word) in words {
+        let word_width: usize = word.iter().map(|(c, _)| char_width(*c)).sum();
+
+        if !line.is_empty() && line_width + 1 + word_width <= width {
+            line.push((' ', gap.unwrap_or(word[0].1)));
+            line.extend_from_slice(word);
+            line_width += 1 + word_width;
+            continue;
+        }
+
+        if !line.is_empty() {
+            lines.push(std::mem::take(&mut line));
+            line_width = 0;
+        }
+
+        // Palavra maior que a linha: divide em pedaços
+        for &(c, style) in word {
+            let w = char_width(c);
+            if line_width + w > width && !line.is_empty() {
+                lines.push(std::mem::take(&mut line));
+                line_width = 0;
+            }
+            line.push((c, style));
+            line_width += w;
+        }
+    }
+
+    if !line.is_empty() {
+        lines.push(line);
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display_width() {
+        assert_eq!(display_width("abc"), 3);
+        assert_eq!(display_width("ação"), 4);
+        assert_eq!(display_width("🚀 ok"), 5);
+        assert_eq!(display_width("日本"), 4);
+        assert_eq!(display_width("\x1B[1mnegrito\x1B[22m"), 7);
+    }
+
+    #[test]
+    fn test_truncate_and_fit() {
+        assert_eq!(truncate("Relatório mensal", 10), "Relatório…");
+        assert_eq!(truncate("curto", 10), "curto");
+        assert_eq!(truncate("日本語のテキスト", 7), "日本語…");
+        assert_eq!(fit("ação", 6), "ação  ");
+        assert_eq!(fit("🚀🚀🚀", 4), "🚀… ");
+        assert_eq!(
+            truncate("\x1B[31mvermelho\x1B[0m", 5),
+            "\x1B[31mverm…\x1B[0m"
+        );
+    }
+
+    #[test]
+    fn test_wrap() {
+        assert_eq!(
+            wrap("Um texto longo para quebrar em linhas", 12),
+            vec!["Um texto", "longo para", "quebrar em", "linhas"]
+        );
+        assert_eq!(
+            wrap("linha 1\n\nlinha 2", 20),
+            vec!["linha 1", "", "linha 2"]
+        );
+        assert_eq!(wrap("abcdefghij", 4), vec!["abcd", "efgh", "ij"]);
+        assert_eq!(wrap("🚀🚀🚀 fim", 4), vec!["🚀🚀", "🚀", "fim"]);
+    }
+}
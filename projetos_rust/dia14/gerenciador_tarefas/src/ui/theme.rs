@@ -0,0 +1,426 @@
+use crate::models::{Priority, Status};
+use crate::ui::text;
+use std::cell::Cell;
+use std::env;
+use std::io::{self, IsTerminal, Write};
+
+/// Código ANSI que volta ao estilo normal
+const RESET: &str = "\x1B[0m";
+
+/// Sequências (ignoradas na contagem de colunas) que cercam o texto do
+/// usuário para a conversão ASCII deixá-lo intacto
+const VERBATIM_START: &str = "\x1B[<1v";
+const VERBATIM_END: &str = "\x1B[<0v";
+
+thread_local! {
+    static THEME: Cell<Theme> = const { Cell::new(Theme::Default) };
+    static ASCII: Cell<bool> = const { Cell::new(false) };
+}
+
+/// O que um trecho da saída representa; o tema decide a cor
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Role {
+    High,
+    Medium,
+    Low,
+    Pending,
+    InProgress,
+    Completed,
+    Overdue,
+    Success,
+    Error,
+    Warning,
+    Heading,
+}
+
+impl Role {
+    pub fn priority(priority: Priority) -> Role {
+        match priority {
+            Priority::High => Role::High,
+            Priority::Medium => Role::Medium,
+            Priority::Low => Role::Low,
+        }
+    }
+
+    pub fn status(status: Status) -> Role {
+        match status {
+            Status::Pending => Role::Pending,
+            Status::InProgress => Role::InProgress,
+            Status::Completed => Role::Completed,
+        }
+    }
+}
+
+/// Conjunto de cores da saída do menu
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Theme {
+    /// Cores vivas, para terminais de fundo escuro
+    Default,
+    /// Cores escuras, legíveis em fundo claro
+    Light,
+    /// Sem cores: só negrito, sublinhado e esmaecido
+    Mono,
+}
+
+impl Theme {
+    pub fn all() -> Vec<Theme> {
+        vec![Theme::Default, Theme::Light, Theme::Mono]
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Theme::Default => "default",
+            Theme::Light => "light",
+            Theme::Mono => "mono",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Theme> {
+        let value = value.trim().to_lowercase();
+        Theme::all()
+            .into_iter()
+            .find(|theme| theme.as_str() == value)
+    }
+
+    /// Código ANSI de início de cada papel (vazio = sem estilo)
+    fn code(&self, role: Role) -> &'static str {
+        match (self, role) {
+            (Theme::Default, Role::High) => "\x1B[1;31m",
+            (Theme::Default, Role::Medium) => "\x1B[33m",
+            (Theme::Default, Role::Low) => "\x1B[32m",
+            (Theme::Default, Role::Pending) => "",
+            (Theme::Default, Role::InProgress) => "\x1B[36m",
+            (Theme::Default, Role::Completed) => "\x1B[32m",
+            (Theme::Default, Role::Overdue) => "\x1B[1;31m",
+            (Theme::Default, Role::Success) => "\x1B[32m",
+            (Theme::Default, Role::Error) => "\x1B[31m",
+            (Theme::Default, Role::Warning) => "\x1B[33m",
+            (Theme::Default, Role::Heading) => "\x1B[1;36m",
+
+            (Theme::Light, Role::High) => "\x1B[31m",
+            (Theme::Light, Role::Medium) => "\x1B[35m",
+            (Theme::Light, Role::Low) => "\x1B[34m",
+            (Theme::Light, Role::Pending) => "",
+            (Theme::Light, Role::InProgress) => "\x1B[34m",
+            (Theme::Light, Role::Completed) => "\x1B[32m",
+            (Theme::Light, Role::Overdue) => "\x1B[1;31m",
+            (Theme::Light, Role::Success) => "\x1B[32m",
+            (Theme::Light, Role::Error) => "\x1B[31m",
+            (Theme::Light, Role::Warning) => "\x1B[35m",
+            (Theme::Light, Role::Heading) => "\x1B[1;34m",
+
+            (Theme::Mono, Role::High | Role::Error | Role::Warning | Role::Heading) => "\x1B[1m",
+            (Theme::Mono, Role::Low | Role::Completed) => "\x1B[2m",
+            (Theme::Mono, Role::Overdue) => "\x1B[1;4m",
+            (Theme::Mono, _) => "",
+        }
+    }
+}
+
+/// Define o tema usado por [`paint`]
+pub fn set_theme(theme: Theme) {
+    THEME.with(|current| current.set(theme));
+}
+
+/// Tema atual
+pub fn theme() -> Theme {
+    THEME.with(|current| current.get())
+}
+
+/// Liga ou desliga a troca de emojis e molduras por ASCII
+///
+/// Só deve ficar ligada quando a saída passa por um [`AsciiWriter`] ligado,
+/// que retira as marcas de [`user_text`].
+pub fn set_ascii(enabled: bool) {
+    ASCII.with(|ascii| ascii.set(enabled));
+}
+
+/// Verifica se a saída deve ser só ASCII
+pub fn ascii_enabled() -> bool {
+    ASCII.with(|ascii| ascii.get())
+}
+
+/// Decide se a saída deve sair sem cores
+///
+/// Vale quando a saída padrão não é um terminal (redirecionada para um
+/// arquivo ou outro programa) ou quando `NO_COLOR` está definida.
+pub fn plain_output() -> bool {
+    let no_color = env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty());
+    no_color || ascii_output()
+}
+
+/// Decide se emojis e molduras devem virar ASCII
+///
+/// Só quando a saída padrão não é um terminal: `NO_COLOR` tira as cores,
+/// mas não a decoração.
+pub fn ascii_output() -> bool {
+    !io::stdout().is_terminal()
+}
+
+/// Marca texto do usuário (título, descrição, notas...) para passar intacto
+/// pela conversão ASCII
+///
+/// Sem a conversão ligada, devolve o texto como está. As marcas não ocupam
+/// colunas e valem até o fim da linha, então um corte no meio não vaza.
+pub fn user_text(value: &str) -> String {
+    if !ascii_enabled() {
+        return value.to_string();
+    }
+    value
+        .split('\n')
+        .map(|line| format!("{}{}{}", VERBATIM_START, line, VERBATIM_END))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Aplica a cor do papel ao texto, se os estilos estiverem ligados
+///
+/// Deve ser chamada depois de cortar ou completar o texto, já que os
+/// códigos não ocupam colunas.
+pub fn paint(role: Role, value: &str) -> String {
+    let code = theme().code(role);
+    if !text::styles_enabled() || code.is_empty() || value.is_empty() {
+        return value.to_string();
+    }
+    format!("{}{}{}", code, value, RESET)
+}
+
+/// Troca a decoração por ASCII e remove os códigos ANSI
+///
+/// Molduras viram `+ - = |`, setas viram `^ > v`, alguns emojis viram
+/// marcadores (`[OK]`, `[X]`, `[!]`) e os demais somem com o espaço que
+/// os segue. Letras acentuadas e pontuação do texto ficam como estão, e o
+/// texto marcado por [`user_text`] não é convertido.
+pub fn to_ascii(value: &str) -> String {
+    convert(value, &mut Conversion::default())
+}
+
+/// Estado da conversão entre duas escritas do [`AsciiWriter`]
+#[derive(Default)]
+struct Conversion {
+    /// Um emoji acabou de ser removido e os espaços seguintes também somem
+    skipping: bool,
+    /// Dentro de texto do usuário, que passa sem conversão
+    verbatim: bool,
+}
+
+/// Conversão de [`to_ascii`]
+fn convert(value: &str, state: &mut Conversion) -> String {
+    let mut plain = String::with_capacity(value.len());
+    let mut chars = value.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c == '\x1B' {
+            // Pula a sequência ANSI inteira (ex.: "\x1B[1;31m", "\x1B[2J")
+            let mut sequence = String::from(c);
+            if let Some(bracket) = chars.next_if_eq(&'[') {
+                sequence.push(bracket);
+                for c in chars.by_ref() {
+                    sequence.push(c);
+                    if c.is_ascii_alphabetic() {
+                        break;
+                    }
+                }
+            }
+            if sequence == VERBATIM_START || sequence == VERBATIM_END {
+                state.verbatim = sequence == VERBATIM_START;
+                state.skipping = false;
+            }
+            continue;
+        }
+
+        if c == '\n' {
+            state.verbatim = false;
+        }
+        if state.verbatim {
+            plain.push(c);
+            continue;
+        }
+
+        if state.skipping && (c == ' ' || is_decoration(c)) {
+            continue;
+        }
+        state.skipping = false;
+
+        // Seletor de variação e junção não aparecem sozinhos
+        if matches!(c, '\u{FE0F}' | '\u{200D}') {
+            continue;
+        }
+
+        match ascii_symbol(c) {
+            Some(symbol) => plain.push_str(symbol),
+            None if is_decoration(c) => state.skipping = true,
+            None => plain.push(c),
+        }
+    }
+
+    plain
+}
+
+/// Equivalente ASCII dos símbolos que carregam significado
+fn ascii_symbol(c: char) -> Option<&'static str> {
+    let symbol = match c {
+        '─' | '┄' | '╌' => "-",
+        '═' => "=",
+        '│' | '║' | '▏' => "|",
+        '┌' | '┐' | '└' | '┘' | '├' | '┤' | '┬' | '┴' | '┼' | '╔' | '╗' | '╚' | '╝' => {
+            "+"
+        }
+        '█' => "#",
+        '↑' => "^",
+        '→' | '▶' => ">",
+        '↓' => "v",
+        '•' => "*",
+        '✅' => "[OK]",
+        '❌' => "[X]",
+        '⚠' => "[!]",
+        _ => return None,
+    };
+    Some(symbol)
+}
+
+/// Emojis e símbolos puramente decorativos, removidos no modo ASCII
+fn is_decoration(c: char) -> bool {
+    matches!(c as u32,
+        0x2300..=0x23FF     // técnicos (⏱ ⏳ ⏹)
+        | 0x2600..=0x27BF   // símbolos e dingbats (♻ ✏ ➕)
+        | 0x2B00..=0x2BFF   // setas e estrelas (⭐)
+        | 0x1F000..=0x1FAFF // emojis
+        | 0xFE0F            // seletor de variação emoji
+        | 0x200D            // junção de emojis
+    )
+}
+
+/// Saída que passa tudo por [`to_ascii`] quando ligada
+///
+/// Guarda um caractere UTF-8 ou uma sequência ANSI cortados entre duas
+/// escritas até o resto chegar.
+pub struct AsciiWriter<W> {
+    inner: W,
+    enabled: bool,
+    pending: Vec<u8>,
+    state: Conversion,
+}
+
+impl<W: Write> AsciiWriter<W> {
+    pub fn new(inner: W, enabled: bool) -> Self {
+        AsciiWriter {
+            inner,
+            enabled,
+            pending: Vec::new(),
+            state: Conversion::default(),
+        }
+    }
+
+    /// Devolve a saída de dentro (chame `flush` antes)
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+impl<W: Write> Write for AsciiWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if !self.enabled {
+            return self.inner.write(buf);
+        }
+
+        self.pending.extend_from_slice(buf);
+        let mut valid = match std::str::from_utf8(&self.pending) {
+            Ok(_) => self.pending.len(),
+            // Bytes inválidos (não só incompletos) seguem como vieram
+            Err(e) if e.error_len().is_some() => self.pending.len(),
+            Err(e) => e.valid_up_to(),
+        };
+        if let Some(escape) = self.pending[..valid].iter().rposition(|b| *b == 0x1B)
+            && !self.pending[escape..valid]
+                .iter()
+                .skip(1)
+                .any(|b| b.is_ascii_alphabetic())
+        {
+            valid = escape;
+        }
+
+        let chunk: Vec<u8> = self.pending.drain(..valid).collect();
+        let converted = match std::str::from_utf8(&chunk) {
+            Ok(text) => convert(text, &mut self.state).into_bytes(),
+            Err(_) => chunk,
+        };
+        self.inner.write_all(&converted)?;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        if !self.pending.is_empty() {
+            let rest = std::mem::take(&mut self.pending);
+            self.inner.write_all(&rest)?;
+        }
+        self.inner.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_paint_follows_theme_and_styles() {
+        text::set_styles(true);
+        set_theme(Theme::Default);
+        assert_eq!(paint(Role::High, "Alta"), "\x1B[1;31mAlta\x1B[0m");
+        assert_eq!(paint(Role::Pending, "Pendente"), "Pendente");
+
+        set_theme(Theme::Mono);
+        assert_eq!(paint(Role::High, "Alta"), "\x1B[1mAlta\x1B[0m");
+
+        text::set_styles(false);
+        assert_eq!(paint(Role::High, "Alta"), "Alta");
+        set_theme(Theme::Default);
+    }
+
+    #[test]
+    fn test_parse_theme() {
+        assert_eq!(Theme::parse("Light"), Some(Theme::Light));
+        assert_eq!(Theme::parse(" mono "), Some(Theme::Mono));
+        assert_eq!(Theme::parse("neon"), None);
+    }
+
+    #[test]
+    fn test_to_ascii() {
+        assert_eq!(to_ascii("┌──┐\n│ab│\n└──┘"), "+--+\n|ab|\n+--+");
+        assert_eq!(to_ascii("\n✅ Tarefa criada"), "\n[OK] Tarefa criada");
+        assert_eq!(to_ascii("⚠️  Atrasada"), "[!]  Atrasada");
+        assert_eq!(to_ascii("4. ✏️  Editar tarefa"), "4. Editar tarefa");
+        assert_eq!(
+            to_ascii("\x1B[2J\x1B[1;1H\x1B[1;31mAlta\x1B[0m ↑"),
+            "Alta ^"
+        );
+        assert_eq!(to_ascii("Descrição… já"), "Descrição… já");
+    }
+
+    #[test]
+    fn test_ascii_writer_joins_split_characters() {
+        let mut out = AsciiWriter::new(Vec::new(), true);
+        let bytes = "📋 Lista \x1B[32m✅\x1B[0m".as_bytes();
+
+        for byte in bytes {
+            out.write_all(&[*byte]).unwrap();
+        }
+        out.flush().unwrap();
+
+        assert_eq!(String::from_utf8(out.inner).unwrap(), "Lista [OK]");
+    }
+
+    #[test]
+    fn test_user_text_is_kept_verbatim() {
+        assert_eq!(user_text("Ir → 🚀"), "Ir → 🚀");
+
+        set_ascii(true);
+        let line = format!("✅ {} → ok\n{}", user_text("Ir → 🚀"), user_text("a\nb ✅"));
+        set_ascii(false);
+        assert_eq!(to_ascii(&line), "[OK] Ir → 🚀 > ok\na\nb ✅");
+
+        // Texto cortado antes da marca final: ela não passa do fim da linha
+        let cut = format!("{}Ir → 🚀…\n→", VERBATIM_START);
+        assert_eq!(to_ascii(&cut), "Ir → 🚀…\n>");
+    }
+}
@@ -0,0 +1,673 @@
+use crate::i18n::{self, t, tf};
+use crate::models::{Category, Priority, Status, Task};
+use crate::services::TaskService;
+use crate::ui::output;
+use ratatui::Frame;
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind};
+use ratatui::layout::{Constraint, Layout};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::Line;
+use ratatui::widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState, Wrap};
+use std::fmt::Display;
+use std::io;
+
+/// Campo editado inline
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EditField {
+    Title,
+    Description,
+}
+
+/// Modo atual da interface
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    Normal,
+    Filter,
+    Edit(EditField),
+    Create,
+    ConfirmDelete,
+}
+
+/// Estado da interface em tela cheia
+pub struct TuiApp {
+    service: TaskService,
+    table_state: TableState,
+    mode: Mode,
+    filter: String,
+    input: String,
+    message: String,
+    should_quit: bool,
+    colors: bool,
+}
+
+impl TuiApp {
+    /// Cria um novo TuiApp
+    pub fn new(service: TaskService) -> Self {
+        let mut table_state = TableState::default();
+        table_state.select(Some(0));
+
+        TuiApp {
+            service,
+            table_state,
+            mode: Mode::Normal,
+            filter: String::new(),
+            input: String::new(),
+            message: String::new(),
+            should_quit: false,
+            colors: true,
+        }
+    }
+
+    /// Liga ou desliga as cores da tabela
+    pub fn with_colors(mut self, colors: bool) -> Self {
+        self.colors = colors;
+        self
+    }
+
+    pub fn mode(&self) -> Mode {
+        self.mode
+    }
+
+    pub fn filter(&self) -> &str {
+        &self.filter
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    pub fn should_quit(&self) -> bool {
+        self.should_quit
+    }
+
+    pub fn service(&self) -> &TaskService {
+        &self.service
+    }
+
+    /// Tarefas do projeto ativo que passam pelo filtro atual
+    pub fn visible_tasks(&self) -> Vec<&Task> {
+        let filter = self.filter.to_lowercase();
+        self.service
+            .active_tasks()
+            .into_iter()
+            .filter(|t| filter.is_empty() || matches_filter(t, &filter))
+            .collect()
+    }
+
+    /// Tarefa selecionada na tabela
+    pub fn selected_task(&self) -> Option<&Task> {
+        let index = self.table_state.selected()?;
+        self.visible_tasks().get(index).copied()
+    }
+
+    /// Processa uma tecla
+    pub fn handle_key(&mut self, key: KeyEvent) {
+        match self.mode {
+            Mode::Normal => self.handle_normal_key(key.code),
+            Mode::Filter => self.handle_filter_key(key.code),
+            Mode::Edit(_) | Mode::Create => self.handle_input_key(key.code),
+            Mode::ConfirmDelete => self.handle_confirm_key(key.code),
+        }
+        self.clamp_selection();
+    }
+
+    fn handle_normal_key(&mut self, code: KeyCode) {
+        self.message.clear();
+
+        match code {
+            KeyCode::Char('q') | KeyCode::Esc => self.should_quit = true,
+            KeyCode::Down | KeyCode::Char('j') => self.move_selection(1),
+            KeyCode::Up | KeyCode::Char('k') => self.move_selection(-1),
+            KeyCode::PageDown => self.move_selection(10),
+            KeyCode::PageUp => self.move_selection(-10),
+            KeyCode::Home => self.table_state.select(Some(0)),
+            KeyCode::End => {
+                let last = self.visible_tasks().len().saturating_sub(1);
+                self.table_state.select(Some(last));
+            }
+            KeyCode::Char('/') => self.mode = Mode::Filter,
+            KeyCode::Char('n') => {
+                self.input.clear();
+                self.mode = Mode::Create;
+            }
+            KeyCode::Char('e') => self.begin_edit(EditField::Title),
+            KeyCode::Char('E') => self.begin_edit(EditField::Description),
+            KeyCode::Char('s') => self.with_selected(|service, id| {
+                service.start_task(id).map(|_| t("menu.task_started"))
+            }),
+            KeyCode::Char('c') => self.with_selected(|service, id| {
+                service.complete_task(id).map(|_| t("menu.task_completed"))
+            }),
+            KeyCode::Char('t') => self.with_selected(|service, id| {
+                if service.get_by_id(id).unwrap().is_timer_running() {
+                    service.stop_timer(id).map(|_| t("tui.timer_stopped"))
+                } else {
+                    service.start_timer(id).map(|_| t("menu.timer_started"))
+                }
+            }),
+            KeyCode::Char('p') => self.with_selected(|service, id| {
+                let next = next_priority(service.get_by_id(id).unwrap().priority);
+                service
+                    .update_task(id, None, None, None, Some(next), None)
+                    .map(|_| t("tui.priority_changed"))
+            }),
+            KeyCode::Char('g') => self.with_selected(|service, id| {
+                let next = next_category(service.get_by_id(id).unwrap().category);
+                service
+                    .update_task(id, None, None, Some(next), None, None)
+                    .map(|_| t("tui.category_changed"))
+            }),
+            KeyCode::Char('d') | KeyCode::Delete if self.selected_task().is_some() => {
+                self.mode = Mode::ConfirmDelete;
+            }
+            _ => {}
+        }
+    }
+
+    fn handle_filter_key(&mut self, code: KeyCode) {
+        match code {
+            KeyCode::Enter => self.mode = Mode::Normal,
+            KeyCode::Esc => {
+                self.filter.clear();
+                self.mode = Mode::Normal;
+            }
+            KeyCode::Backspace => {
+                self.filter.pop();
+            }
+            KeyCode::Char(c) => {
+                self.filter.push(c);
+                self.table_state.select(Some(0));
+            }
+            _ => {}
+        }
+    }
+
+    fn handle_input_key(&mut self, code: KeyCode) {
+        match code {
+            KeyCode::Enter => {
+                self.commit_input();
+                self.mode = Mode::Normal;
+            }
+            KeyCode::Esc => {
+                self.input.clear();
+                self.mode = Mode::Normal;
+            }
+            KeyCode::Backspace => {
+                self.input.pop();
+            }
+            KeyCode::Char(c) => self.input.push(c),
+            _ => {}
+        }
+    }
+
+    fn handle_confirm_key(&mut self, code: KeyCode) {
+        if matches!(code, KeyCode::Char('s') | KeyCode::Char('y')) {
+            self.with_selected(|service, id| {
+                service.delete_task(id).map(|_| t("menu.task_deleted"))
+            });
+        } else {
+            self.message = t("menu.cancelled").to_string();
+        }
+        self.mode = Mode::Normal;
+    }
+
+    fn begin_edit(&mut self, field: EditField) {
+        if let Some(task) = self.selected_task() {
+            self.input = match field {
+                EditField::Title => task.title.clone(),
+                EditField::Description => task.description.clone(),
+            };
+            self.mode = Mode::Edit(field);
+        }
+    }
+
+    fn commit_input(&mut self) {
+        let value = std::mem::take(&mut self.input);
+
+        match self.mode {
+            Mode::Create => {
+                if value.trim().is_empty() {
+                    self.message = t("menu.empty_title").to_string();
+                    return;
+                }
+                // Aceita a sintaxe de adição rápida (#categoria, !prioridade, datas)
+                let result = self.service.quick_add(value.trim());
+                self.message = match result {
+                    Ok(task) => tf("menu.task_created", &[&task.id]),
+                    Err(e) => tf("menu.create_error", &[&e]),
+                };
+                let last = self.visible_tasks().len().saturating_sub(1);
+                self.table_state.select(Some(last));
+            }
+            Mode::Edit(EditField::Title) => {
+                if value.trim().is_empty() {
+                    self.message = t("menu.empty_title").to_string();
+                    return;
+                }
+                self.with_selected(|service, id| {
+                    service
+                        .update_task(id, Some(value), None, None, None, None)
+                        .map(|_| t("menu.task_updated"))
+                });
+            }
+            Mode::Edit(EditField::Description) => {
+                self.with_selected(|service, id| {
+                    service
+                        .update_task(id, None, Some(value), None, None, None)
+                        .map(|_| t("menu.task_updated"))
+                });
+            }
+            _ => {}
+        }
+    }
+
+    /// Executa uma ação do serviço sobre a tarefa selecionada
+    fn with_selected<F>(&mut self, action: F)
+    where
+        F: FnOnce(&mut TaskService, u32) -> io::Result<&'static str>,
+    {
+        let id = match self.selected_task() {
+            Some(task) => task.id,
+            None => return,
+        };
+
+        self.message = match action(&mut self.service, id) {
+            Ok(message) => message.to_string(),
+            Err(e) => tf("output.error", &[&e]),
+        };
+    }
+
+    fn move_selection(&mut self, delta: isize) {
+        let len = self.visible_tasks().len();
+        if len == 0 {
+            return;
+        }
+        let current = self.table_state.selected().unwrap_or(0) as isize;
+        let next = (current + delta).clamp(0, len as isize - 1);
+        self.table_state.select(Some(next as usize));
+    }
+
+    fn clamp_selection(&mut self) {
+        let len = self.visible_tasks().len();
+        let current = self.table_state.selected().unwrap_or(0);
+        self.table_state
+            .select(Some(current.min(len.saturating_sub(1))));
+    }
+
+    /// Desenha a interface
+    pub fn draw(&mut self, frame: &mut Frame) {
+        let [main, footer] =
+            Layout::vertical([Constraint::Min(5), Constraint::Length(3)]).areas(frame.area());
+        let [table_area, detail_area] =
+            Layout::horizontal([Constraint::Percentage(60), Constraint::Percentage(40)])
+                .areas(main);
+
+        let header = Row::new([
+            t("field.id"),
+            t("field.title"),
+            t("field.category"),
+            t("field.priority"),
+            t("field.status"),
+        ])
+        .style(Style::default().add_modifier(Modifier::BOLD));
+
+        let now = self.service.clock().now();
+        let rows: Vec<Row> = self
+            .visible_tasks()
+            .iter()
+            .map(|task| {
+                let style = if !self.colors {
+                    Style::default()
+                } else if task.is_overdue_at(&now) {
+                    Style::default().fg(Color::Red)
+                } else if task.status == Status::Completed {
+                    Style::default().fg(Color::DarkGray)
+                } else {
+                    Style::default()
+                };
+
+                Row::new(vec![
+                    Cell::from(task.id.to_string()),
+                    Cell::from(task.title.clone()),
+                    Cell::from(task.category.as_str().to_string()),
+                    Cell::from(task.priority.as_str().to_string()),
+                    Cell::from(task.status.as_str().to_string()),
+                ])
+                .style(style)
+            })
+            .collect();
+
+        let title = if self.filter.is_empty() {
+            format!(" {} ", t("tui.tasks"))
+        } else {
+            format!(" {} ", tf("tui.tasks_filtered", &[&self.filter]))
+        };
+
+        let table = Table::new(
+            rows,
+            [
+                Constraint::Length(4),
+                Constraint::Min(10),
+                Constraint::Length(10),
+                Constraint::Length(10),
+                Constraint::Length(12),
+            ],
+        )
+        .header(header)
+        .block(Block::default().borders(Borders::ALL).title(title))
+        .row_highlight_style(Style::default().add_modifier(Modifier::REVERSED))
+        .highlight_symbol("▶ ");
+
+        frame.render_stateful_widget(table, table_area, &mut self.table_state);
+
+        let detail = Paragraph::new(self.detail_lines())
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(format!(" {} ", t("tui.details"))),
+            )
+            .wrap(Wrap { trim: false });
+        frame.render_widget(detail, detail_area);
+
+        let footer_text = match self.mode {
+            Mode::Normal if !self.message.is_empty() => self.message.clone(),
+            Mode::Normal => t("tui.help").to_string(),
+            Mode::Filter => tf("tui.filter_prompt", &[&self.filter]),
+            Mode::Edit(EditField::Title) => {
+                format!("{}{}▏", t("menu.prompt_new_title"), self.input)
+            }
+            Mode::Edit(EditField::Description) => {
+                format!("{}{}▏", t("menu.prompt_new_description"), self.input)
+            }
+            Mode::Create => format!("{}{}▏", t("tui.prompt_new_task"), self.input),
+            Mode::ConfirmDelete => {
+                format!("{} {}", t("menu.confirm_delete").trim(), t("input.yes_no"))
+            }
+        };
+        let footer_widget =
+            Paragraph::new(footer_text).block(Block::default().borders(Borders::ALL));
+        frame.render_widget(footer_widget, footer);
+    }
+
+    fn detail_lines(&self) -> Vec<Line<'static>> {
+        let task = match self.selected_task() {
+            Some(task) => task,
+            None => return vec![Line::from(t("output.no_tasks"))],
+        };
+
+        let mut lines = vec![
+            detail_line(t("field.id"), &task.id),
+            detail_line(t("field.title"), &task.title),
+            detail_line(t("field.description"), &task.description),
+            detail_line(t("field.category"), &task.category.as_str()),
+            detail_line(t("field.priority"), &task.priority.as_str()),
+            detail_line(t("field.status"), &task.status.as_str()),
+        ];
+
+        let now = self.service.clock().now();
+        if let Some(due_date) = task.due_date {
+            let overdue = if task.is_overdue_at(&now) {
+                t("output.overdue_tag")
+            } else {
+                ""
+            };
+            let time = task
+                .due_time
+                .map(|time| time.format(" %H:%M").to_string())
+                .unwrap_or_default();
+            let value = format!(
+                "{}{}{}",
+                due_date.format(&i18n::date_format()),
+                time,
+                overdue
+            );
+            lines.push(detail_line(t("field.due_date"), &value));
+        }
+
+        if !task.time_entries.is_empty() || task.estimate_minutes.is_some() {
+            let running = if task.is_timer_running() {
+                t("output.timer_running_tag")
+            } else {
+                ""
+            };
+            let value = format!(
+                "{}{}",
+                output::format_minutes(task.time_spent(now).num_minutes()),
+                running
+            );
+            lines.push(detail_line(t("field.time_spent"), &value));
+        }
+
+        if let Some(estimate) = task.estimate_minutes {
+            let value = output::format_minutes(estimate as i64);
+            lines.push(detail_line(t("field.estimate"), &value));
+        }
+
+        if let Some(assignee) = &task.assignee {
+            lines.push(detail_line(t("field.assignee"), assignee));
+        }
+
+        if let Some(project) = &task.project {
+            lines.push(detail_line(t("field.project"), project));
+        }
+
+        lines.push(detail_line(
+            t("field.created_at"),
+            &task.created_at.format(&i18n::datetime_format()),
+        ));
+
+        if let Some(completed_at) = task.completed_at {
+            lines.push(detail_line(
+                t("field.completed_at"),
+                &completed_at.format(&i18n::datetime_format()),
+            ));
+        }
+
+        lines
+    }
+}
+
+/// Linha "rótulo: valor" do painel de detalhes
+fn detail_line(label: &str, value: &dyn Display) -> Line<'static> {
+    Line::from(format!("{}: {}", label, value))
+}
+
+/// Verifica se a tarefa contém o texto do filtro (já em minúsculas)
+fn matches_filter(task: &Task, filter: &str) -> bool {
+    [
+        task.title.as_str(),
+        task.description.as_str(),
+        task.category.as_str(),
+        task.priority.as_str(),
+        task.status.as_str(),
+    ]
+    .iter()
+    .any(|field| field.to_lowercase().contains(filter))
+}
+
+fn next_priority(priority: Priority) -> Priority {
+    let all = Priority::all();
+    let index = all.iter().position(|p| *p == priority).unwrap_or(0);
+    all[(index + 1) % all.len()]
+}
+
+fn next_category(category: Category) -> Category {
+    let all = Category::all();
+    let index = all.iter().position(|c| *c == category).unwrap_or(0);
+    all[(index + 1) % all.len()]
+}
+
+/// Executa a interface em tela cheia até o usuário sair
+pub fn run(service: TaskService, colors: bool) -> io::Result<()> {
+    let mut terminal = ratatui::init();
+    let mut app = TuiApp::new(service).with_colors(colors);
+
+    let result = loop {
+        if let Err(e) = terminal.draw(|frame| app.draw(frame)) {
+            break Err(e);
+        }
+
+        match event::read() {
+            Ok(Event::Key(key)) if key.kind == KeyEventKind::Press => app.handle_key(key),
+            Ok(_) => {}
+            Err(e) => break Err(e),
+        }
+
+        if app.should_quit() {
+            break Ok(());
+        }
+    };
+
+    ratatui::restore();
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::ProjectColor;
+    use ratatui::Terminal;
+    use ratatui::backend::TestBackend;
+
+    fn create_test_app(name: &str) -> TuiApp {
+        let path = format!("data/test_tui_{}.json", name);
+        TaskService::new(path.clone()).delete_storage().ok();
+
+        let mut service = TaskService::new(path);
+        for (title, category) in [
+            ("Revisar PR", Category::Work),
+            ("Academia", Category::Health),
+            ("Ler livro de Rust", Category::Study),
+        ] {
+            service
+                .add_task(
+                    title.to_string(),
+                    "Desc".to_string(),
+                    category,
+                    Priority::Medium,
+                    None,
+                )
+                .unwrap();
+        }
+
+        TuiApp::new(service)
+    }
+
+    fn press(app: &mut TuiApp, code: KeyCode) {
+        app.handle_key(KeyEvent::from(code));
+    }
+
+    fn type_text(app: &mut TuiApp, text: &str) {
+        for c in text.chars() {
+            press(app, KeyCode::Char(c));
+        }
+    }
+
+    #[test]
+    fn test_filter_as_you_type() {
+        let mut app = create_test_app("filter");
+
+        press(&mut app, KeyCode::Char('/'));
+        assert_eq!(app.mode(), Mode::Filter);
+
+        type_text(&mut app, "rust");
+        assert_eq!(app.visible_tasks().len(), 1);
+        assert_eq!(app.selected_task().unwrap().title, "Ler livro de Rust");
+
+        press(&mut app, KeyCode::Esc);
+        assert_eq!(app.filter(), "");
+        assert_eq!(app.visible_tasks().len(), 3);
+
+        app.service.delete_storage().ok();
+    }
+
+    #[test]
+    fn test_table_shows_only_the_active_project() {
+        let mut app = create_test_app("project");
+        app.service
+            .create_project("Casa".to_string(), String::new(), ProjectColor::Green)
+            .unwrap();
+        app.service.move_task(2, Some("Casa")).unwrap();
+        app.service.set_active_project(Some("Casa")).unwrap();
+
+        assert_eq!(app.visible_tasks().len(), 1);
+        assert_eq!(app.selected_task().unwrap().title, "Academia");
+
+        app.service.delete_storage().ok();
+    }
+
+    #[test]
+    fn test_shortcuts_start_and_complete() {
+        let mut app = create_test_app("shortcuts");
+
+        press(&mut app, KeyCode::Down);
+        press(&mut app, KeyCode::Char('s'));
+        assert_eq!(app.selected_task().unwrap().status, Status::InProgress);
+
+        press(&mut app, KeyCode::Char('c'));
+        assert_eq!(app.selected_task().unwrap().status, Status::Completed);
+        assert_eq!(app.message(), "Tarefa concluída!");
+
+        app.service.delete_storage().ok();
+    }
+
+    #[test]
+    fn test_delete_requires_confirmation() {
+        let mut app = create_test_app("delete");
+
+        press(&mut app, KeyCode::Char('d'));
+        press(&mut app, KeyCode::Char('n'));
+        assert_eq!(app.visible_tasks().len(), 3);
+
+        press(&mut app, KeyCode::End);
+        press(&mut app, KeyCode::Char('d'));
+        assert_eq!(app.mode(), Mode::ConfirmDelete);
+        press(&mut app, KeyCode::Char('s'));
+        assert_eq!(app.visible_tasks().len(), 2);
+        assert_eq!(app.selected_task().unwrap().title, "Academia");
+
+        app.service.delete_storage().ok();
+    }
+
+    #[test]
+    fn test_inline_edit_and_create() {
+        let mut app = create_test_app("edit");
+
+        press(&mut app, KeyCode::Char('e'));
+        for _ in 0.."Revisar PR".len() {
+            press(&mut app, KeyCode::Backspace);
+        }
+        type_text(&mut app, "Revisar PR #42");
+        press(&mut app, KeyCode::Enter);
+        assert_eq!(app.service().get_by_id(1).unwrap().title, "Revisar PR #42");
+
+        press(&mut app, KeyCode::Char('n'));
+        type_text(&mut app, "Nova");
+        press(&mut app, KeyCode::Enter);
+        assert_eq!(app.service().list_all().len(), 4);
+        assert_eq!(app.selected_task().unwrap().title, "Nova");
+
+        app.service.delete_storage().ok();
+    }
+
+    #[test]
+    fn test_draw_shows_table_and_details() {
+        let mut app = create_test_app("draw");
+        let mut terminal = Terminal::new(TestBackend::new(100, 20)).unwrap();
+
+        terminal.draw(|frame| app.draw(frame)).unwrap();
+
+        let content: String = terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect();
+        assert!(content.contains("Academia"));
+        assert!(content.contains("Detalhes"));
+        assert!(content.contains("Título: Revisar PR"));
+
+        app.service.delete_storage().ok();
+    }
+}
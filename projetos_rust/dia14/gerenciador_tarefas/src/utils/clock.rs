@@ -0,0 +1,71 @@
+use chrono::{DateTime, Duration, Local};
+use std::sync::Mutex;
+
+/// Fonte da hora atual
+///
+/// O código que depende do tempo recebe um relógio em vez de chamar
+/// `Local::now()`, para os testes controlarem a hora sem esperar.
+pub trait Clock: Send + Sync {
+    /// Hora atual
+    fn now(&self) -> DateTime<Local>;
+}
+
+/// Relógio do sistema
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Local> {
+        Local::now()
+    }
+}
+
+/// Relógio que só anda quando mandam (para testes)
+#[derive(Debug)]
+pub struct ManualClock {
+    now: Mutex<DateTime<Local>>,
+}
+
+impl ManualClock {
+    /// Cria um relógio parado em `now`
+    pub fn new(now: DateTime<Local>) -> Self {
+        ManualClock {
+            now: Mutex::new(now),
+        }
+    }
+
+    /// Muda a hora
+    pub fn set(&self, now: DateTime<Local>) {
+        *self.now.lock().unwrap_or_else(|poisoned| poisoned.into_inner()) = now;
+    }
+
+    /// Avança a hora
+    pub fn advance(&self, duration: Duration) {
+        let mut now = self.now.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        *now += duration;
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> DateTime<Local> {
+        *self.now.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_manual_clock() {
+        let start = Local::now();
+        let clock = ManualClock::new(start);
+        assert_eq!(clock.now(), start);
+
+        clock.advance(Duration::minutes(25));
+        assert_eq!(clock.now(), start + Duration::minutes(25));
+
+        clock.set(start);
+        assert_eq!(clock.now(), start);
+    }
+}
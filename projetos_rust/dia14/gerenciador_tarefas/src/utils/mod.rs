@@ -1,2 +1,5 @@
 // src/utils/mod.rs
-pub mod validators;
\ No newline at end of file
+pub mod clock;
+pub mod validators;
+
+pub use clock::{Clock, ManualClock, SystemClock};
@@ -1,5 +1,10 @@
 use gerenciador_tarefas::models::{Category, Priority, Status};
-use gerenciador_tarefas::services::TaskService;
+use gerenciador_tarefas::services::{SyncStore, TaskService};
+use gerenciador_tarefas::storage::StorageFormat;
+use gerenciador_tarefas::ui::commands::{self, Command};
+use gerenciador_tarefas::ui::json::OutputFormat;
+use gerenciador_tarefas::ui::theme::{self, AsciiWriter};
+use std::io::Write;
 
 #[test]
 fn test_full_crud_workflow() {
@@ -49,7 +54,7 @@ fn test_full_crud_workflow() {
     assert!(service.get_by_id(task_id).is_none());
 
     // Cleanup
-    service.storage.delete().ok();
+    service.delete_storage().ok();
 }
 
 #[test]
@@ -78,7 +83,7 @@ fn test_persistence() {
         assert_eq!(tasks[0].title, "Persistent Task");
 
         // Cleanup
-        service.storage.delete().ok();
+        service.delete_storage().ok();
     }
 }
 
@@ -107,7 +112,7 @@ fn test_filters() {
         )
         .unwrap();
 
-    let task3 = service
+    let task3_id = service
         .add_task(
             "Study Task".to_string(),
             "Desc".to_string(),
@@ -115,9 +120,10 @@ fn test_filters() {
             Priority::High,
             None,
         )
-        .unwrap();
+        .unwrap()
+        .id;
 
-    service.complete_task(task3.id).unwrap();
+    service.complete_task(task3_id).unwrap();
 
     // Testa filtros
     let work_tasks = service.filter_by_category(Category::Work);
@@ -130,7 +136,7 @@ fn test_filters() {
     assert_eq!(completed.len(), 1);
 
     // Cleanup
-    service.storage.delete().ok();
+    service.delete_storage().ok();
 }
 
 #[test]
@@ -148,7 +154,7 @@ fn test_statistics() {
         )
         .unwrap();
 
-    let task2 = service
+    let task2_id = service
         .add_task(
             "Task 2".to_string(),
             "Desc".to_string(),
@@ -156,7 +162,8 @@ fn test_statistics() {
             Priority::Medium,
             None,
         )
-        .unwrap();
+        .unwrap()
+        .id;
 
     service
         .add_task(
@@ -168,7 +175,7 @@ fn test_statistics() {
         )
         .unwrap();
 
-    service.complete_task(task2.id).unwrap();
+    service.complete_task(task2_id).unwrap();
 
     // Testa estatísticas
     let stats = service.get_statistics();
@@ -177,5 +184,165 @@ fn test_statistics() {
     assert_eq!(stats.pending, 2);
 
     // Cleanup
-    service.storage.delete().ok();
-}
\ No newline at end of file
+    service.delete_storage().ok();
+}
+
+#[test]
+fn test_json_lines_list_command() {
+    let path = "data/test_json_command.json".to_string();
+    TaskService::new(path.clone()).delete_storage().ok();
+    let mut service = TaskService::new(path);
+
+    let done_id = service
+        .add_task(
+            "Feita".to_string(),
+            "".to_string(),
+            Category::Work,
+            Priority::High,
+            None,
+        )
+        .unwrap()
+        .id;
+    service
+        .add_task(
+            "Aberta".to_string(),
+            "".to_string(),
+            Category::Study,
+            Priority::Low,
+            None,
+        )
+        .unwrap();
+    service.complete_task(done_id).unwrap();
+
+    // Só as pendentes, um objeto por linha
+    let args: Vec<String> = ["list", "--status", "pending"]
+        .iter()
+        .map(|a| a.to_string())
+        .collect();
+    let command = Command::parse(&args).unwrap();
+    let mut out = Vec::new();
+    commands::run(&mut service, &command, OutputFormat::JsonLines, &mut out).unwrap();
+
+    let output = String::from_utf8(out).unwrap();
+    let lines: Vec<serde_json::Value> = output
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    assert_eq!(lines.len(), 1);
+    assert_eq!(lines[0]["title"], "Aberta");
+    assert_eq!(lines[0]["category"], "Study");
+    assert_eq!(lines[0]["is_overdue"], false);
+
+    // Estatísticas num documento só
+    let command = Command::parse(&["stats".to_string()]).unwrap();
+    let mut out = Vec::new();
+    commands::run(&mut service, &command, OutputFormat::Json, &mut out).unwrap();
+
+    let stats: serde_json::Value = serde_json::from_slice(&out).unwrap();
+    assert_eq!(stats["overall"]["total"], 2);
+    assert_eq!(stats["overall"]["completed"], 1);
+    assert_eq!(stats["overall"]["by_priority"]["High"], 1);
+
+    // Cleanup
+    service.delete_storage().ok();
+}
+
+#[test]
+fn test_ascii_output_keeps_task_text() {
+    let path = "data/test_ascii_command.json".to_string();
+    TaskService::new(path.clone()).delete_storage().ok();
+    let mut service = TaskService::new(path);
+    service
+        .add_task(
+            "Deploy → produção 🚀".to_string(),
+            "".to_string(),
+            Category::Work,
+            Priority::High,
+            None,
+        )
+        .unwrap();
+
+    // Saída redirecionada: a decoração vira ASCII, o título não
+    theme::set_ascii(true);
+    let command = Command::parse(&["list".to_string()]).unwrap();
+    let mut out = AsciiWriter::new(Vec::new(), true);
+    commands::run(&mut service, &command, OutputFormat::Text, &mut out).unwrap();
+    out.flush().unwrap();
+    theme::set_ascii(false);
+
+    let output = String::from_utf8(out.into_inner()).unwrap();
+    assert!(output.contains("Deploy → produção 🚀"));
+    assert!(output.contains("-----"));
+    assert!(!output.contains('─'));
+    assert!(!output.contains('\x1B'));
+
+    // Cleanup
+    service.delete_storage().ok();
+}
+
+#[test]
+fn test_sync_two_directories() {
+    let (laptop_path, desktop_path) = ("data/test_sync_laptop", "data/test_sync_desktop");
+    let mut laptop = TaskService::with_format(laptop_path.to_string(), StorageFormat::Markdown);
+    let desktop_store = SyncStore::open(desktop_path, StorageFormat::Markdown);
+    laptop.delete_storage().ok();
+    TaskService::with_format(desktop_path.to_string(), StorageFormat::Markdown)
+        .delete_storage()
+        .ok();
+
+    let shared = laptop
+        .add_task(
+            "Compartilhada".to_string(),
+            "".to_string(),
+            Category::Work,
+            Priority::Low,
+            None,
+        )
+        .unwrap()
+        .id;
+    let removed = laptop
+        .add_task(
+            "Some no notebook".to_string(),
+            "".to_string(),
+            Category::Other,
+            Priority::Low,
+            None,
+        )
+        .unwrap()
+        .id;
+
+    // Primeira sincronização: o desktop recebe tudo
+    let report = laptop.sync_with(&desktop_store).unwrap();
+    assert_eq!(report.sent, vec![shared, removed]);
+
+    // Cada máquina mexe num campo diferente; o notebook exclui uma tarefa
+    let mut desktop = TaskService::with_format(desktop_path.to_string(), StorageFormat::Markdown);
+    assert_eq!(desktop.list_all().len(), 2);
+    desktop
+        .update_task(shared, None, None, None, Some(Priority::High), None)
+        .unwrap();
+    laptop
+        .update_task(shared, Some("Renomeada".to_string()), None, None, None, None)
+        .unwrap();
+    laptop.delete_task(removed).unwrap();
+
+    let laptop_store = SyncStore::open(laptop_path, StorageFormat::Markdown);
+    let report = desktop.sync_with(&laptop_store).unwrap();
+    assert_eq!(report.updated, vec![shared]);
+    assert_eq!(report.deleted, vec![removed]);
+    assert!(report.conflicts.is_empty());
+
+    for service in [
+        &desktop,
+        &TaskService::with_format(laptop_path.to_string(), StorageFormat::Markdown),
+    ] {
+        let tasks = service.list_all();
+        assert_eq!(tasks.len(), 1);
+        assert_eq!(tasks[0].title, "Renomeada");
+        assert_eq!(tasks[0].priority, Priority::High);
+    }
+
+    // Cleanup
+    laptop.delete_storage().ok();
+    desktop.delete_storage().ok();
+}
@@ -0,0 +1,598 @@
+use chrono::{Duration, Local};
+use gerenciador_tarefas::config::Config;
+use gerenciador_tarefas::i18n::{self, Locale};
+use gerenciador_tarefas::models::{Category, Priority, Status, Template, TemplateTask};
+use gerenciador_tarefas::services::TaskService;
+use gerenciador_tarefas::storage::StorageFormat;
+use gerenciador_tarefas::ui::Menu;
+
+/// Cria um serviço limpo com duas tarefas de exemplo
+fn create_service(name: &str) -> TaskService {
+    let path = format!("data/test_menu_{}.json", name);
+    TaskService::new(path.clone()).delete_storage().ok();
+
+    let mut service = TaskService::new(path);
+    service
+        .add_task(
+            "Relatório".to_string(),
+            "Mensal".to_string(),
+            Category::Work,
+            Priority::High,
+            Some(Local::now().date_naive() - Duration::days(2)),
+        )
+        .unwrap();
+    service
+        .add_task(
+            "Academia".to_string(),
+            "Treino".to_string(),
+            Category::Health,
+            Priority::Low,
+            None,
+        )
+        .unwrap();
+    service
+}
+
+/// Executa uma sessão completa do menu com a entrada roteirizada
+fn run_session(name: &str, script: &str) -> (String, TaskService) {
+    let service = create_service(name);
+    let mut menu = Menu::with_io(service, script.as_bytes(), Vec::new());
+
+    menu.run().unwrap();
+
+    let output = String::from_utf8(menu.writer().clone()).unwrap();
+    let path = format!("data/test_menu_{}.json", name);
+    (output, TaskService::new(path))
+}
+
+fn cleanup(service: TaskService) {
+    service.delete_storage().ok();
+}
+
+#[test]
+fn test_exit() {
+    let (output, service) = run_session("exit", "0\n");
+
+    assert!(output.contains("GERENCIADOR DE TAREFAS"));
+    assert!(output.contains("Até logo!"));
+
+    cleanup(service);
+}
+
+#[test]
+fn test_end_of_input_ends_session() {
+    let (output, service) = run_session("eof", "2\n");
+
+    assert!(output.contains("TODAS AS TAREFAS"));
+    assert!(!output.contains("Até logo!"));
+
+    cleanup(service);
+}
+
+#[test]
+fn test_invalid_option() {
+    let (output, service) = run_session("invalid", "42\n\n0\n");
+
+    assert!(output.contains("Opção inválida!"));
+
+    cleanup(service);
+}
+
+#[test]
+fn test_create_task() {
+    let script = "1\nEstudar Rust\nCapítulo 8\n3\n1\n31/12/2030\n\n0\n";
+    let (output, service) = run_session("create", script);
+
+    assert!(output.contains("Tarefa criada com ID: 3"));
+    let task = service.get_by_id(3).unwrap();
+    assert_eq!(task.title, "Estudar Rust");
+    assert_eq!(task.description, "Capítulo 8");
+    assert_eq!(task.category, Category::Study);
+    assert_eq!(task.priority, Priority::High);
+    assert_eq!(task.due_date.unwrap().to_string(), "2030-12-31");
+
+    cleanup(service);
+}
+
+#[test]
+fn test_create_task_validation() {
+    let script = "1\n\n\n1\nX\n\n0\n\n1\nX\n\n1\n2\namanhã\n\n0\n";
+    let (output, service) = run_session("create_invalid", script);
+
+    assert!(output.contains("Título não pode ser vazio!"));
+    assert!(output.contains("Categoria inválida!"));
+    assert!(output.contains("Data inválida!"));
+    assert_eq!(service.list_all().len(), 2);
+
+    cleanup(service);
+}
+
+#[test]
+fn test_list_tasks() {
+    let (output, service) = run_session("list", "2\n\n0\n");
+
+    assert!(output.contains("Relatório"));
+    assert!(output.contains("Academia"));
+    assert!(output.contains("Total: 2 tarefa(s)"));
+
+    cleanup(service);
+}
+
+#[test]
+fn test_view_and_start_task() {
+    let (output, service) = run_session("view_start", "3\n2\n1\n\n0\n");
+
+    assert!(output.contains("Título: Academia"));
+    assert!(output.contains("Tarefa iniciada!"));
+    assert_eq!(service.get_by_id(2).unwrap().status, Status::InProgress);
+
+    cleanup(service);
+}
+
+#[test]
+fn test_view_and_complete_task() {
+    let (output, service) = run_session("view_complete", "3\n1\n2\n\n0\n");
+
+    assert!(output.contains("(ATRASADA!)"));
+    assert!(output.contains("Tarefa concluída!"));
+    assert_eq!(service.get_by_id(1).unwrap().status, Status::Completed);
+
+    cleanup(service);
+}
+
+#[test]
+fn test_view_missing_task() {
+    let (output, service) = run_session("view_missing", "3\n42\n\n3\nabc\n\n0\n");
+
+    assert!(output.contains("Tarefa não encontrada!"));
+    assert!(output.contains("ID inválido!"));
+
+    cleanup(service);
+}
+
+#[test]
+fn test_update_task() {
+    let (output, service) = run_session("update", "4\n2\nNatação\n\n\n0\n");
+
+    assert!(output.contains("Tarefa atualizada com sucesso!"));
+    let task = service.get_by_id(2).unwrap();
+    assert_eq!(task.title, "Natação");
+    assert_eq!(task.description, "Treino");
+
+    cleanup(service);
+}
+
+#[test]
+fn test_delete_task_confirmed_and_cancelled() {
+    let (output, service) = run_session("delete", "5\n1\nn\n\n5\n2\ns\n\n0\n");
+
+    assert!(output.contains("Operação cancelada."));
+    assert!(output.contains("Tarefa movida para a lixeira!"));
+    assert!(service.get_by_id(1).is_some());
+    assert!(service.get_by_id(2).is_none());
+
+    cleanup(service);
+}
+
+#[test]
+fn test_filter_by_status() {
+    let (output, service) = run_session("filter_status", "6\n1\n1\n\n0\n");
+
+    assert!(output.contains("FILTRAR TAREFAS"));
+    assert!(output.contains("Total: 2 tarefa(s)"));
+
+    cleanup(service);
+}
+
+#[test]
+fn test_filter_by_category() {
+    let (output, service) = run_session("filter_category", "6\n2\n4\n\n0\n");
+
+    assert!(output.contains("Academia"));
+    assert!(output.contains("Total: 1 tarefa(s)"));
+
+    cleanup(service);
+}
+
+#[test]
+fn test_filter_by_priority() {
+    let (output, service) = run_session("filter_priority", "6\n3\n2\n\n0\n");
+
+    assert!(output.contains("Nenhuma tarefa encontrada."));
+
+    cleanup(service);
+}
+
+#[test]
+fn test_filter_overdue() {
+    let (output, service) = run_session("filter_overdue", "6\n4\n\n0\n");
+
+    assert!(output.contains("Relatório"));
+    assert!(!output.contains("Academia"));
+
+    cleanup(service);
+}
+
+#[test]
+fn test_statistics() {
+    let (output, service) = run_session("statistics", "7\n\n0\n");
+
+    assert!(output.contains("ESTATÍSTICAS"));
+    assert!(output.contains("Total de tarefas: 2"));
+    assert!(output.contains("Atrasadas: 1"));
+
+    cleanup(service);
+}
+
+#[test]
+fn test_session_in_english() {
+    i18n::set_locale(Locale::EnUs);
+    let script = "1\nWrite docs\n\n5\n3\n12/31/2030\n\n3\n3\n0\n\n0\n";
+    let (output, service) = run_session("english", script);
+    i18n::set_locale(Locale::PtBr);
+
+    assert!(output.contains("TASK MANAGER"));
+    assert!(output.contains("Due date (MM/DD/YYYY) or Enter to skip: "));
+    assert!(output.contains("Task created with ID: 3"));
+    assert!(output.contains("Category: Other"));
+    assert!(output.contains("Due date: 12/31/2030"));
+    assert!(output.contains("Goodbye!"));
+    assert_eq!(
+        service.get_by_id(3).unwrap().due_date.unwrap().to_string(),
+        "2030-12-31"
+    );
+
+    cleanup(service);
+}
+
+#[test]
+fn test_create_task_with_config_defaults() {
+    let config = Config {
+        storage_path: "data/test_menu_config_defaults.json".to_string(),
+        default_category: Category::Study,
+        default_priority: Priority::High,
+        clear_screen: false,
+        ..Config::default()
+    };
+
+    TaskService::new(config.storage_path.clone()).delete_storage().ok();
+    let service = TaskService::from_config(&config, None).unwrap();
+    let script = "1\nLer livro\n\n\n\n\n0\n";
+    let mut menu = Menu::with_io(service, script.as_bytes(), Vec::new()).with_config(config);
+    menu.run().unwrap();
+
+    let output = String::from_utf8(menu.writer().clone()).unwrap();
+    assert!(output.contains("Escolha (Enter = Estudos): "));
+    assert!(!output.contains("\x1B[2J"));
+
+    let task = menu.service().get_by_id(1).unwrap();
+    assert_eq!(task.category, Category::Study);
+    assert_eq!(task.priority, Priority::High);
+
+    menu.service().delete_storage().ok();
+}
+
+#[test]
+fn test_time_tracking_actions() {
+    let script = "3\n2\n5\n60\n\n3\n2\n4\n90\n\n3\n2\n3\n\n3\n1\n3\n\n7\n\n0\n";
+    let (output, service) = run_session("time_tracking", script);
+
+    assert!(output.contains("Estimativa atualizada!"));
+    assert!(output.contains("Tempo registrado!"));
+    assert!(output.contains("Cronômetro iniciado!"));
+    assert!(output.contains("Já existe um cronômetro rodando na tarefa 2 (Academia)"));
+    assert!(output.contains("Estimado x real: 1h 00min / 1h 30min"));
+    assert!(output.contains("Acima da estimativa: 1"));
+
+    let task = service.get_by_id(2).unwrap();
+    assert_eq!(task.estimate_minutes, Some(60));
+    assert!(task.is_timer_running());
+    assert!(task.is_over_estimate(service.clock().now()));
+
+    cleanup(service);
+}
+
+#[test]
+fn test_analytics() {
+    let (output, service) = run_session(
+        "analytics",
+        "3\n1\n2\n\n8\n\n\n\n8\n01/02/2030\n01/01/2030\n\n0\n",
+    );
+
+    assert!(output.contains("ANÁLISE DE PRODUTIVIDADE"));
+    assert!(output.contains("Concluídas por dia:"));
+    assert!(output.contains("Burndown (tarefas em aberto):"));
+    assert!(output.contains("█ 1"));
+    assert!(output.contains("A data inicial deve ser anterior à final!"));
+
+    cleanup(service);
+}
+
+#[test]
+fn test_board() {
+    let (output, service) = run_session("board", "3\n2\n1\n\n9\n\n0\n");
+
+    assert!(output.contains("QUADRO KANBAN"));
+    assert!(output.contains("Pendente (1)"));
+    assert!(output.contains("Em Andamento (1)"));
+    assert!(output.contains("!↑ #1 Relatório"));
+    assert!(output.contains("↓ #2 Academia"));
+
+    cleanup(service);
+}
+
+#[test]
+fn test_trash_and_archive() {
+    let script = "5\n2\ns\n\n10\n1\n\n10\n2\n2\n\n3\n1\n2\n\n10\n5\n\n10\n6\n\n7\n\n0\n";
+    let (output, service) = run_session("trash_archive", script);
+
+    assert!(output.contains("NA LIXEIRA"));
+    assert!(output.contains("Tarefa restaurada!"));
+    assert!(output.contains("1 tarefa(s) arquivada(s)."));
+    assert!(output.contains("ARQUIVADAS"));
+    assert!(output.contains("Total de tarefas: 1"));
+    assert!(service.get_by_id(1).is_none());
+    assert_eq!(service.list_archived()[0].id, 1);
+    assert!(service.get_by_id(2).is_some());
+    assert!(service.list_trash().is_empty());
+
+    cleanup(service);
+}
+
+#[test]
+fn test_bulk_operations() {
+    let script = "11\n1-2,5\n4\n3\n\n11\nx-1\n\n11\n1,2\n3\ns\n\n0\n";
+    let (output, service) = run_session("bulk", script);
+
+    assert!(output.contains("✅ #1"));
+    assert!(output.contains("❌ #5: Tarefa não encontrada"));
+    assert!(output.contains("2 tarefa(s) alterada(s), 1 com erro."));
+    assert!(output.contains("Lista de IDs inválida!"));
+    assert!(service.list_all().is_empty());
+    assert!(service.list_trash().iter().all(|t| t.priority == Priority::Low));
+
+    cleanup(service);
+}
+
+#[test]
+fn test_quick_add() {
+    let script = "12\nComprar presente amanhã 18h #pessoal !baixa\n\n12\n#saude\n\n0\n";
+    let (output, service) = run_session("quick_add", script);
+
+    assert!(output.contains("Tarefa criada com ID: 3"));
+    assert!(output.contains("Título não pode ser vazio!"));
+
+    let task = service.get_by_id(3).unwrap();
+    assert_eq!(task.title, "Comprar presente");
+    assert_eq!(task.category, Category::Personal);
+    assert_eq!(task.priority, Priority::Low);
+    assert_eq!(
+        task.due_date,
+        Some(Local::now().date_naive() + Duration::days(1))
+    );
+    assert!(output.contains(" 18:00"));
+
+    cleanup(service);
+}
+
+#[test]
+fn test_next_up_with_dependencies() {
+    // Sem bloqueio, a atrasada de prioridade alta vem primeiro
+    let (output, service) = run_session("next_up", "13\n\n3\n1\n6\n2\n\n13\n\n0\n");
+
+    assert!(output.contains("O QUE FAZER AGORA"));
+    assert!(output.contains("1. ↑ #1 Relatório"));
+    assert!(output.contains("atrasada há 2 dia(s)"));
+    assert!(output.contains("Dependências atualizadas!"));
+    assert!(output.contains("bloqueada por outra tarefa"));
+    assert!(output.contains("1. ↓ #2 Academia"));
+    assert_eq!(service.get_by_id(1).unwrap().blocked_by, vec![2]);
+
+    cleanup(service);
+}
+
+#[test]
+fn test_user_filters_and_assignment() {
+    let mut service = create_service("users");
+    service.set_current_user(Some("ana".to_string()));
+    service
+        .add_task(
+            "Revisar contrato".to_string(),
+            "".to_string(),
+            Category::Work,
+            Priority::Medium,
+            None,
+        )
+        .unwrap();
+
+    // Atribui a #3 ao Bruno, lista "atribuídas por mim", "minhas" e estatísticas
+    let script = "3\n3\n7\nbruno\n\n6\n6\n\n6\n5\n\n7\n\n0\n";
+    let mut menu = Menu::with_io(service, script.as_bytes(), Vec::new());
+    menu.run().unwrap();
+    let output = String::from_utf8(menu.writer().clone()).unwrap();
+
+    assert!(output.contains("Responsável atualizado!"));
+    assert!(output.contains("Revisar contrato"));
+    assert!(output.contains("Por Responsável:"));
+    assert!(output.contains("bruno: 1 tarefa(s), 0 concluída(s), 0 atrasada(s)"));
+    assert!(output.contains("(sem responsável): 2 tarefa(s)"));
+
+    let service = TaskService::new("data/test_menu_users.json".to_string());
+    let task = service.get_by_id(3).unwrap();
+    assert_eq!(task.created_by.as_deref(), Some("ana"));
+    assert_eq!(task.assignee.as_deref(), Some("bruno"));
+    cleanup(service);
+}
+
+#[test]
+fn test_user_filters_without_current_user() {
+    let (output, service) = run_session("no_user", "6\n5\n\n0\n");
+
+    assert!(output.contains("Nenhum usuário atual definido"));
+
+    cleanup(service);
+}
+
+#[test]
+fn test_projects() {
+    // Cria "Casa" (verde), ativa, cria tarefa nele, move #2 e mostra estatísticas
+    let script = "14\n2\nCasa\nReforma\n2\n\n14\n3\ncasa\n\n1\nPintar sala\n\n\n\n\n\n\
+                  2\n\n14\n4\n2\nCasa\n\n14\n1\n\n14\n6\n\n0\n";
+    let (output, service) = run_session("projects", script);
+
+    assert!(output.contains("Projeto 'Casa' criado!"));
+    assert!(output.contains("Projeto ativo: Casa"));
+    assert!(output.contains("Tarefa movida!"));
+    assert!(output.contains("▶ Casa (Verde) — 2 tarefa(s)"));
+    assert!(output.contains("Por Projeto:"));
+    assert!(output.contains("(sem projeto): 1 tarefa(s)"));
+
+    assert_eq!(service.get_by_id(3).unwrap().project.as_deref(), Some("Casa"));
+    assert_eq!(service.get_by_id(2).unwrap().project.as_deref(), Some("Casa"));
+    assert_eq!(service.get_by_id(1).unwrap().project, None);
+    assert_eq!(service.list_projects()[0].description, "Reforma");
+
+    cleanup(service);
+}
+
+#[test]
+fn test_notes_and_attachments() {
+    std::fs::create_dir_all("data").unwrap();
+    std::fs::write("data/test_menu_anexo.txt", "ata da reunião").unwrap();
+
+    // Nota, anexo, lista de anexos (com remoção) e visualização final
+    let script = "3\n1\n8\nLigar para o cliente\n\n\
+                  3\n1\n9\ndata/test_menu_anexo.txt\n\n\
+                  3\n1\n10\n\n\n\
+                  3\n1\n10\n1\n\n\
+                  3\n1\n0\n\n0\n";
+    let (output, service) = run_session("attachments", script);
+
+    assert!(output.contains("Nota adicionada!"));
+    assert!(output.contains("] Ligar para o cliente"));
+    assert!(output.contains("Arquivo 'test_menu_anexo.txt' anexado!"));
+    assert!(output.contains("1. test_menu_anexo.txt (15 B)"));
+    assert!(output.contains("data/test_menu_attachments.attachments/"));
+    assert!(output.contains("Anexo 'test_menu_anexo.txt' removido!"));
+
+    let task = service.get_by_id(1).unwrap();
+    assert_eq!(task.notes.len(), 1);
+    assert!(task.attachments.is_empty());
+
+    cleanup(service);
+    std::fs::remove_file("data/test_menu_anexo.txt").ok();
+}
+
+#[test]
+fn test_encryption_menu() {
+    // Criptografa com uma senha (confirmação errada primeiro) e exporta
+    let script = "15\n1\nsegredo\noutra\n\n15\n1\nsegredo\nsegredo\n\n\
+                  15\n2\ndata/test_menu_export.json\n\n0\n";
+    let (output, _) = run_session("encryption", script);
+
+    assert!(output.contains("Criptografar com uma senha"));
+    assert!(output.contains("As senhas não conferem"));
+    assert!(output.contains("Senha definida; arquivos criptografados de novo!"));
+    assert!(output.contains("2 tarefa(s) exportada(s) para data/test_menu_export.json"));
+
+    // Sem a senha, o arquivo não abre
+    let path = "data/test_menu_encryption.json".to_string();
+    assert!(!std::fs::read_to_string(&path).unwrap().contains("Academia"));
+    let exported = std::fs::read_to_string("data/test_menu_export.json").unwrap();
+    assert!(exported.contains("Academia"));
+
+    let service = TaskService::open(path, StorageFormat::Json, Some("segredo")).unwrap();
+    assert_eq!(service.list_all().len(), 2);
+
+    cleanup(service);
+    std::fs::remove_file("data/test_menu_export.json").ok();
+}
+
+#[test]
+fn test_encrypted_attachments_menu() {
+    std::fs::create_dir_all("data").unwrap();
+    std::fs::write("data/test_menu_anexo_secreto.txt", "proposta ACME").unwrap();
+
+    // Criptografa, anexa e salva uma cópia aberta pela lista de anexos
+    let script = "15\n1\nsegredo\nsegredo\n\n\
+                  3\n1\n9\ndata/test_menu_anexo_secreto.txt\n\n\
+                  3\n1\n10\n1\ndata/test_menu_anexo_copia.txt\n\n\n0\n";
+    let (output, _) = run_session("encrypted_attachments", script);
+
+    assert!(output.contains("1. test_menu_anexo_secreto.txt (13 B)"));
+    assert!(output.contains("🔒 Criptografado; salve uma cópia para abrir"));
+    assert!(!output.contains("data/test_menu_encrypted_attachments.attachments/"));
+    assert!(output.contains(
+        "Cópia de 'test_menu_anexo_secreto.txt' salva em data/test_menu_anexo_copia.txt"
+    ));
+    let copy = std::fs::read_to_string("data/test_menu_anexo_copia.txt").unwrap();
+    assert_eq!(copy, "proposta ACME");
+
+    let path = "data/test_menu_encrypted_attachments.json".to_string();
+    let service = TaskService::open(path, StorageFormat::Json, Some("segredo")).unwrap();
+    let attachment = &service.get_by_id(1).unwrap().attachments[0];
+    let stored = std::fs::read(service.attachment_path(attachment).unwrap()).unwrap();
+    assert!(!String::from_utf8_lossy(&stored).contains("ACME"));
+
+    cleanup(service);
+    std::fs::remove_file("data/test_menu_anexo_secreto.txt").ok();
+    std::fs::remove_file("data/test_menu_anexo_copia.txt").ok();
+}
+
+#[test]
+fn test_focus_session_needs_terminal() {
+    let (output, service) = run_session("focus", "3\n1\n11\n\n0\n");
+
+    assert!(output.contains("Sessão de foco (Pomodoro)"));
+    assert!(output.contains("A sessão de foco precisa de um terminal"));
+    assert!(service.get_by_id(1).unwrap().pomodoros.is_empty());
+
+    cleanup(service);
+}
+
+#[test]
+fn test_templates_menu() {
+    let mut service = create_service("templates");
+    let mut account = TemplateTask::new(
+        "Criar conta de {nome}".to_string(),
+        Category::Work,
+        Priority::High,
+    );
+    account.due_in_days = Some(3);
+    let mut intro = TemplateTask::new(
+        "Apresentar {nome}".to_string(),
+        Category::Work,
+        Priority::Low,
+    );
+    intro.after = vec![0];
+    service
+        .create_template(Template::new(
+            "Onboarding".to_string(),
+            "Pessoa nova no time".to_string(),
+            vec![account, intro],
+        ))
+        .unwrap();
+
+    // Usa o modelo, salva as tarefas #1 e #2 como outro, lista e exclui
+    let script = "16\n2\n1\nAna\n\n\n16\n3\n1,2\nRotina\n\n\n16\n1\n\n16\n4\nrotina\ns\n\n0\n";
+    let mut menu = Menu::with_io(service, script.as_bytes(), Vec::new());
+    menu.run().unwrap();
+    let output = String::from_utf8(menu.writer().clone()).unwrap();
+
+    assert!(output.contains("1. Onboarding — 2 tarefa(s)"));
+    assert!(output.contains("{nome}"));
+    assert!(output.contains("Valor para 'nome': "));
+    assert!(output.contains("2 tarefa(s) criada(s): #3, #4"));
+    assert!(output.contains("Modelo 'Rotina' salvo!"));
+    assert!(output.contains("2. Rotina — 2 tarefa(s)"));
+    assert!(output.contains("Modelo excluído!"));
+
+    let service = TaskService::new("data/test_menu_templates.json".to_string());
+    let account = service.get_by_id(3).unwrap();
+    assert_eq!(account.title, "Criar conta de Ana");
+    assert_eq!(account.due_date, Some(Local::now().date_naive() + Duration::days(3)));
+    assert_eq!(service.get_by_id(4).unwrap().blocked_by, vec![3]);
+    assert_eq!(service.list_templates().len(), 1);
+
+    cleanup(service);
+}